@@ -0,0 +1,101 @@
+use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+  account::AccountHash, system::CallStackElement, ContractHash, ContractPackageHash, Key,
+};
+
+use bridge_core::{CasperAddressTag, ChainType, Error, GenericAddress};
+
+// casper side of the generic address, anything that can hold tokens
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CasperAddress {
+  Account(AccountHash),
+  Contract(ContractHash),
+  Package(ContractPackageHash),
+}
+
+impl CasperAddress {
+  pub fn tag(&self) -> CasperAddressTag {
+    match self {
+      CasperAddress::Account(_) => CasperAddressTag::Account,
+      CasperAddress::Contract(_) => CasperAddressTag::Contract,
+      CasperAddress::Package(_) => CasperAddressTag::Package,
+    }
+  }
+
+  pub fn value(&self) -> [u8; 32] {
+    match self {
+      CasperAddress::Account(hash) => hash.value(),
+      CasperAddress::Contract(hash) => hash.value(),
+      CasperAddress::Package(hash) => hash.value(),
+    }
+  }
+
+  pub fn to_generic(&self) -> GenericAddress {
+    GenericAddress::Casper(self.tag(), self.value())
+  }
+
+  pub fn from_generic(bytes: &[u8]) -> Result<Self, Error> {
+    let (tag, value) = match GenericAddress::decode(ChainType::Casper, bytes)? {
+      GenericAddress::Casper(tag, value) => (tag, value),
+      _ => return Err(Error::InvalidAddressTag),
+    };
+
+    let address = match tag {
+      CasperAddressTag::Account => CasperAddress::Account(AccountHash::new(value)),
+      CasperAddressTag::Contract => CasperAddress::Contract(ContractHash::new(value)),
+      CasperAddressTag::Package => CasperAddress::Package(ContractPackageHash::new(value)),
+    };
+
+    Ok(address)
+  }
+}
+
+impl From<CasperAddress> for Key {
+  fn from(address: CasperAddress) -> Self {
+    match address {
+      CasperAddress::Account(hash) => Key::from(hash),
+      CasperAddress::Contract(hash) => Key::from(hash),
+      CasperAddress::Package(hash) => Key::from(hash),
+    }
+  }
+}
+
+impl From<CallStackElement> for CasperAddress {
+  fn from(element: CallStackElement) -> Self {
+    match element {
+      CallStackElement::Session { account_hash } => CasperAddress::Account(account_hash),
+      CallStackElement::StoredSession { account_hash, .. } => CasperAddress::Account(account_hash),
+      CallStackElement::StoredContract {
+        contract_package_hash,
+        ..
+      } => CasperAddress::Package(contract_package_hash),
+    }
+  }
+}
+
+// whoever called the bridge: account (session or stored session) or contract package
+pub fn get_immediate_caller() -> CasperAddress {
+  let call_stack = runtime::get_call_stack();
+
+  call_stack
+    .into_iter()
+    .rev()
+    .nth(1)
+    .map(CasperAddress::from)
+    .unwrap_or_revert()
+}
+
+// package of the running contract, the bridge itself
+pub fn get_self_package() -> ContractPackageHash {
+  let call_stack = runtime::get_call_stack();
+
+  let contract_package_hash = match call_stack.into_iter().last() {
+    Some(CallStackElement::StoredContract {
+      contract_package_hash,
+      ..
+    }) => Some(contract_package_hash),
+    _ => None,
+  };
+
+  contract_package_hash.unwrap_or_revert()
+}
@@ -0,0 +1,74 @@
+use core::marker::PhantomData;
+
+use casper_contract::{
+  contract_api::{runtime, storage},
+  unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+  bytesrepr::{FromBytes, ToBytes},
+  ApiError, CLTyped, URef,
+};
+
+use bridge_core::{DictKey, NameCache};
+
+// named key lookups are host calls, dictionary urefs are kept for the rest of
+// the call, wasm is single threaded
+static mut DICT_UREFS: NameCache<URef> = NameCache::new();
+
+pub fn get_dict_uref(name: &str) -> URef {
+  let cached = unsafe { &mut DICT_UREFS };
+
+  cached.get_or_insert_with(name, || {
+    let key = runtime::get_key(name).unwrap_or_revert();
+
+    *key.as_uref().unwrap_or_revert()
+  })
+}
+
+// items are stored as Some(value), removed ones as None,
+// both read as missing
+pub struct Dict<K, V> {
+  uref: URef,
+  marker: PhantomData<(K, V)>,
+}
+
+impl<K: DictKey, V: CLTyped + FromBytes + ToBytes> Dict<K, V> {
+  pub fn at(name: &str) -> Self {
+    Dict {
+      uref: get_dict_uref(name),
+      marker: PhantomData,
+    }
+  }
+
+  // missing item is an error
+  pub fn get(&self, key: &K) -> Result<V, ApiError> {
+    self.try_get(key)?.ok_or(ApiError::ValueNotFound)
+  }
+
+  pub fn try_get(&self, key: &K) -> Result<Option<V>, ApiError> {
+    let value: Option<Option<V>> = storage::dictionary_get(self.uref, &key.to_dict_key())?;
+
+    Ok(value.flatten())
+  }
+
+  pub fn contains(&self, key: &K) -> Result<bool, ApiError> {
+    Ok(self.try_get(key)?.is_some())
+  }
+
+  pub fn set(&self, key: &K, value: V) {
+    storage::dictionary_put(self.uref, &key.to_dict_key(), Some(value));
+  }
+
+  pub fn remove(&self, key: &K) {
+    storage::dictionary_put(self.uref, &key.to_dict_key(), Option::<V>::None);
+  }
+
+  // read, change and write back one item
+  pub fn update_with<F: FnOnce(Option<V>) -> V>(&self, key: &K, f: F) -> Result<(), ApiError> {
+    let value = f(self.try_get(key)?);
+
+    self.set(key, value);
+
+    Ok(())
+  }
+}
@@ -5,9 +5,9 @@ use casper_types::{
 };
 
 pub trait ERC20Trait {
-  fn balance_of(&self, owner: AccountHash) -> U256;
-  fn mint(&self, to: AccountHash, amount: U256);
-  fn burn(&self, from: AccountHash, amount: U256);
+  fn balance_of(&self, owner: Key) -> U256;
+  fn mint(&self, to: Key, amount: U256);
+  fn burn(&self, from: Key, amount: U256);
 
   fn new(hash: ContractHash) -> Self;
 }
@@ -21,33 +21,33 @@ impl ERC20Trait for SuperToken {
     SuperToken { hash }
   }
 
-  fn balance_of(&self, owner: AccountHash) -> U256 {
+  fn balance_of(&self, owner: Key) -> U256 {
     runtime::call_contract(
       self.hash,
       "balance_of",
       runtime_args! {
-        "owner" => Key::from(owner),
+        "owner" => owner,
       },
     )
   }
 
-  fn mint(&self, to: AccountHash, amount: U256) {
+  fn mint(&self, to: Key, amount: U256) {
     let _: () = runtime::call_contract(
       self.hash,
       "mint",
       runtime_args! {
-        "to" => Key::from(to),
+        "to" => to,
         "amount" => amount,
       },
     );
   }
 
-  fn burn(&self, from: AccountHash, amount: U256) {
+  fn burn(&self, from: Key, amount: U256) {
     let _: () = runtime::call_contract(
       self.hash,
       "burn",
       runtime_args! {
-        "from" => Key::from(from),
+        "from" => from,
         "amount" => amount
       },
     );
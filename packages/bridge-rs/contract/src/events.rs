@@ -0,0 +1,84 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+  bytesrepr::{Bytes, ToBytes},
+  contracts::NamedKeys,
+  URef,
+};
+
+use bridge_core::{
+  BridgeEvent, BRIDGE_EVENTS_COUNT, BRIDGE_EVENTS_DICT, CES_VERSION, CES_VERSION_KEY, EVENTS_DICT,
+  EVENTS_LENGTH, EVENTS_SCHEMA,
+};
+
+use crate::dict::get_dict_uref;
+use crate::shared::{empty_dict, ensure_dict, ensure_key, get_key, set_key};
+
+// casper event standard keys, schema is fixed for this contract version
+pub fn init_events(named_keys: &mut NamedKeys) {
+  named_keys.insert(EVENTS_DICT.to_string(), empty_dict(EVENTS_DICT).into());
+  named_keys.insert(EVENTS_LENGTH.to_string(), storage::new_uref(0u32).into());
+  named_keys.insert(
+    EVENTS_SCHEMA.to_string(),
+    storage::new_uref(BridgeEvent::schemas()).into(),
+  );
+  named_keys.insert(
+    CES_VERSION_KEY.to_string(),
+    storage::new_uref(CES_VERSION.to_string()).into(),
+  );
+
+  named_keys.insert(
+    BRIDGE_EVENTS_DICT.to_string(),
+    empty_dict(BRIDGE_EVENTS_DICT).into(),
+  );
+  named_keys.insert(
+    BRIDGE_EVENTS_COUNT.to_string(),
+    storage::new_uref(0u64).into(),
+  );
+}
+
+// keys added after the first install, see `migrate`
+pub fn migrate_events() {
+  ensure_dict(EVENTS_DICT);
+  ensure_key(EVENTS_LENGTH, 0u32);
+  ensure_key(CES_VERSION_KEY, CES_VERSION.to_string());
+
+  // rewritten, the event set may have changed with the new version
+  set_key(EVENTS_SCHEMA, BridgeEvent::schemas());
+
+  ensure_dict(BRIDGE_EVENTS_DICT);
+  ensure_key(BRIDGE_EVENTS_COUNT, 0u64);
+}
+
+// stored as raw bytes under the next index, same as casper-event-standard `emit`
+pub fn record_event(event: &BridgeEvent) {
+  let events_length = get_key::<u32>(EVENTS_LENGTH).unwrap_or_revert();
+
+  let bytes: Bytes = event.to_bytes().unwrap_or_revert().into();
+
+  storage::dictionary_put(
+    get_dict_uref(EVENTS_DICT),
+    &events_length.to_string(),
+    bytes,
+  );
+
+  set_key(EVENTS_LENGTH, events_length + 1);
+}
+
+// plain bytes, not an option, so the value decodes like the old event urefs
+pub fn record_wire_event(bytes: Vec<u8>) {
+  // the indexer still picks events out of the deploy's uref writes (List U8)
+  let _: URef = storage::new_uref(bytes.clone());
+
+  let events_count = get_key::<u64>(BRIDGE_EVENTS_COUNT).unwrap_or_revert();
+
+  storage::dictionary_put(
+    get_dict_uref(BRIDGE_EVENTS_DICT),
+    &events_count.to_string(),
+    Bytes::from(bytes),
+  );
+
+  set_key(BRIDGE_EVENTS_COUNT, events_count + 1);
+}
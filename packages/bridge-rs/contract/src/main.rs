@@ -9,13 +9,16 @@ use casper_types::contracts::NamedKeys;
 use endpoints::endpoint;
 // use hex;
 
+use core::convert::TryFrom;
+
+use alloc::collections::BTreeMap;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use alloc::{format, vec};
 use casper_types::{
   account::AccountHash,
   bytesrepr::{FromBytes, ToBytes},
-  CLTyped, CLValue, ContractHash, URef, U128, U256,
+  CLTyped, CLValue, ContractHash, ContractPackageHash, RuntimeArgs, U128, U256,
 };
 
 use casper_contract::{
@@ -27,220 +30,220 @@ use casper_contract::{
   unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{ApiError, CLType, EntryPoints, Key, Parameter};
+use address::{get_immediate_caller, get_self_package, CasperAddress};
 use erc20::{SuperToken, ERC20Trait};
-use shared::{
-  empty_dict, encode_hex, get_key, merge_bytes, set_key, sha256, u256_to_bytes, u256_to_hex,
-  u32_to_hex, u8_to_hex, Dict,
+use events::{init_events, migrate_events, record_event, record_wire_event};
+use bridge_core::{
+  encode_hex, merge_bytes, u256_to_bytes, verify_merkle_path, Allowance, AllowanceSet,
+  ApprovedBurnProof, ApprovedMerkleRoot, AttestedBurnCancellation, BridgeConfig, BridgeConfigured,
+  BridgeEvent, BurnProof, CallerKey, CancelledBurnProof, ChainEnabledSet, ChainInfo, ChainSet, ChainType,
+  Error, GenericAddress, HashAlgorithm, MintRequest, Nonce, ProofOfBurn, ProofOfMint, ProofRecord,
+  ProofStatesDropped,
+  RefundTimeoutSet, RefundedBurnProof, RoleGranted, RoleRevoked, Route, SchemaMigrated, States,
+  EXPIRY_GRACE,
+  GENERIC_ADDRESS_LENGTH, PROOF_VERSION,
+  ALLOWANCE_SET_SIG, APPROVED_BURN_PROOF_SIG, APPROVED_MERKLE_ROOT_SIG,
+  ATTESTED_BURN_CANCELLATION_SIG, BRIDGE_CONFIGURED_SIG, CANCELLED_BURN_PROOF_SIG,
+  CHAIN_ENABLED_SET_SIG, CHAIN_SET_SIG, PROOF_OF_BURN_V2_SIG, PROOF_OF_MINT_V2_SIG,
+  PROOF_STATES_DROPPED_SIG,
+  REFUNDED_BURN_PROOF_SIG, REFUND_TIMEOUT_SET_SIG, ROLE_GRANTED_SIG, ROLE_REVOKED_SIG,
+  SCHEMA_MIGRATED_SIG,
 };
+use dict::Dict;
+use shared::{empty_dict, ensure_dict, ensure_key, get_key, get_optional_named_arg, set_key};
 
+mod address;
+mod dict;
 mod endpoints;
 mod erc20;
+mod events;
 mod shared;
 
-#[repr(u16)]
-enum Error {
-  AlreadyApproved = 0,
-  AmountExceeded,        // 1
-  NotApprovedOrExecuted, // 2
-  ProvidedHashIsInvalid, // 3
-  InvalidCallerLength,   // 4
-  InvalidTokenLength,    // 5
-  InvalidPackage,        // 6
-  UnknownState,          // 7
-  UnknownChain,          // 8
-  UnknownAllowance,      // 9
-  AllowanceNotFound,     // 10
-  MissingApproverRole,   // 11
-}
-
-impl From<Error> for ApiError {
-  fn from(error: Error) -> Self {
-    ApiError::User(error as u16)
-  }
-}
+const ROLE_APPROVER: &str = "ROLE_APPROVER";
+const ROLE_ADMIN: &str = "ROLE_ADMIN";
+
+const BURN_PROOF_STORAGE_DICT: &str = "burn_proof_storage";
+const PROOF_RECORDS_DICT: &str = "proof_records";
+const ALLOWANCES_DICT: &str = "allowances";
+const ROUTE_TTLS_DICT: &str = "route_ttls";
+const CHAINS_DICT: &str = "chains";
+const MERKLE_ROOTS_DICT: &str = "merkle_roots";
+const NONCES_DICT: &str = "nonces";
+// 
+
+const HASH_ALGORITHM: &str = "hash_algorithm";
+const REFUND_TIMEOUT: &str = "refund_timeout";
+const MERKLE_EPOCH: &str = "merkle_epoch";
+const CHAIN_ID: &str = "chain_id";
+// install config applied, see `init`
+const INITIALIZED: &str = "initialized";
+const DEFAULT_REFUND_TIMEOUT: u64 = 7 * 24 * 60 * 60 * 1000; // 7 days in ms
+
+// installer account keys
+const BRIDGE_PACKAGE_HASH: &str = "bridge_package_hash";
+const BRIDGE_CONTRACT_HASH: &str = "bridge_contract_hash";
+
+// layout of the named keys, see `migrate`
+const SCHEMA_VERSION: &str = "schema_version";
+const CURRENT_SCHEMA_VERSION: u32 = 2;
 
-trait Thingy {
-  fn to_hex(&self) -> String;
+
+fn get_burn_proof_state(proof_hash: U256) -> States {
+  let dict: Dict<U256, u8> = Dict::at(BURN_PROOF_STORAGE_DICT);
+
+  return dict.try_get(&proof_hash).unwrap_or_revert()
+    .map(|v| States::try_from(v).unwrap_or_revert())
+    .unwrap_or(States::Undefined)
 }
 
-impl Thingy for Vec<u8> {
-  fn to_hex(&self) -> String {
-    return encode_hex(&self);
-  }
+fn set_burn_proof_state(proof_hash: U256, state: States)  {
+  let dict: Dict<U256, u8> = Dict::at(BURN_PROOF_STORAGE_DICT);
+
+  dict.set(&proof_hash, state as u8)
 }
 
-impl Thingy for U256 {
-  fn to_hex(&self) -> String {
-    return u256_to_hex(&self);
-  }
+fn find_proof_record(proof_hash: U256) -> Option<ProofRecord> {
+  let dict: Dict<U256, ProofRecord> = Dict::at(PROOF_RECORDS_DICT);
+
+  dict.try_get(&proof_hash).unwrap_or_revert()
 }
 
-impl Thingy for u8 {
-  fn to_hex(&self) -> String {
-    return u8_to_hex(&self);
-  }
+fn get_proof_record(proof_hash: U256) -> ProofRecord {
+  let dict: Dict<U256, ProofRecord> = Dict::at(PROOF_RECORDS_DICT);
+
+  dict.get(&proof_hash).unwrap_or_revert_with(Error::ProofRecordNotFound)
 }
 
-impl Thingy for u32 {
-  fn to_hex(&self) -> String {
-    return u32_to_hex(&self);
-  }
+fn set_proof_record(proof_hash: U256, record: ProofRecord) {
+  let dict: Dict<U256, ProofRecord> = Dict::at(PROOF_RECORDS_DICT);
+
+  dict.set(&proof_hash, record)
 }
 
-// WORK IN PROGRESS
-
-pub enum BridgeEvent {
-  ProofOfBurn {
-    mint_token: Bytes,
-    burn_token: Bytes,
-    mint_caller: Bytes,
-    burn_caller: Bytes,
-    burn_amount: U256,
-    burn_nonce: U256,
-    mint_chain_type: ChainType,
-    mint_chain_id: u32,
-    burn_chain_type: ChainType,
-    burn_chain_id: u32,
-    burn_proof_hash: U256,
-  },
-  ProofOfMint {
-    mint_token: Bytes,
-    burn_token: Bytes,
-    mint_caller: Bytes,
-    burn_caller: Bytes,
-    burn_amount: U256,
-    // burn_nonce ?
-    mint_chain_type: ChainType,
-    mint_chain_id: u32,
-    burn_chain_type: ChainType,
-    burn_chain_id: u32,
-    burn_proof_hash: U256,
-  },
-  ApprovedBurnProof {
-    burn_proof_hash: U256,
-  },
-  // FeeUpdated {},
+fn update_proof_record<F: FnOnce(&mut ProofRecord)>(proof_hash: U256, f: F) {
+  let dict: Dict<U256, ProofRecord> = Dict::at(PROOF_RECORDS_DICT);
+
+  dict
+    .update_with(&proof_hash, |record| {
+      // if proof not found, start from empty record
+      let mut record = record.unwrap_or_default();
+      f(&mut record);
+
+      record
+    })
+    .unwrap_or_revert()
 }
 
-#[derive(Debug, PartialEq, Eq)]
-#[repr(u8)]
-enum States {
-  Undefined = 0,
-  Burned,   // 1
-  Approved, // 2
-  Executed, // 3
+fn get_allowance_by_hash(hash: Vec<u8>) -> Allowance  {
+  let dict: Dict<Vec<u8>, u8> = Dict::at(ALLOWANCES_DICT);
+
+  return dict.try_get(&hash).unwrap_or_revert()
+    .map(|v| Allowance::try_from(v).unwrap_or_revert())
+    .unwrap_or(Allowance::Undefined)
 }
 
-impl From<u8> for States {
-  fn from(val: u8) -> Self {
-    match val {
-      1 => States::Burned,
-      2 => States::Approved,
-      3 => States::Executed,
-      _ => revert(Error::UnknownState),
-    }
-  }
+fn set_allowance_by_hash(hash: Vec<u8>, allowance: Allowance) {
+  let dict: Dict<Vec<u8>, u8> = Dict::at(ALLOWANCES_DICT);
+
+  dict.set(&hash, allowance as u8)
 }
 
-#[derive(Debug, PartialEq, Eq)]
-#[repr(u8)]
-pub enum ChainType {
-  Undefined = 0,
-  Evm,    // 1
-  Casper, // 2
-  Solana, // 3
-  Radix,  // 4
-}
-
-impl From<u8> for ChainType {
-  fn from(val: u8) -> Self {
-    match val {
-      1 => ChainType::Evm,
-      2 => ChainType::Casper,
-      3 => ChainType::Solana,
-      4 => ChainType::Radix,
-      _ => revert(Error::UnknownChain),
-    }
-  }
+
+// default proof lifetime for a route, in ms
+fn get_route_ttl_by_hash(hash: Vec<u8>) -> u64 {
+  let dict: Dict<Vec<u8>, u64> = Dict::at(ROUTE_TTLS_DICT);
+
+  // if ttl not found, proofs on this route never expire
+  dict.try_get(&hash).unwrap_or_revert().unwrap_or(0)
 }
 
+fn set_route_ttl_by_hash(hash: Vec<u8>, ttl: u64) {
+  let dict: Dict<Vec<u8>, u64> = Dict::at(ROUTE_TTLS_DICT);
 
-#[derive(Debug, PartialEq, Eq)]
-#[repr(u8)]
-pub enum Allowance {
-  Undefined = 0,
-  Allowed, // 1
-  Blocked  // 2
+  dict.set(&hash, ttl)
 }
 
-impl From<u8> for Allowance {
-  fn from(val: u8) -> Self {
-    match val {
-      1 => Allowance::Allowed,
-      2 => Allowance::Blocked,
-      _ => revert(Error::UnknownAllowance),
-    }
-  }
+fn find_chain(chain_type: u8, chain_id: u32) -> Option<ChainInfo> {
+  let dict: Dict<(u8, u32), ChainInfo> = Dict::at(CHAINS_DICT);
+
+  dict.try_get(&(chain_type, chain_id)).unwrap_or_revert()
 }
 
-const ROLE_APPROVER: &str = "ROLE_APPROVER";
+fn set_chain_info(chain_type: u8, chain_id: u32, info: ChainInfo) {
+  let dict: Dict<(u8, u32), ChainInfo> = Dict::at(CHAINS_DICT);
 
-const BURN_PROOF_STORAGE_DICT: &str = "burn_proof_storage";
-const ALLOWANCES_DICT: &str = "allowances";
-const NONCES_DICT: &str = "nonces";
-// 
+  dict.set(&(chain_type, chain_id), info)
+}
 
+// remote chain must be registered and enabled to be bridged to or from
+fn get_enabled_chain(chain_type: u8, chain_id: u32) -> ChainInfo {
+  let chain = find_chain(chain_type, chain_id).unwrap_or_revert_with(Error::UnknownChain);
 
-fn get_burn_proof_state(proof_hash: U256) -> States {
-  let dict = Dict::at(BURN_PROOF_STORAGE_DICT);
+  require(chain.enabled, Error::ChainDisabled);
 
-  return dict.get(&proof_hash.to_hex())
-    .map(|v: u8| States::from(v))
-    .unwrap_or(States::Undefined)
+  chain
 }
 
-fn set_burn_proof_state(proof_hash: U256, state: States)  {
-  let dict = Dict::at(BURN_PROOF_STORAGE_DICT);
+// root of a batch of approved proof hashes, one per epoch
+fn get_merkle_root_by_epoch(epoch: u64) -> Option<Vec<u8>> {
+  let dict: Dict<u64, Bytes> = Dict::at(MERKLE_ROOTS_DICT);
 
-  dict.set(&proof_hash.to_hex(), state as u8)
+  dict.try_get(&epoch).unwrap_or_revert().map(|root| root.into())
 }
 
-fn get_allowance_by_hash(hash: Vec<u8>) -> Allowance  {
-  let dict = Dict::at(ALLOWANCES_DICT);
+fn set_merkle_root_by_epoch(epoch: u64, root: Bytes) {
+  let dict: Dict<u64, Bytes> = Dict::at(MERKLE_ROOTS_DICT);
 
-  return dict.get(&hash.to_hex())
-    .map(|v: u8| Allowance::from(v))
-    .unwrap_or(Allowance::Undefined)
+  dict.set(&epoch, root)
 }
 
-fn set_allowance_by_hash(hash: Vec<u8>, allowance: Allowance) {
-  let dict = Dict::at(ALLOWANCES_DICT);
+// zero deadline means proof never expires
+fn is_expired(deadline: u64) -> bool {
+  deadline != 0 && get_blocktime() > deadline
+}
+
+// expired and past the grace period, no mint of it can still be in flight
+fn is_refundable_after_deadline(deadline: u64) -> bool {
+  deadline != 0 && is_expired(deadline.saturating_add(EXPIRY_GRACE))
+}
 
-  dict.set(&hash.to_hex(), allowance as u8)
+// next nonce of a burn caller (generic address)
+fn get_nonce(burn_caller: &[u8]) -> Nonce {
+  let dict: Dict<CallerKey, Nonce> = Dict::at(NONCES_DICT);
+  // if caller not found, provide zero nonce
+  dict.try_get(&CallerKey(burn_caller)).unwrap_or_revert().unwrap_or_default()
 }
 
+fn increment_nonce(burn_caller: &[u8]) {
+  let dict: Dict<CallerKey, Nonce> = Dict::at(NONCES_DICT);
 
-fn get_nonce_by_token(token: ContractHash) -> U256 {
-  let dict = Dict::at(NONCES_DICT);
-  let token_hex = token.as_bytes().to_vec().to_hex();
-  // if token not found, provide zero nonce
-  dict.get(&token_hex).unwrap_or(0.into())
+  dict
+    .update_with(&CallerKey(burn_caller), |nonce| nonce.unwrap_or_default() + 1)
+    .unwrap_or_revert()
 }
 
-fn set_nonce_by_token(token: ContractHash, nonce: U256) {
-  let dict = Dict::at(NONCES_DICT);
-  let token_hex = token.as_bytes().to_vec().to_hex();
+// every role is held by a list of accounts
+fn has_role(role: &str) -> bool {
+  let caller = runtime::get_caller();
+
+  let holders: Vec<AccountHash> = get_key(role).unwrap_or_revert();
 
-  dict.set(&token_hex, nonce)
+  holders.contains(&caller)
 }
 
 // TODO: use macro
-fn only_role(role: &str) {
-  let caller = runtime::get_caller();
+fn only_role(role: &str, error: Error) {
+  require(has_role(role), error);
+}
+
+fn get_hash_algorithm() -> HashAlgorithm {
+  let algorithm: u8 = get_key(HASH_ALGORITHM).unwrap_or_revert();
 
-  let approver_address: AccountHash = get_key(role).unwrap_or_revert();
+  HashAlgorithm::try_from(algorithm).unwrap_or_revert()
+}
 
-  require(caller == approver_address, Error::MissingApproverRole);
+fn get_blocktime() -> u64 {
+  runtime::get_blocktime().into()
 }
 
 
@@ -249,58 +252,162 @@ fn print(s: &str) {
   runtime::print(s);
 }
 
-// sha256(ProofOfBurn) = c5 e1 9c 70 19c477aefcdafef0a3df24119045c1ed6d916e92bd99388b99ba6216
-// sha256(ProofOfMint) = ab ba 24 3b 9bae2dcfb6e971870ca2e3c2a64f98edb65ab4cdd1dcda54a4fbf369
-// sha256(ApprovedBurnProof) = a4 39 a6 33 2c4168f32836e9fc3a1c1770bd6503c3718aedc53d66544aa65f0191
-
-const PROOF_OF_BURN_SIG: [u8; 4] = [0xC5, 0xE1, 0x9C, 0x70];
-const PROOF_OF_MINT_SIG: [u8; 4] = [0xab, 0xba, 0x24, 0x3b];
-const APPROVED_BURN_PROOF_SIG: [u8; 4] = [0xa4, 0x39, 0xa6, 0x33];
-
+// wire signatures come from the bridge-core table
 #[rustfmt::skip]
-pub fn emit(bridge_event: BridgeEvent) {  
+pub fn emit(bridge_event: BridgeEvent) {
+  record_event(&bridge_event);
+
   let vec_event = match bridge_event {
-    BridgeEvent::ProofOfBurn { mint_token, burn_token, mint_caller, burn_caller, burn_amount, burn_nonce, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, burn_proof_hash } => {
+    BridgeEvent::ProofOfBurn(ProofOfBurn { mint_token, burn_token, mint_caller, burn_caller, burn_amount, burn_nonce, burn_deadline, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, burn_proof_hash, hash_algorithm }) => {
       vec![
-        PROOF_OF_BURN_SIG.to_vec(),
+        PROOF_OF_BURN_V2_SIG.to_vec(),
         mint_token.to_vec(),
         burn_token.to_vec(),
         mint_caller.to_vec(),
         burn_caller.to_vec(),
         u256_to_bytes(&burn_amount),
         u256_to_bytes(&burn_nonce),
-        (mint_chain_type as u8).to_be_bytes().to_vec(),
+        burn_deadline.to_be_bytes().to_vec(),
+        mint_chain_type.to_be_bytes().to_vec(),
         mint_chain_id.to_be_bytes().to_vec(),
-        (burn_chain_type as u8).to_be_bytes().to_vec(),
+        burn_chain_type.to_be_bytes().to_vec(),
         burn_chain_id.to_be_bytes().to_vec(),
         u256_to_bytes(&burn_proof_hash),
+        hash_algorithm.to_be_bytes().to_vec(),
       ]
     },
-    BridgeEvent::ProofOfMint { mint_token, burn_token, mint_caller, burn_caller, burn_amount, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, burn_proof_hash } => {
+    BridgeEvent::ProofOfMint(ProofOfMint { mint_token, burn_token, mint_caller, burn_caller, burn_amount, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, burn_proof_hash, hash_algorithm, burn_nonce, recipient, executor, block_time }) => {
       vec![
-        PROOF_OF_MINT_SIG.to_vec(),
+        PROOF_OF_MINT_V2_SIG.to_vec(),
         mint_token.to_vec(),
         burn_token.to_vec(),
         mint_caller.to_vec(),
         burn_caller.to_vec(),
         u256_to_bytes(&burn_amount),
-        (mint_chain_type as u8).to_be_bytes().to_vec(),
+        mint_chain_type.to_be_bytes().to_vec(),
         mint_chain_id.to_be_bytes().to_vec(),
-        (burn_chain_type as u8).to_be_bytes().to_vec(),
+        burn_chain_type.to_be_bytes().to_vec(),
         burn_chain_id.to_be_bytes().to_vec(),
-        u256_to_bytes(&burn_proof_hash)
+        u256_to_bytes(&burn_proof_hash),
+        hash_algorithm.to_be_bytes().to_vec(),
+        u256_to_bytes(&burn_nonce),
+        recipient.to_vec(),
+        executor.as_bytes().to_vec(),
+        block_time.to_be_bytes().to_vec(),
       ]
     },
-    BridgeEvent::ApprovedBurnProof { burn_proof_hash } => {
+    BridgeEvent::ApprovedBurnProof(ApprovedBurnProof { burn_proof_hash }) => {
       vec![
         APPROVED_BURN_PROOF_SIG.to_vec(),
         u256_to_bytes(&burn_proof_hash)
       ]
     },
+    BridgeEvent::CancelledBurnProof(CancelledBurnProof { burn_proof_hash }) => {
+      vec![
+        CANCELLED_BURN_PROOF_SIG.to_vec(),
+        u256_to_bytes(&burn_proof_hash)
+      ]
+    },
+    BridgeEvent::RefundedBurnProof(RefundedBurnProof { burn_token, burn_caller, burn_amount, burn_proof_hash }) => {
+      vec![
+        REFUNDED_BURN_PROOF_SIG.to_vec(),
+        burn_token.to_vec(),
+        burn_caller.to_vec(),
+        u256_to_bytes(&burn_amount),
+        u256_to_bytes(&burn_proof_hash)
+      ]
+    },
+    BridgeEvent::ApprovedMerkleRoot(ApprovedMerkleRoot { epoch, root }) => {
+      vec![
+        APPROVED_MERKLE_ROOT_SIG.to_vec(),
+        epoch.to_be_bytes().to_vec(),
+        root.to_vec()
+      ]
+    },
+    BridgeEvent::AttestedBurnCancellation(AttestedBurnCancellation { burn_proof_hash }) => {
+      vec![
+        ATTESTED_BURN_CANCELLATION_SIG.to_vec(),
+        u256_to_bytes(&burn_proof_hash)
+      ]
+    },
+    BridgeEvent::AllowanceSet(AllowanceSet { mint_token, burn_token, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, allowance_hash, default_ttl }) => {
+      vec![
+        ALLOWANCE_SET_SIG.to_vec(),
+        mint_token.to_vec(),
+        burn_token.to_vec(),
+        mint_chain_type.to_be_bytes().to_vec(),
+        mint_chain_id.to_be_bytes().to_vec(),
+        burn_chain_type.to_be_bytes().to_vec(),
+        burn_chain_id.to_be_bytes().to_vec(),
+        allowance_hash.to_vec(),
+        default_ttl.to_be_bytes().to_vec(),
+      ]
+    },
+    // role name is the variable length tail
+    BridgeEvent::RoleGranted(RoleGranted { role, account }) => {
+      vec![
+        ROLE_GRANTED_SIG.to_vec(),
+        account.as_bytes().to_vec(),
+        role.into_bytes()
+      ]
+    },
+    BridgeEvent::RoleRevoked(RoleRevoked { role, account }) => {
+      vec![
+        ROLE_REVOKED_SIG.to_vec(),
+        account.as_bytes().to_vec(),
+        role.into_bytes()
+      ]
+    },
+    BridgeEvent::ChainSet(ChainSet { chain_type, chain_id, address_length, remote_bridge, enabled, name }) => {
+      vec![
+        CHAIN_SET_SIG.to_vec(),
+        chain_type.to_be_bytes().to_vec(),
+        chain_id.to_be_bytes().to_vec(),
+        address_length.to_be_bytes().to_vec(),
+        remote_bridge.to_vec(),
+        vec![enabled as u8],
+        name.into_bytes()
+      ]
+    },
+    BridgeEvent::ChainEnabledSet(ChainEnabledSet { chain_type, chain_id, enabled }) => {
+      vec![
+        CHAIN_ENABLED_SET_SIG.to_vec(),
+        chain_type.to_be_bytes().to_vec(),
+        chain_id.to_be_bytes().to_vec(),
+        vec![enabled as u8]
+      ]
+    },
+    BridgeEvent::RefundTimeoutSet(RefundTimeoutSet { refund_timeout }) => {
+      vec![
+        REFUND_TIMEOUT_SET_SIG.to_vec(),
+        refund_timeout.to_be_bytes().to_vec()
+      ]
+    },
+    BridgeEvent::BridgeConfigured(BridgeConfigured { chain_type, chain_id, hash_algorithm, refund_timeout }) => {
+      vec![
+        BRIDGE_CONFIGURED_SIG.to_vec(),
+        chain_type.to_be_bytes().to_vec(),
+        chain_id.to_be_bytes().to_vec(),
+        hash_algorithm.to_be_bytes().to_vec(),
+        refund_timeout.to_be_bytes().to_vec()
+      ]
+    },
+    BridgeEvent::SchemaMigrated(SchemaMigrated { from_version, to_version }) => {
+      vec![
+        SCHEMA_MIGRATED_SIG.to_vec(),
+        from_version.to_be_bytes().to_vec(),
+        to_version.to_be_bytes().to_vec()
+      ]
+    },
+    BridgeEvent::ProofStatesDropped(ProofStatesDropped { schema_version }) => {
+      vec![
+        PROOF_STATES_DROPPED_SIG.to_vec(),
+        schema_version.to_be_bytes().to_vec()
+      ]
+    },
   };
 
-  let bytes = merge_bytes(vec_event);
-  let _: URef = storage::new_uref(bytes);
+  record_wire_event(merge_bytes(vec_event));
 }
 
 fn require<T: Into<ApiError>>(is_true: bool, error: T) {
@@ -310,15 +417,19 @@ fn require<T: Into<ApiError>>(is_true: bool, error: T) {
 }
 
 // TODO: change on main net to chain id = 1, or any other
-const SOURCE_CHAIN_ID: u32 = 1010; // 1010 as test chain id for casper
+const DEFAULT_CHAIN_ID: u32 = 1010; // 1010 as test chain id for casper
 const SOURCE_CHAIN_TYPE: ChainType = ChainType::Casper;
 
+fn get_source_chain_id() -> u32 {
+  get_key(CHAIN_ID).unwrap_or_revert()
+}
+
 // TODO: can't bridge to itself
 // TODO: do not allow to approve hash on chain it was burned (add fee)
 #[no_mangle]
 pub fn approve_burn_proof() {
   // guards
-  only_role(ROLE_APPROVER);
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
   // 
 
   let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
@@ -327,184 +438,316 @@ pub fn approve_burn_proof() {
 
   require(some_burn_proof == States::Undefined, Error::AlreadyApproved);
 
+  approve(proof_hash);
+}
+
+// relayer bursts: one deploy for many proofs
+#[no_mangle]
+pub fn approve_burn_proofs() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let proof_hashes = runtime::get_named_arg::<Vec<U256>>("proof_hashes");
+
+  // skip already approved (or otherwise known) proofs instead of reverting the batch
+  let skip_approved = runtime::get_named_arg::<bool>("skip_approved");
+
+  for proof_hash in proof_hashes {
+    if get_burn_proof_state(proof_hash) != States::Undefined {
+      require(skip_approved, Error::AlreadyApproved);
+      continue;
+    }
+
+    approve(proof_hash);
+  }
+}
+
+// approves every proof hash under the root at once, epochs are sequential
+#[no_mangle]
+pub fn approve_merkle_root() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let root = runtime::get_named_arg::<Bytes>("root");
+
+  require(root.len() == 32, Error::InvalidMerkleRoot);
+
+  let epoch: u64 = get_key(MERKLE_EPOCH).unwrap_or_revert();
+
+  set_merkle_root_by_epoch(epoch, root.clone());
+  set_key(MERKLE_EPOCH, epoch + 1);
+
+  emit(BridgeEvent::ApprovedMerkleRoot(ApprovedMerkleRoot { epoch, root }))
+}
+
+// executed proofs are told apart from the ones never approved
+fn require_approved(state: States) {
+  require(state != States::Executed, Error::ProofAlreadyExecuted);
+  require(state == States::Approved, Error::ProofNotApproved);
+}
+
+fn approve(proof_hash: U256) {
   set_burn_proof_state(proof_hash, States::Approved);
 
-  emit(BridgeEvent::ApprovedBurnProof {
+  update_proof_record(proof_hash, |record| {
+    record.approved_at = Some(get_blocktime());
+    record.approver = Some(runtime::get_caller());
+  });
+
+  emit(BridgeEvent::ApprovedBurnProof(ApprovedBurnProof {
     burn_proof_hash: proof_hash,
-  })
+  }))
 }
 
+// account, contract or package which called the bridge directly
 fn get_generic_caller() -> Vec<u8> {
-  let pad_bytes = vec![0; 8];
-  let caller = runtime::get_caller();
-  let caller_bytes = caller.to_bytes().unwrap_or_revert();
+  let caller = get_immediate_caller();
 
-  require(caller_bytes.len() == 32, Error::InvalidCallerLength);
+  // 8 + 32, tagged
+  caller.to_generic().encode()
+}
 
-  // 8 + 32
-  merge_bytes(vec![pad_bytes, caller_bytes])
+// this bridge deployment
+fn get_generic_bridge() -> Vec<u8> {
+  // 8 + 32, tagged as package
+  CasperAddress::Package(get_self_package()).to_generic().encode()
 }
 
 fn get_generic_token(token: ContractHash) -> Vec<u8> {
-  let pad_bytes = vec![0; 8];
-  let token_bytes = token.as_bytes().to_vec();
+  // 8 + 32, tagged as contract
+  CasperAddress::Contract(token).to_generic().encode()
+}
+
+// generic address from arguments must be canonical for the chain it lives on
+fn require_address(chain_type: u8, chain_id: u32, address: &Bytes, length_error: Error) {
+  require(address.len() == GENERIC_ADDRESS_LENGTH, length_error);
+
+  if is_source_chain(chain_type, chain_id) {
+    GenericAddress::decode(SOURCE_CHAIN_TYPE, address).unwrap_or_revert();
+  } else {
+    let chain = get_enabled_chain(chain_type, chain_id);
 
-  require(token_bytes.len() == 32, Error::InvalidTokenLength);
+    GenericAddress::validate(chain_type, chain.address_length, address).unwrap_or_revert();
+  }
+}
 
-  // 8 + 32
-  merge_bytes(vec![pad_bytes, token_bytes])
+fn is_source_chain(chain_type: u8, chain_id: u32) -> bool {
+  chain_type == SOURCE_CHAIN_TYPE as u8 && chain_id == get_source_chain_id()
 }
 
 fn get_allowance_hash(
-  mint_chain_type: ChainType,
+  mint_chain_type: u8,
   mint_chain_id: u32,
-  burn_chain_type: ChainType,
+  burn_chain_type: u8,
   burn_chain_id: u32,
   mint_token: Vec<u8>,
   burn_token: Vec<u8>,
 ) -> Vec<u8> {
-  let mint_bytes = {
-    let mint_chain_type_bytes = (mint_chain_type as u8).to_be_bytes().to_vec();
-    let mint_chain_id_bytes = mint_chain_id.to_be_bytes().to_vec();
-
-    let data = merge_bytes(vec![
-      mint_chain_type_bytes,
-      mint_chain_id_bytes,
-      mint_token,
-    ]);
-
-    data
-  };
-
-  let burn_bytes = {
-    let burn_chain_type_bytes = (burn_chain_type as u8).to_be_bytes().to_vec();
-    let burn_chain_id_bytes = burn_chain_id.to_be_bytes().to_vec();
-
-    let data = merge_bytes(vec![
-      burn_chain_type_bytes,
-      burn_chain_id_bytes,
-      burn_token,
-    ]);
-
-    data
-  };
+  bridge_core::get_allowance_hash(
+    get_hash_algorithm(),
+    mint_chain_type,
+    mint_chain_id,
+    &mint_token,
+    burn_chain_type,
+    burn_chain_id,
+    &burn_token,
+  )
+}
 
-  if sha256(&mint_bytes) > sha256(&burn_bytes) {
-    sha256(&merge_bytes(vec![mint_bytes, burn_bytes]))
-  } else {
-    sha256(&merge_bytes(vec![burn_bytes, mint_bytes]))
-  }
+fn get_burn_proof_hash(proof: &BurnProof) -> Vec<u8> {
+  proof.hash(get_hash_algorithm()).unwrap_or_revert()
 }
 
 // TODO: add fee
 // TODO: add bot detection
 #[no_mangle]
 pub fn mint_with_burn_proof() {
-  let mint_token = runtime::get_named_arg::<ContractHash>("mint_token"); // use native address type explicitly
+  let (mint_token, params, burn_proof_hash) = get_mint_proof();
 
-  let burn_token = runtime::get_named_arg::<Bytes>("burn_token");
-  let burn_caller = runtime::get_named_arg::<Bytes>("burn_caller");
+  require_approved(get_burn_proof_state(burn_proof_hash));
 
-  let burn_chain_type = runtime::get_named_arg::<u8>("burn_chain_type");
-  let burn_chain_id = runtime::get_named_arg::<u32>("burn_chain_id");
+  execute_mint(mint_token, params, burn_proof_hash);
+}
 
-  let burn_amount = runtime::get_named_arg::<U256>("burn_amount");
+// mints several approved proofs at once, one token call per token,
+// any invalid proof reverts the whole batch
+#[no_mangle]
+pub fn mint_with_burn_proofs() {
+  let requests = runtime::get_named_arg::<Vec<MintRequest>>("requests");
 
-  let burn_proof_hash = runtime::get_named_arg::<U256>("burn_proof_hash");
-  let burn_nonce = runtime::get_named_arg::<U256>("burn_nonce");
+  let mut amounts: BTreeMap<ContractHash, U256> = BTreeMap::new();
 
-  require(burn_caller.len() == 40, Error::InvalidCallerLength);
-  require(burn_token.len() == 41, Error::InvalidTokenLength);
+  for request in requests {
+    let (mint_token, params, burn_proof_hash) = verify_mint_request(request);
 
-  let allowance_hash = get_allowance_hash(
-    SOURCE_CHAIN_TYPE,
-    SOURCE_CHAIN_ID,
-    ChainType::from(burn_chain_type),
-    burn_chain_id,
-    get_generic_token(mint_token),
-    burn_token.clone().into()
-  );
+    // executed proofs fail here, so duplicates in the batch revert too
+    require_approved(get_burn_proof_state(burn_proof_hash));
+
+    let amount = amounts.entry(mint_token).or_insert_with(U256::zero);
+    *amount = amount.checked_add(params.burn_amount).unwrap_or_revert();
+
+    record_mint(params, burn_proof_hash);
+  }
+
+  // recipient is the immediate caller for every proof of the batch
+  let recipient = get_immediate_caller();
+
+  for (mint_token, amount) in amounts {
+    SuperToken::new(mint_token).mint(recipient.into(), amount);
+  }
+}
+
+// proof is approved as a leaf of a merkle root, instead of one by one
+#[no_mangle]
+pub fn mint_with_merkle_proof() {
+  let (mint_token, params, burn_proof_hash) = get_mint_proof();
 
+  let epoch = runtime::get_named_arg::<u64>("epoch");
+  let merkle_path = runtime::get_named_arg::<Vec<Bytes>>("merkle_path");
+
+  let root = get_merkle_root_by_epoch(epoch).unwrap_or_revert_with(Error::MerkleRootNotFound);
+
+  // double spend guard, leaf is marked as executed same as single approvals
+  let state = get_burn_proof_state(burn_proof_hash);
+
+  require(state != States::Executed, Error::ProofAlreadyExecuted);
   require(
-    get_allowance_by_hash(allowance_hash) == Allowance::Allowed,
-     Error::AllowanceNotFound
+    state == States::Undefined || state == States::Approved,
+    Error::ProofNotApproved,
   );
 
-  let burn_proof_status = get_burn_proof_state(burn_proof_hash);
+  let merkle_path: Vec<Vec<u8>> = merkle_path.into_iter().map(Vec::from).collect();
 
   require(
-    burn_proof_status == States::Approved,
-    Error::NotApprovedOrExecuted,
+    verify_merkle_path(&root, &u256_to_bytes(&burn_proof_hash), &merkle_path),
+    Error::InvalidMerkleProof,
   );
 
-  let computed_burn_proof_hash = {
-    let mint_caller_bytes = get_generic_caller();
-    let burn_caller_bytes = burn_caller.to_vec();
+  execute_mint(mint_token, params, burn_proof_hash);
+}
+
+// reads mint arguments and checks them against the provided proof hash
+fn get_mint_proof() -> (ContractHash, BurnProof, U256) {
+  let request = MintRequest {
+    mint_token: runtime::get_named_arg::<ContractHash>("mint_token"), // use native address type explicitly
+    burn_token: runtime::get_named_arg::<Bytes>("burn_token"),
+    burn_caller: runtime::get_named_arg::<Bytes>("burn_caller"),
+    burn_chain_type: runtime::get_named_arg::<u8>("burn_chain_type"),
+    burn_chain_id: runtime::get_named_arg::<u32>("burn_chain_id"),
+    burn_amount: runtime::get_named_arg::<U256>("burn_amount"),
+    burn_proof_hash: runtime::get_named_arg::<U256>("burn_proof_hash"),
+    burn_nonce: runtime::get_named_arg::<Nonce>("burn_nonce"),
+    burn_deadline: runtime::get_named_arg::<u64>("burn_deadline"),
+  };
 
-    let mint_token_bytes = get_generic_token(mint_token);
-    let burn_token_bytes = burn_token.to_vec();
+  verify_mint_request(request)
+}
 
-    // burn & mint
-    let burn_amount_bytes = u256_to_bytes(&burn_amount);
+fn verify_mint_request(request: MintRequest) -> (ContractHash, BurnProof, U256) {
+  let MintRequest {
+    mint_token,
+    burn_token,
+    burn_caller,
+    burn_chain_type,
+    burn_chain_id,
+    burn_amount,
+    burn_proof_hash,
+    burn_nonce,
+    burn_deadline,
+  } = request;
 
-    let mint_chain_type_bytes = (SOURCE_CHAIN_TYPE as u8).to_be_bytes().to_vec();
-    let mint_chain_id_bytes = SOURCE_CHAIN_ID.to_be_bytes().to_vec();
+  require_address(burn_chain_type, burn_chain_id, &burn_caller, Error::InvalidBurnCallerLength);
+  require_address(burn_chain_type, burn_chain_id, &burn_token, Error::InvalidBurnTokenLength);
 
-    let burn_chain_type_bytes = burn_chain_type.to_be_bytes().to_vec();
-    let burn_chain_id_bytes = burn_chain_id.to_be_bytes().to_vec();
+  let allowance_hash = get_allowance_hash(
+    SOURCE_CHAIN_TYPE as u8,
+    get_source_chain_id(),
+    burn_chain_type,
+    burn_chain_id,
+    get_generic_token(mint_token),
+    burn_token.clone().into()
+  );
 
-    let burn_nonce_bytes = u256_to_bytes(&burn_nonce);
+  require(
+    get_allowance_by_hash(allowance_hash) == Allowance::Allowed,
+     Error::AllowanceNotFound
+  );
 
-    #[rustfmt::skip]
-    let data = merge_bytes(vec![
-      mint_caller_bytes, burn_caller_bytes,
-      mint_token_bytes, burn_token_bytes,
-      burn_amount_bytes,
-      mint_chain_type_bytes, mint_chain_id_bytes,
-      burn_chain_type_bytes, burn_chain_id_bytes,
-      burn_nonce_bytes
-    ]);
+  require(!is_expired(burn_deadline), Error::ProofExpired);
 
-    require(
-      data.len() ==  234, // 234 = 40 + 40 + 40 + 40 + 32 + 1 + 4 + 1 + 4 + 32
-      Error::InvalidPackage,
-    );
+  let remote_bridge = get_enabled_chain(burn_chain_type, burn_chain_id).remote_bridge;
 
-    sha256(&data)
+  let params = BurnProof {
+    proof_version: PROOF_VERSION,
+    mint_caller: get_generic_caller().into(),
+    burn_caller,
+    mint_token: get_generic_token(mint_token).into(),
+    burn_token,
+    burn_amount,
+    mint_chain_type: SOURCE_CHAIN_TYPE as u8,
+    mint_chain_id: get_source_chain_id(),
+    burn_chain_type,
+    burn_chain_id,
+    burn_nonce,
+    burn_deadline,
+    mint_bridge: get_generic_bridge().into(),
+    burn_bridge: remote_bridge,
   };
 
+  let computed_burn_proof_hash = get_burn_proof_hash(&params);
+
   let are_hashes_equal = computed_burn_proof_hash == u256_to_bytes(&burn_proof_hash);
 
   require(are_hashes_equal, Error::ProvidedHashIsInvalid);
 
-  let burn_proof_hash = U256::from_big_endian(&computed_burn_proof_hash);
+  (mint_token, params, burn_proof_hash)
+}
 
-  set_burn_proof_state(burn_proof_hash, States::Executed);
+fn execute_mint(mint_token: ContractHash, params: BurnProof, burn_proof_hash: U256) {
+  let burn_amount = params.burn_amount;
+
+  record_mint(params, burn_proof_hash);
 
   let token = SuperToken::new(mint_token);
-  let caller = runtime::get_caller();
+  let recipient = get_immediate_caller();
 
-  token.mint(caller, burn_amount);
+  token.mint(recipient.into(), burn_amount);
+}
 
-  emit(BridgeEvent::ProofOfMint {
-    mint_token: get_generic_token(mint_token).into(),
-    burn_token,
-    mint_caller: get_generic_caller().into(),
-    burn_caller,
-    burn_amount,
-    // burn_nonce ?
-    mint_chain_type: SOURCE_CHAIN_TYPE,
-    mint_chain_id: SOURCE_CHAIN_ID,
-    burn_chain_type: ChainType::from(burn_chain_type),
-    burn_chain_id,
-    burn_proof_hash
+// marks the proof as executed, token transfer is up to the caller
+fn record_mint(params: BurnProof, burn_proof_hash: U256) {
+  set_burn_proof_state(burn_proof_hash, States::Executed);
+
+  update_proof_record(burn_proof_hash, |record| {
+    record.params = Some(params.clone());
+    record.executed_at = Some(get_blocktime());
   });
+
+  emit(BridgeEvent::ProofOfMint(ProofOfMint {
+    mint_token: params.mint_token,
+    burn_token: params.burn_token,
+    mint_caller: params.mint_caller,
+    burn_caller: params.burn_caller,
+    burn_amount: params.burn_amount,
+    mint_chain_type: params.mint_chain_type,
+    mint_chain_id: params.mint_chain_id,
+    burn_chain_type: params.burn_chain_type,
+    burn_chain_id: params.burn_chain_id,
+    burn_proof_hash,
+    hash_algorithm: get_hash_algorithm() as u8,
+    burn_nonce: params.burn_nonce,
+    recipient: get_generic_caller().into(),
+    executor: runtime::get_caller(),
+    block_time: get_blocktime(),
+  }));
 }
 
 #[no_mangle]
 pub fn set_allowance() {
   // guards
-  only_role(ROLE_APPROVER);
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
   // 
 
   let mint_token = runtime::get_named_arg::<Bytes>("mint_token");
@@ -516,20 +759,75 @@ pub fn set_allowance() {
   let burn_chain_type = runtime::get_named_arg::<u8>("burn_chain_type");
   let burn_chain_id = runtime::get_named_arg::<u32>("burn_chain_id");
 
-  require(mint_token.len() == 40, Error::InvalidTokenLength);
-  require(burn_token.len() == 40, Error::InvalidTokenLength);
+  // optional, proof lifetime in ms applied when burner gives no deadline
+  let default_ttl = get_optional_named_arg::<u64>("default_ttl");
+
+  add_route(Route {
+    mint_token,
+    burn_token,
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type,
+    burn_chain_id,
+    default_ttl,
+  });
+}
+
+fn add_route(route: Route) {
+  require_address(
+    route.mint_chain_type,
+    route.mint_chain_id,
+    &route.mint_token,
+    Error::InvalidMintTokenLength,
+  );
+  require_address(
+    route.burn_chain_type,
+    route.burn_chain_id,
+    &route.burn_token,
+    Error::InvalidBurnTokenLength,
+  );
+
+  set_route(route);
+}
+
+// route without address checks, see `add_route`
+fn set_route(route: Route) {
+  let Route {
+    mint_token,
+    burn_token,
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type,
+    burn_chain_id,
+    default_ttl,
+  } = route;
 
   let allowance_hash = get_allowance_hash(
-    ChainType::from(mint_chain_type),
+    mint_chain_type,
     mint_chain_id,
-    ChainType::from(burn_chain_type),
+    burn_chain_type,
     burn_chain_id, 
     mint_token.to_vec(),
     burn_token.to_vec(),
   );
 
-  set_allowance_by_hash(allowance_hash, Allowance::Allowed);
-}
+  if let Some(ttl) = default_ttl {
+    set_route_ttl_by_hash(allowance_hash.clone(), ttl);
+  }
+
+  set_allowance_by_hash(allowance_hash.clone(), Allowance::Allowed);
+
+  emit(BridgeEvent::AllowanceSet(AllowanceSet {
+    mint_token,
+    burn_token,
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type,
+    burn_chain_id,
+    default_ttl: get_route_ttl_by_hash(allowance_hash.clone()),
+    allowance_hash: allowance_hash.into(),
+  }));
+}
 
 #[no_mangle]
 pub fn burn_and_create_proof() {
@@ -543,25 +841,41 @@ pub fn burn_and_create_proof() {
 
   let burn_amount = runtime::get_named_arg::<U256>("burn_amount");
 
-  require(mint_caller.len() == 40, Error::InvalidCallerLength);
-  require(mint_token.len() == 40, Error::InvalidTokenLength);
+  // optional, absolute block time in ms
+  let deadline = get_optional_named_arg::<u64>("deadline");
+
+  require_address(mint_chain_type, mint_chain_id, &mint_caller, Error::InvalidMintCallerLength);
+  require_address(mint_chain_type, mint_chain_id, &mint_token, Error::InvalidMintTokenLength);
 
   let allowance_hash = get_allowance_hash(
-    ChainType::from(mint_chain_type),
+    mint_chain_type,
     mint_chain_id,
-    SOURCE_CHAIN_TYPE,
-    SOURCE_CHAIN_ID,
+    SOURCE_CHAIN_TYPE as u8,
+    get_source_chain_id(),
     mint_token.clone().into(),
     get_generic_token(burn_token),
   );
 
+  let burn_deadline = match deadline {
+    Some(deadline) => {
+      require(deadline > get_blocktime(), Error::InvalidDeadline);
+      deadline
+    }
+    None => match get_route_ttl_by_hash(allowance_hash.clone()) {
+      0 => 0,
+      ttl => get_blocktime().saturating_add(ttl),
+    },
+  };
+
   require(
     get_allowance_by_hash(allowance_hash) == Allowance::Allowed,
      Error::AllowanceNotFound
   );
 
-  let burn_nonce = get_nonce_by_token(burn_token);
-  let caller = runtime::get_caller();
+  let remote_bridge = get_enabled_chain(mint_chain_type, mint_chain_id).remote_bridge;
+
+  let burn_nonce = get_nonce(&get_generic_caller());
+  let caller: Key = get_immediate_caller().into();
 
   let token = SuperToken::new(burn_token);
 
@@ -571,77 +885,648 @@ pub fn burn_and_create_proof() {
 
   require(burn_amount <= balance, Error::AmountExceeded);
 
-  let computed_burn_proof_hash = {
-    let mint_caller_bytes = mint_caller.to_vec();
-    let burn_caller_bytes = get_generic_caller();
-
-    let mint_token_bytes = mint_token.to_vec();
-    let burn_token_bytes = get_generic_token(burn_token);
-
-    // burn & mint
-    let burn_amount_bytes = u256_to_bytes(&burn_amount);
-
-    let mint_chain_type_bytes = (mint_chain_type as u8).to_be_bytes().to_vec();
-    let mint_chain_id_bytes = mint_chain_id.to_be_bytes().to_vec();
-
-    let burn_chain_type_bytes = (SOURCE_CHAIN_TYPE as u8).to_be_bytes().to_vec();
-    let burn_chain_id_bytes =  SOURCE_CHAIN_ID.to_be_bytes().to_vec();
-
-    let burn_nonce_bytes = u256_to_bytes(&burn_nonce);
-
-    #[rustfmt::skip]
-    let data = merge_bytes(vec![
-      mint_caller_bytes, burn_caller_bytes,
-      mint_token_bytes, burn_token_bytes,
-      burn_amount_bytes,
-      mint_chain_type_bytes, mint_chain_id_bytes,
-      burn_chain_type_bytes, burn_chain_id_bytes,
-      burn_nonce_bytes
-    ]);
-    require(
-      data.len() ==  234, // 234 = 40 + 40 + 40 + 40 + 32 + 1 + 4 + 1 + 4 + 32
-      Error::InvalidPackage,
-    );
-
-    sha256(&data)
+  let params = BurnProof {
+    proof_version: PROOF_VERSION,
+    mint_caller: mint_caller.clone(),
+    burn_caller: get_generic_caller().into(),
+    mint_token: mint_token.clone(),
+    burn_token: get_generic_token(burn_token).into(),
+    burn_amount,
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type: SOURCE_CHAIN_TYPE as u8,
+    burn_chain_id: get_source_chain_id(),
+    burn_nonce,
+    burn_deadline,
+    mint_bridge: remote_bridge,
+    burn_bridge: get_generic_bridge().into(),
   };
 
-  print(&format!("burn_proof_hash {}", computed_burn_proof_hash.to_hex()));
+  let computed_burn_proof_hash = get_burn_proof_hash(&params);
+
+  print(&format!("burn_proof_hash {}", encode_hex(&computed_burn_proof_hash)));
 
   let burn_proof_hash = U256::from_big_endian(&computed_burn_proof_hash);
 
   set_burn_proof_state(burn_proof_hash, States::Burned);
 
+  set_proof_record(burn_proof_hash, ProofRecord {
+    params: Some(params),
+    burned_at: Some(get_blocktime()),
+    ..ProofRecord::default()
+  });
+
   token.burn(caller, burn_amount);
 
-  emit(BridgeEvent::ProofOfBurn {
+  emit(BridgeEvent::ProofOfBurn(ProofOfBurn {
     mint_token,
     burn_token: get_generic_token(burn_token).into(),
     mint_caller,
     burn_caller: get_generic_caller().into(),
     burn_amount,
     burn_nonce,
-    mint_chain_type: ChainType::from(mint_chain_type),
+    burn_deadline,
+    mint_chain_type,
     mint_chain_id,
-    burn_chain_type: SOURCE_CHAIN_TYPE,
-    burn_chain_id: SOURCE_CHAIN_ID,
+    burn_chain_type: SOURCE_CHAIN_TYPE as u8,
+    burn_chain_id: get_source_chain_id(),
     burn_proof_hash,
+    hash_algorithm: get_hash_algorithm() as u8,
+  }));
+
+  increment_nonce(&get_generic_caller());
+
+  // contracts get the hash directly, accounts via the burn session
+  runtime::ret(CLValue::from_t(burn_proof_hash).unwrap_or_revert())
+}
+
+// destination side: approver marks a proof that must never be minted here,
+// relayer then attests the cancellation on the burn side
+#[no_mangle]
+pub fn cancel_burn_proof() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  let state = get_burn_proof_state(proof_hash);
+
+  require(
+    state == States::Undefined || state == States::Approved,
+    Error::NotCancellable,
+  );
+
+  set_burn_proof_state(proof_hash, States::Cancelled);
+
+  update_proof_record(proof_hash, |record| {
+    record.cancelled_at = Some(get_blocktime());
   });
 
-  let next_nonce = burn_nonce + 1;
-  set_nonce_by_token(burn_token, next_nonce)
+  emit(BridgeEvent::CancelledBurnProof(CancelledBurnProof {
+    burn_proof_hash: proof_hash,
+  }))
+}
+
+// burn side: destination cancelled the proof, so it can be refunded
+#[no_mangle]
+pub fn attest_burn_cancellation() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  require(
+    get_burn_proof_state(proof_hash) == States::Burned,
+    Error::ProofNotBurned,
+  );
+
+  let mut record = get_proof_record(proof_hash);
+  record.cancel_attested = true;
+
+  set_proof_record(proof_hash, record);
 
-  // TODO: return burn proof hash
-  // ret(computed_burn_proof_hash)
+  emit(BridgeEvent::AttestedBurnCancellation(AttestedBurnCancellation {
+    burn_proof_hash: proof_hash,
+  }))
 }
 
+#[no_mangle]
+pub fn refund_burn_proof() {
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  require(
+    get_burn_proof_state(proof_hash) == States::Burned,
+    Error::ProofNotBurned,
+  );
+
+  let mut record = get_proof_record(proof_hash);
+  let params = record.params.clone().unwrap_or_revert_with(Error::ProofParamsNotFound);
+  let burned_at = record.burned_at.unwrap_or_revert_with(Error::BurnTimeNotFound);
+
+  let refund_timeout: u64 = get_key(REFUND_TIMEOUT).unwrap_or_revert();
+
+  require(
+    get_blocktime() >= burned_at.saturating_add(refund_timeout),
+    Error::RefundLocked,
+  );
+
+  // either destination side cancelled it, its deadline passed with some margin,
+  // so it can't be minted anymore, or admin decided to refund
+  require(
+    record.cancel_attested
+      || is_refundable_after_deadline(params.burn_deadline)
+      || has_role(ROLE_ADMIN),
+    Error::RefundNotAllowed,
+  );
+
+  set_burn_proof_state(proof_hash, States::Refunded);
+
+  record.refunded_at = Some(get_blocktime());
+
+  set_proof_record(proof_hash, record);
+
+  let burn_token = match CasperAddress::from_generic(&params.burn_token).unwrap_or_revert() {
+    CasperAddress::Contract(hash) => hash,
+    _ => revert(Error::InvalidAddressTag),
+  };
+  let burner = CasperAddress::from_generic(&params.burn_caller).unwrap_or_revert();
+
+  let token = SuperToken::new(burn_token);
+
+  token.mint(burner.into(), params.burn_amount);
+
+  emit(BridgeEvent::RefundedBurnProof(RefundedBurnProof {
+    burn_token: params.burn_token,
+    burn_caller: params.burn_caller,
+    burn_amount: params.burn_amount,
+    burn_proof_hash: proof_hash,
+  }));
+}
+
+// view
+#[no_mangle]
+pub fn get_burn_proof_record() {
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  let record = find_proof_record(proof_hash);
+
+  runtime::ret(CLValue::from_t(record).unwrap_or_revert())
+}
+
+// view, lets clients precompute the proof hash before burning
+#[no_mangle]
+pub fn get_next_nonce() {
+  let burn_caller = runtime::get_named_arg::<Bytes>("burn_caller");
+
+  let nonce = get_nonce(&burn_caller);
+
+  runtime::ret(CLValue::from_t(nonce).unwrap_or_revert())
+}
+
+// view, States as u8, undefined for unknown proofs
+#[no_mangle]
+pub fn get_proof_state() {
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  let state = get_burn_proof_state(proof_hash);
+
+  runtime::ret(CLValue::from_t(state as u8).unwrap_or_revert())
+}
+
+// view, Allowance as u8 of a route, both directions share it
+#[no_mangle]
+pub fn get_allowance() {
+  let mint_token = runtime::get_named_arg::<Bytes>("mint_token");
+  let burn_token = runtime::get_named_arg::<Bytes>("burn_token");
+
+  let mint_chain_type = runtime::get_named_arg::<u8>("mint_chain_type");
+  let mint_chain_id = runtime::get_named_arg::<u32>("mint_chain_id");
+
+  let burn_chain_type = runtime::get_named_arg::<u8>("burn_chain_type");
+  let burn_chain_id = runtime::get_named_arg::<u32>("burn_chain_id");
+
+  let allowance_hash = get_allowance_hash(
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type,
+    burn_chain_id,
+    mint_token.to_vec(),
+    burn_token.to_vec(),
+  );
+
+  let allowance = get_allowance_by_hash(allowance_hash);
+
+  runtime::ret(CLValue::from_t(allowance as u8).unwrap_or_revert())
+}
+
+// view
+#[no_mangle]
+pub fn get_config() {
+  let config = BridgeConfig {
+    chain_type: SOURCE_CHAIN_TYPE as u8,
+    chain_id: get_source_chain_id(),
+    hash_algorithm: get_hash_algorithm() as u8,
+    refund_timeout: get_key(REFUND_TIMEOUT).unwrap_or_revert(),
+    merkle_epoch: get_key(MERKLE_EPOCH).unwrap_or_revert(),
+    schema_version: get_key(SCHEMA_VERSION).unwrap_or_revert(),
+  };
+
+  runtime::ret(CLValue::from_t(config).unwrap_or_revert())
+}
+
+// view, accounts holding the role
+#[no_mangle]
+pub fn get_roles() {
+  let role = runtime::get_named_arg::<String>("role");
+
+  require(role == ROLE_ADMIN || role == ROLE_APPROVER, Error::UnknownRole);
+
+  let holders: Vec<AccountHash> = get_key(&role).unwrap_or_revert();
+
+  runtime::ret(CLValue::from_t(holders).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub fn set_refund_timeout() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let refund_timeout = runtime::get_named_arg::<u64>("refund_timeout");
+
+  set_key(REFUND_TIMEOUT, refund_timeout);
+
+  emit(BridgeEvent::RefundTimeoutSet(RefundTimeoutSet { refund_timeout }));
+}
+
+// roles are held by several accounts, e.g. one approver per validator
+#[no_mangle]
+pub fn grant_role() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let role = runtime::get_named_arg::<String>("role");
+  let account = runtime::get_named_arg::<AccountHash>("account");
+
+  require(role == ROLE_ADMIN || role == ROLE_APPROVER, Error::UnknownRole);
+
+  let mut holders: Vec<AccountHash> = get_key(&role).unwrap_or_revert();
+
+  require(!holders.contains(&account), Error::RoleAlreadyGranted);
+
+  holders.push(account);
+  set_key(&role, holders);
+
+  emit(BridgeEvent::RoleGranted(RoleGranted { role, account }));
+}
+
+#[no_mangle]
+pub fn revoke_role() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let role = runtime::get_named_arg::<String>("role");
+  let account = runtime::get_named_arg::<AccountHash>("account");
+
+  require(role == ROLE_ADMIN || role == ROLE_APPROVER, Error::UnknownRole);
+
+  let mut holders: Vec<AccountHash> = get_key(&role).unwrap_or_revert();
+
+  require(holders.contains(&account), Error::RoleNotGranted);
+  // nobody could grant roles or change the config anymore
+  require(role != ROLE_ADMIN || holders.len() > 1, Error::LastAdmin);
+
+  holders.retain(|holder| *holder != account);
+  set_key(&role, holders);
+
+  emit(BridgeEvent::RoleRevoked(RoleRevoked { role, account }));
+}
+
+// registers or replaces a remote chain, new chain types need no upgrade
+#[no_mangle]
+pub fn set_chain() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let chain_type = runtime::get_named_arg::<u8>("chain_type");
+  let chain_id = runtime::get_named_arg::<u32>("chain_id");
+  let address_length = runtime::get_named_arg::<u8>("address_length");
+  let name = runtime::get_named_arg::<String>("name");
+  let remote_bridge = runtime::get_named_arg::<Bytes>("remote_bridge");
+
+  // optional, enabled by default
+  let enabled = get_optional_named_arg::<bool>("enabled").unwrap_or(true);
+
+  add_chain(chain_type, chain_id, ChainInfo {
+    enabled,
+    address_length,
+    name,
+    remote_bridge,
+  });
+}
+
+fn add_chain(chain_type: u8, chain_id: u32, info: ChainInfo) {
+  let ChainInfo {
+    enabled,
+    address_length,
+    name,
+    remote_bridge,
+  } = info;
+
+  require(chain_type != ChainType::Undefined as u8, Error::UnknownChainType);
+  require(!is_source_chain(chain_type, chain_id), Error::SourceChainNotRemote);
+
+  require(
+    address_length > 0 && address_length as usize <= GENERIC_ADDRESS_LENGTH,
+    Error::InvalidAddressLength,
+  );
+
+  // built-in chain types keep their own address length
+  if let Ok(known) = ChainType::try_from(chain_type) {
+    require(known.address_length() == Some(address_length), Error::AddressLengthMismatch);
+  }
+
+  require(remote_bridge.len() == GENERIC_ADDRESS_LENGTH, Error::InvalidBridgeLength);
+  GenericAddress::validate(chain_type, address_length, &remote_bridge).unwrap_or_revert();
+
+  set_chain_info(chain_type, chain_id, ChainInfo {
+    enabled,
+    address_length,
+    name: name.clone(),
+    remote_bridge: remote_bridge.clone(),
+  });
+
+  emit(BridgeEvent::ChainSet(ChainSet {
+    chain_type,
+    chain_id,
+    address_length,
+    remote_bridge,
+    enabled,
+    name,
+  }));
+}
+
+// disabled chain keeps its routes, but nothing can be burned to or minted from it
+#[no_mangle]
+pub fn set_chain_enabled() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let chain_type = runtime::get_named_arg::<u8>("chain_type");
+  let chain_id = runtime::get_named_arg::<u32>("chain_id");
+  let enabled = runtime::get_named_arg::<bool>("enabled");
+
+  let mut chain = find_chain(chain_type, chain_id).unwrap_or_revert_with(Error::UnknownChain);
+  chain.enabled = enabled;
+
+  set_chain_info(chain_type, chain_id, chain);
+
+  emit(BridgeEvent::ChainEnabledSet(ChainEnabledSet {
+    chain_type,
+    chain_id,
+    enabled,
+  }));
+}
+
+// view
+#[no_mangle]
+pub fn get_chain() {
+  let chain_type = runtime::get_named_arg::<u8>("chain_type");
+  let chain_id = runtime::get_named_arg::<u32>("chain_id");
+
+  let chain = find_chain(chain_type, chain_id);
+
+  runtime::ret(CLValue::from_t(chain).unwrap_or_revert())
+}
+
+// install config which needs the contract context, applied once by the install
+// session in the same deploy, so a fresh bridge is configured from its first block
+#[no_mangle]
+pub fn init() {
+  // guards
+  require(!get_key::<bool>(INITIALIZED).unwrap_or_revert(), Error::AlreadyInitialized);
+  //
+
+  let chains = runtime::get_named_arg::<Vec<(u8, u32, ChainInfo)>>("chains");
+  let routes = runtime::get_named_arg::<Vec<Route>>("routes");
+
+  // the install session can't emit, so settings and roles chosen there are announced here
+  emit(BridgeEvent::BridgeConfigured(BridgeConfigured {
+    chain_type: SOURCE_CHAIN_TYPE as u8,
+    chain_id: get_source_chain_id(),
+    hash_algorithm: get_hash_algorithm() as u8,
+    refund_timeout: get_key(REFUND_TIMEOUT).unwrap_or_revert(),
+  }));
+
+  for role in [ROLE_ADMIN, ROLE_APPROVER].iter() {
+    let holders: Vec<AccountHash> = get_key(role).unwrap_or_revert();
+
+    for account in holders {
+      emit(BridgeEvent::RoleGranted(RoleGranted {
+        role: role.to_string(),
+        account,
+      }));
+    }
+  }
+
+  // chains first, routes are checked against them
+  for (chain_type, chain_id, info) in chains {
+    add_chain(chain_type, chain_id, info);
+  }
+
+  for route in routes {
+    add_route(route);
+  }
+
+  set_key(INITIALIZED, true);
+}
+
+// run by the upgrade session right after add_contract_version, the named keys
+// of the previous version are already carried over
+#[no_mangle]
+pub fn migrate() {
+  // versions installed before schema versioning have no key
+  let from_version = get_key::<u32>(SCHEMA_VERSION).unwrap_or(0);
+  let mut version = from_version;
+
+  require(version <= CURRENT_SCHEMA_VERSION, Error::UnknownSchemaVersion);
+
+  while version < CURRENT_SCHEMA_VERSION {
+    migrate_from(version);
+    version += 1;
+  }
+
+  set_key(SCHEMA_VERSION, version);
+
+  // guards, after the steps so roles are read in the current layout,
+  // the revert undoes the migration
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  // same version upgrades only replace entry points
+  if from_version != version {
+    emit(BridgeEvent::SchemaMigrated(SchemaMigrated {
+      from_version,
+      to_version: version,
+    }));
+  }
+}
+
+// one arm per schema version, bump CURRENT_SCHEMA_VERSION with each new arm
+fn migrate_from(version: u32) {
+  match version {
+    // unversioned installs, create whatever keys the old layout lacks
+    0 => {
+      for name in [
+        NONCES_DICT,
+        ROUTE_TTLS_DICT,
+        CHAINS_DICT,
+        BURN_PROOF_STORAGE_DICT,
+        ALLOWANCES_DICT,
+        PROOF_RECORDS_DICT,
+        MERKLE_ROOTS_DICT,
+      ]
+      .iter()
+      {
+        ensure_dict(name);
+      }
+
+      ensure_key(MERKLE_EPOCH, 0u64);
+      ensure_key(REFUND_TIMEOUT, DEFAULT_REFUND_TIMEOUT);
+      ensure_key(HASH_ALGORITHM, HashAlgorithm::Sha256 as u8);
+
+      migrate_events();
+
+      // roles in the schema 1 layout, from the old access model: the installing account
+      // already is the approver, the upgrading account holds `bridge_access_token` and
+      // becomes admin
+      let caller = runtime::get_caller();
+
+      for role in [ROLE_ADMIN, ROLE_APPROVER].iter() {
+        ensure_key(role, caller);
+
+        let account: AccountHash = get_key(role).unwrap_or_revert();
+
+        emit(BridgeEvent::RoleGranted(RoleGranted {
+          role: role.to_string(),
+          account,
+        }));
+      }
+
+      // allowances were hashed over untagged casper addresses and hashes can't be listed
+      // on chain, so the upgrader passes the routes to keep, in the current format
+      let legacy_routes = get_optional_named_arg::<Vec<Route>>("legacy_routes");
+
+      for route in legacy_routes.unwrap_or_default() {
+        migrate_legacy_route(route);
+      }
+
+      // proof hashes of the old preimage can't be derived again: old burns have no record
+      // to refund from and old approvals never match a current mint, the states are
+      // left in place but no longer honored
+      emit(BridgeEvent::ProofStatesDropped(ProofStatesDropped { schema_version: 0 }));
+    }
+    // roles became account lists, chain id a named key,
+    // allowance and proof hashes are the same as in the current layout
+    1 => {
+      for role in [ROLE_ADMIN, ROLE_APPROVER].iter() {
+        let holder: AccountHash = get_key(role).unwrap_or_revert();
+
+        set_key(role, vec![holder]);
+      }
+
+      ensure_key(CHAIN_ID, DEFAULT_CHAIN_ID);
+      ensure_key(INITIALIZED, true);
+    }
+    _ => revert(Error::UnknownSchemaVersion),
+  }
+}
+
+// moves an allowance of an unversioned install to the current route hash, chains
+// weren't registered then, so addresses are not checked against them
+fn migrate_legacy_route(route: Route) {
+  // schema 0 padded casper addresses with 8 zero bytes, without the tag
+  let untagged = |chain_type: u8, token: &Bytes| {
+    require(token.len() == GENERIC_ADDRESS_LENGTH, Error::InvalidAddressLength);
+
+    let mut token = token.to_vec();
+    if chain_type == SOURCE_CHAIN_TYPE as u8 {
+      token[7] = 0;
+    }
+
+    token
+  };
+
+  let legacy_hash = bridge_core::get_allowance_hash(
+    HashAlgorithm::Sha256,
+    route.mint_chain_type,
+    route.mint_chain_id,
+    &untagged(route.mint_chain_type, &route.mint_token),
+    route.burn_chain_type,
+    route.burn_chain_id,
+    &untagged(route.burn_chain_type, &route.burn_token),
+  );
+
+  let dict: Dict<Vec<u8>, u8> = Dict::at(ALLOWANCES_DICT);
+
+  require(dict.contains(&legacy_hash).unwrap_or_revert(), Error::AllowanceNotFound);
+
+  dict.remove(&legacy_hash);
+
+  set_route(route);
+}
 
 #[no_mangle]
 pub extern "C" fn call() {
+  // the installer keeps the package hash, running the wasm again upgrades
+  match runtime::get_key(BRIDGE_PACKAGE_HASH) {
+    Some(package_key) => upgrade(package_key),
+    None => install(),
+  }
+}
+
+fn upgrade(package_key: Key) {
+  let package_hash = ContractPackageHash::new(package_key.into_hash().unwrap_or_revert());
+
+  let previous_contract_hash = runtime::get_key(BRIDGE_CONTRACT_HASH)
+    .and_then(Key::into_hash)
+    .map(ContractHash::new)
+    .unwrap_or_revert();
+
+  // optional, the previous version stays callable by default
+  let disable_previous = get_optional_named_arg::<bool>("disable_previous").unwrap_or(false);
+
+  // named keys of the previous version are carried over, only new entry points here
+  let (contract_hash, _) =
+    storage::add_contract_version(package_hash, get_entry_points(), NamedKeys::new());
+
+  // optional, routes of an unversioned install to carry over, see `migrate_from`
+  let mut migrate_args = RuntimeArgs::new();
+
+  if let Some(legacy_routes) = get_optional_named_arg::<Vec<Route>>("legacy_routes") {
+    migrate_args.insert("legacy_routes", legacy_routes).unwrap_or_revert();
+  }
+
+  runtime::call_contract::<()>(contract_hash, "migrate", migrate_args);
+
+  if disable_previous {
+    storage::disable_contract_version(package_hash, previous_contract_hash).unwrap_or_revert();
+  }
+
+  runtime::put_key(BRIDGE_CONTRACT_HASH, contract_hash.into());
+}
+
+fn install() {
   let mut named_keys = NamedKeys::new();
 
+  let caller = runtime::get_caller();
+
+  // optional, the deployer by default
+  let admin = get_optional_named_arg::<AccountHash>("admin").unwrap_or(caller);
+  let approvers = get_optional_named_arg::<Vec<AccountHash>>("approvers").unwrap_or(vec![caller]);
+
+  // optional, none by default, applied by `init`
+  let chains = get_optional_named_arg::<Vec<(u8, u32, ChainInfo)>>("chains").unwrap_or_default();
+  let routes = get_optional_named_arg::<Vec<Route>>("routes").unwrap_or_default();
+
   named_keys.insert(NONCES_DICT.to_string(), empty_dict(NONCES_DICT).into());
 
+  named_keys.insert(
+    ROUTE_TTLS_DICT.to_string(),
+    empty_dict(ROUTE_TTLS_DICT).into(),
+  );
+
+  named_keys.insert(
+    CHAINS_DICT.to_string(),
+    empty_dict(CHAINS_DICT).into(),
+  );
+
   named_keys.insert(
     BURN_PROOF_STORAGE_DICT.to_string(),
     empty_dict(BURN_PROOF_STORAGE_DICT).into(),
@@ -652,11 +1537,84 @@ pub extern "C" fn call() {
   empty_dict(ALLOWANCES_DICT).into(),
   );
 
+  named_keys.insert(
+    PROOF_RECORDS_DICT.to_string(),
+    empty_dict(PROOF_RECORDS_DICT).into(),
+  );
+
+  named_keys.insert(
+    ROLE_ADMIN.to_string(),
+    storage::new_uref(vec![admin]).into()
+  );
+
+  named_keys.insert(
+    MERKLE_ROOTS_DICT.to_string(),
+    empty_dict(MERKLE_ROOTS_DICT).into(),
+  );
+
+  named_keys.insert(
+    MERKLE_EPOCH.to_string(),
+    storage::new_uref(0u64).into()
+  );
+
+  init_events(&mut named_keys);
+
+  named_keys.insert(
+    REFUND_TIMEOUT.to_string(),
+    storage::new_uref(DEFAULT_REFUND_TIMEOUT).into()
+  );
+
+  // optional, sha256 by default
+  let hash_algorithm = get_optional_named_arg::<u8>(HASH_ALGORITHM)
+    .map(|v| HashAlgorithm::try_from(v).unwrap_or_revert())
+    .unwrap_or(HashAlgorithm::Sha256);
+
+  named_keys.insert(
+    HASH_ALGORITHM.to_string(),
+    storage::new_uref(hash_algorithm as u8).into()
+  );
+
   named_keys.insert(
     ROLE_APPROVER.to_string(), 
-  storage::new_uref(runtime::get_caller()).into()
+  storage::new_uref(approvers).into()
+  );
+
+  // optional, test chain id by default
+  let chain_id = get_optional_named_arg::<u32>(CHAIN_ID).unwrap_or(DEFAULT_CHAIN_ID);
+
+  named_keys.insert(
+    CHAIN_ID.to_string(),
+    storage::new_uref(chain_id).into()
+  );
+
+  named_keys.insert(
+    INITIALIZED.to_string(),
+    storage::new_uref(false).into()
   );
 
+  named_keys.insert(
+    SCHEMA_VERSION.to_string(),
+    storage::new_uref(CURRENT_SCHEMA_VERSION).into()
+  );
+
+  let (contract_hash, _) = storage::new_contract(
+    get_entry_points(),
+    Some(named_keys),
+    Some(BRIDGE_PACKAGE_HASH.to_string()),
+    Some("bridge_access_token".to_string()),
+  );
+
+  // TODO: should expose on prod?
+  runtime::put_key(BRIDGE_CONTRACT_HASH, contract_hash.into());
+
+  let mut init_args = RuntimeArgs::new();
+  init_args.insert("chains", chains).unwrap_or_revert();
+  init_args.insert("routes", routes).unwrap_or_revert();
+
+  runtime::call_contract::<()>(contract_hash, "init", init_args);
+}
+
+fn get_entry_points() -> EntryPoints {
   let mut entry_points = EntryPoints::new();
   entry_points.add_entry_point(endpoint(
     "set_allowance",
@@ -667,6 +1625,7 @@ pub extern "C" fn call() {
       Parameter::new("mint_chain_id", u32::cl_type()),
       Parameter::new("burn_chain_type", u8::cl_type()),
       Parameter::new("burn_chain_id", u32::cl_type()),
+      Parameter::new("default_ttl", u64::cl_type()), // optional
     ],
     CLType::Unit,
     None,
@@ -681,8 +1640,9 @@ pub extern "C" fn call() {
       Parameter::new("mint_chain_type", u8::cl_type()),
       Parameter::new("mint_chain_id", u32::cl_type()),
       Parameter::new("burn_amount", U256::cl_type()),
+      Parameter::new("deadline", u64::cl_type()), // optional
     ],
-    CLType::Unit,
+    U256::cl_type(),
     None,
   ));
 
@@ -696,12 +1656,20 @@ pub extern "C" fn call() {
       Parameter::new("burn_chain_id", u32::cl_type()),
       Parameter::new("burn_amount", U256::cl_type()),
       Parameter::new("burn_proof_hash", U256::cl_type()),
-      Parameter::new("burn_nonce", U256::cl_type()),
+      Parameter::new("burn_nonce", Nonce::cl_type()),
+      Parameter::new("burn_deadline", u64::cl_type()),
     ],
     CLType::Unit,
     None,
   ));
 
+  entry_points.add_entry_point(endpoint(
+    "mint_with_burn_proofs",
+    vec![Parameter::new("requests", Vec::<MintRequest>::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
   entry_points.add_entry_point(endpoint(
     "approve_burn_proof",
     vec![Parameter::new("proof_hash", U256::cl_type())],
@@ -709,13 +1677,185 @@ pub extern "C" fn call() {
     None,
   ));
 
-  let (contract_hash, _) = storage::new_contract(
-    entry_points,
-    Some(named_keys),
-    Some("bridge_package_hash".to_string()),
-    Some("bridge_access_token".to_string()),
-  );
+  entry_points.add_entry_point(endpoint(
+    "approve_merkle_root",
+    vec![Parameter::new("root", Bytes::cl_type())],
+    CLType::Unit,
+    None,
+  ));
 
-  // TODO: should expose on prod?
-  runtime::put_key("bridge_contract_hash", contract_hash.into());
+  entry_points.add_entry_point(endpoint(
+    "mint_with_merkle_proof",
+    vec![
+      Parameter::new("mint_token", ContractHash::cl_type()),
+      Parameter::new("burn_token", Bytes::cl_type()),
+      Parameter::new("burn_caller", Bytes::cl_type()),
+      Parameter::new("burn_chain_type", u8::cl_type()),
+      Parameter::new("burn_chain_id", u32::cl_type()),
+      Parameter::new("burn_amount", U256::cl_type()),
+      Parameter::new("burn_proof_hash", U256::cl_type()),
+      Parameter::new("burn_nonce", Nonce::cl_type()),
+      Parameter::new("burn_deadline", u64::cl_type()),
+      Parameter::new("epoch", u64::cl_type()),
+      Parameter::new("merkle_path", Vec::<Bytes>::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "approve_burn_proofs",
+    vec![
+      Parameter::new("proof_hashes", Vec::<U256>::cl_type()),
+      Parameter::new("skip_approved", bool::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "cancel_burn_proof",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "attest_burn_cancellation",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "refund_burn_proof",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_burn_proof_record",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    Option::<ProofRecord>::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_next_nonce",
+    vec![Parameter::new("burn_caller", Bytes::cl_type())],
+    Nonce::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "set_refund_timeout",
+    vec![Parameter::new("refund_timeout", u64::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "grant_role",
+    vec![
+      Parameter::new("role", String::cl_type()),
+      Parameter::new("account", AccountHash::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "revoke_role",
+    vec![
+      Parameter::new("role", String::cl_type()),
+      Parameter::new("account", AccountHash::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "set_chain",
+    vec![
+      Parameter::new("chain_type", u8::cl_type()),
+      Parameter::new("chain_id", u32::cl_type()),
+      Parameter::new("address_length", u8::cl_type()),
+      Parameter::new("name", String::cl_type()),
+      Parameter::new("remote_bridge", Bytes::cl_type()),
+      Parameter::new("enabled", bool::cl_type()), // optional
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "set_chain_enabled",
+    vec![
+      Parameter::new("chain_type", u8::cl_type()),
+      Parameter::new("chain_id", u32::cl_type()),
+      Parameter::new("enabled", bool::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_chain",
+    vec![
+      Parameter::new("chain_type", u8::cl_type()),
+      Parameter::new("chain_id", u32::cl_type()),
+    ],
+    Option::<ChainInfo>::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_proof_state",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    u8::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_allowance",
+    vec![
+      Parameter::new("mint_token", Bytes::cl_type()),
+      Parameter::new("burn_token", Bytes::cl_type()),
+      Parameter::new("mint_chain_type", u8::cl_type()),
+      Parameter::new("mint_chain_id", u32::cl_type()),
+      Parameter::new("burn_chain_type", u8::cl_type()),
+      Parameter::new("burn_chain_id", u32::cl_type()),
+    ],
+    u8::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint("get_config", vec![], BridgeConfig::cl_type(), None));
+
+  entry_points.add_entry_point(endpoint(
+    "get_roles",
+    vec![Parameter::new("role", String::cl_type())],
+    Vec::<AccountHash>::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "init",
+    vec![
+      Parameter::new("chains", Vec::<(u8, u32, ChainInfo)>::cl_type()),
+      Parameter::new("routes", Vec::<Route>::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "migrate",
+    vec![Parameter::new("legacy_routes", Vec::<Route>::cl_type())], // optional
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points
 }
@@ -1,6 +1,5 @@
 use core::convert::TryInto;
 
-use alloc::string::String;
 use alloc::vec::Vec;
 
 use core::fmt::Write;
@@ -9,53 +8,19 @@ use core::write;
 use casper_types::{
   account::AccountHash,
   bytesrepr::{FromBytes, ToBytes},
-  CLTyped, CLValue, URef, U256,
+  CLTyped, CLValue, URef,
 };
 
 use casper_contract::{
   contract_api::{
-    account, runtime,
+    self, account, runtime,
     storage::{self, new_dictionary},
     system,
   },
+  ext_ffi,
   unwrap_or_revert::UnwrapOrRevert,
 };
-use casper_types::{ApiError, Key};
-
-use sha2::{Digest, Sha256};
-
-pub fn sha256(data: &[u8]) -> Vec<u8> {
-  let mut instance = Sha256::new();
-  instance.update(data);
-  let result = instance.finalize();
-  result.to_vec()
-}
-
-pub struct Dict {
-  uref: URef,
-}
-
-impl Dict {
-  pub fn at(name: &str) -> Dict {
-    let key: Key = runtime::get_key(name).unwrap_or_revert();
-    let uref: URef = *key.as_uref().unwrap_or_revert();
-    Dict { uref }
-  }
-
-  pub fn get<T: CLTyped + FromBytes>(&self, key: &str) -> Option<T> {
-    storage::dictionary_get(self.uref, key)
-      .unwrap_or_revert()
-      .unwrap_or_default()
-  }
-
-  pub fn set<T: CLTyped + ToBytes>(&self, key: &str, value: T) {
-    storage::dictionary_put(self.uref, key, Some(value));
-  }
-
-  pub fn remove<T: CLTyped + ToBytes>(&self, key: &str) {
-    storage::dictionary_put(self.uref, key, Option::<T>::None);
-  }
-}
+use casper_types::{api_error, bytesrepr, ApiError, Key};
 
 pub fn get_key<T: FromBytes + CLTyped>(name: &str) -> Option<T> {
   match runtime::get_key(name) {
@@ -81,57 +46,66 @@ pub fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
   }
 }
 
+fn get_named_arg_size(name: &str) -> Option<usize> {
+  let mut arg_size: usize = 0;
+  let ret = unsafe {
+    ext_ffi::casper_get_named_arg_size(
+      name.as_bytes().as_ptr(),
+      name.len(),
+      &mut arg_size as *mut usize,
+    )
+  };
+
+  match api_error::result_from(ret) {
+    Ok(_) => Some(arg_size),
+    Err(ApiError::MissingArgument) => None,
+    Err(e) => runtime::revert(e),
+  }
+}
+
+// same as runtime::get_named_arg, but missing argument is not an error
+pub fn get_optional_named_arg<T: FromBytes>(name: &str) -> Option<T> {
+  let arg_size = get_named_arg_size(name)?;
+
+  let arg_bytes = if arg_size > 0 {
+    let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
+    let ret = unsafe {
+      ext_ffi::casper_get_named_arg(
+        name.as_bytes().as_ptr(),
+        name.len(),
+        data_non_null_ptr.as_ptr(),
+        arg_size,
+      )
+    };
+    let data = unsafe { Vec::from_raw_parts(data_non_null_ptr.as_ptr(), arg_size, arg_size) };
+
+    api_error::result_from(ret).unwrap_or_revert();
+    data
+  } else {
+    Vec::new()
+  };
+
+  let value = bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument);
+
+  Some(value)
+}
+
 pub fn empty_dict(name: &str) -> URef {
   let dict = new_dictionary(name).unwrap_or_revert();
 
   dict
 }
 
-// utils
+// upgrades, created only if the installed version lacks the key
 
-pub fn merge_bytes(vecs: Vec<Vec<u8>>) -> Vec<u8> {
-  let mut data = Vec::new();
-
-  for vec in vecs {
-    data.extend(vec);
+pub fn ensure_dict(name: &str) {
+  if !runtime::has_key(name) {
+    empty_dict(name);
   }
-
-  data
 }
 
-// pub fn decode_hex(s: &str) -> Result<Vec<u8>> {
-//   (0..s.len())
-//     .step_by(2)
-//     .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
-//     .collect()
-// }
-
-pub fn encode_hex(bytes: &[u8]) -> String {
-  let mut s = String::with_capacity(bytes.len() * 2);
-  for &b in bytes {
-    write!(&mut s, "{:02x}", b).unwrap();
+pub fn ensure_key<T: ToBytes + CLTyped>(name: &str, value: T) {
+  if !runtime::has_key(name) {
+    set_key(name, value);
   }
-  s
-}
-
-// compatible with abi encode (solidity)
-pub fn u256_to_bytes(u: &U256) -> Vec<u8> {
-  let mut buffer = [0u8; 32];
-  u.to_big_endian(&mut buffer);
-  buffer.to_vec()
-}
-
-pub fn u256_to_hex(u: &U256) -> String {
-  let bytes = u256_to_bytes(u);
-  encode_hex(&bytes)
-}
-
-pub fn u8_to_hex(u: &u8) -> String {
-  let bytes = u.to_be_bytes().to_vec();
-  encode_hex(&bytes)
-}
-
-pub fn u32_to_hex(u: &u32) -> String {
-  let bytes = u.to_be_bytes().to_vec();
-  encode_hex(&bytes)
 }
@@ -0,0 +1,243 @@
+use core::convert::TryFrom;
+
+use alloc::vec::Vec;
+
+use crate::{ChainType, Error};
+
+// every address crosses the bridge left padded to 40 bytes
+pub const GENERIC_ADDRESS_LENGTH: usize = 40;
+
+const EVM_ADDRESS_LENGTH: usize = 20;
+const CASPER_ADDRESS_LENGTH: usize = 32;
+const SOLANA_ADDRESS_LENGTH: usize = 32;
+// babylon node id: entity type byte + 29 bytes
+const RADIX_ADDRESS_LENGTH: usize = 30;
+
+// casper addresses keep their kind in the last padding byte
+const CASPER_TAG_INDEX: usize = GENERIC_ADDRESS_LENGTH - CASPER_ADDRESS_LENGTH - 1;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum CasperAddressTag {
+  Account = 0,
+  Contract, // 1
+  Package,  // 2
+}
+
+impl TryFrom<u8> for CasperAddressTag {
+  type Error = Error;
+
+  fn try_from(val: u8) -> Result<Self, Error> {
+    match val {
+      0 => Ok(CasperAddressTag::Account),
+      1 => Ok(CasperAddressTag::Contract),
+      2 => Ok(CasperAddressTag::Package),
+      _ => Err(Error::InvalidAddressTag),
+    }
+  }
+}
+
+impl ChainType {
+  // built-in address length, registry entries of this type must use it
+  pub fn address_length(&self) -> Option<u8> {
+    let length = match self {
+      ChainType::Evm => EVM_ADDRESS_LENGTH,
+      ChainType::Casper => CASPER_ADDRESS_LENGTH,
+      ChainType::Solana => SOLANA_ADDRESS_LENGTH,
+      ChainType::Radix => RADIX_ADDRESS_LENGTH,
+      ChainType::Undefined => return None,
+    };
+
+    Some(length as u8)
+  }
+}
+
+// address on any supported chain, see `encode` for the 40 byte layouts
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum GenericAddress {
+  Evm([u8; EVM_ADDRESS_LENGTH]),
+  Casper(CasperAddressTag, [u8; CASPER_ADDRESS_LENGTH]),
+  Solana([u8; SOLANA_ADDRESS_LENGTH]),
+  Radix([u8; RADIX_ADDRESS_LENGTH]),
+}
+
+impl GenericAddress {
+  pub fn chain_type(&self) -> ChainType {
+    match self {
+      GenericAddress::Evm(_) => ChainType::Evm,
+      GenericAddress::Casper(..) => ChainType::Casper,
+      GenericAddress::Solana(_) => ChainType::Solana,
+      GenericAddress::Radix(_) => ChainType::Radix,
+    }
+  }
+
+  // evm:    20 zero bytes | 20 address bytes (same as abi.encode of address)
+  // casper: 7 zero bytes | tag | 32 hash bytes
+  // solana: 8 zero bytes | 32 pubkey bytes
+  // radix:  10 zero bytes | 30 node id bytes
+  pub fn encode(&self) -> Vec<u8> {
+    let mut bytes = [0u8; GENERIC_ADDRESS_LENGTH];
+
+    match self {
+      GenericAddress::Evm(value) => pad_into(&mut bytes, value),
+      GenericAddress::Casper(tag, value) => {
+        bytes[CASPER_TAG_INDEX] = *tag as u8;
+        pad_into(&mut bytes, value)
+      }
+      GenericAddress::Solana(value) => pad_into(&mut bytes, value),
+      GenericAddress::Radix(value) => pad_into(&mut bytes, value),
+    }
+
+    bytes.to_vec()
+  }
+
+  // padding must be zero, except for the casper tag byte
+  pub fn decode(chain_type: ChainType, bytes: &[u8]) -> Result<Self, Error> {
+    if bytes.len() != GENERIC_ADDRESS_LENGTH {
+      return Err(Error::InvalidAddressLength);
+    }
+
+    let address = match chain_type {
+      ChainType::Evm => GenericAddress::Evm(unpad(bytes, 0)?),
+      ChainType::Casper => {
+        let tag = CasperAddressTag::try_from(bytes[CASPER_TAG_INDEX])?;
+        GenericAddress::Casper(tag, unpad(bytes, 1)?)
+      }
+      ChainType::Solana => GenericAddress::Solana(unpad(bytes, 0)?),
+      ChainType::Radix => GenericAddress::Radix(unpad(bytes, 0)?),
+      ChainType::Undefined => return Err(Error::UnknownChainType),
+    };
+
+    Ok(address)
+  }
+
+  // chain types without a built-in encoding come from the registry,
+  // their addresses are only checked for zero padding up to `address_length`
+  pub fn validate(chain_type: u8, address_length: u8, bytes: &[u8]) -> Result<(), Error> {
+    if let Ok(chain_type) = ChainType::try_from(chain_type) {
+      return GenericAddress::decode(chain_type, bytes).map(|_| ());
+    }
+
+    if chain_type == ChainType::Undefined as u8 {
+      return Err(Error::UnknownChainType);
+    }
+
+    let address_length = address_length as usize;
+
+    if bytes.len() != GENERIC_ADDRESS_LENGTH || address_length > GENERIC_ADDRESS_LENGTH {
+      return Err(Error::InvalidAddressLength);
+    }
+
+    let padding = &bytes[..GENERIC_ADDRESS_LENGTH - address_length];
+
+    if padding.iter().any(|b| *b != 0) {
+      return Err(Error::InvalidAddressPadding);
+    }
+
+    Ok(())
+  }
+}
+
+fn pad_into(bytes: &mut [u8; GENERIC_ADDRESS_LENGTH], value: &[u8]) {
+  bytes[GENERIC_ADDRESS_LENGTH - value.len()..].copy_from_slice(value);
+}
+
+// `skip` trailing padding bytes are not checked (casper tag)
+fn unpad<const N: usize>(bytes: &[u8], skip: usize) -> Result<[u8; N], Error> {
+  let (padding, value) = bytes.split_at(GENERIC_ADDRESS_LENGTH - N);
+
+  if padding[..padding.len() - skip].iter().any(|b| *b != 0) {
+    return Err(Error::InvalidAddressPadding);
+  }
+
+  let mut result = [0u8; N];
+  result.copy_from_slice(value);
+
+  Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec;
+
+  use super::{CasperAddressTag, GenericAddress};
+  use crate::{ChainType, Error};
+
+  #[test]
+  fn round_trip() {
+    let addresses = [
+      GenericAddress::Evm([0x22; 20]),
+      GenericAddress::Casper(CasperAddressTag::Account, [0x11; 32]),
+      GenericAddress::Casper(CasperAddressTag::Package, [0x11; 32]),
+      GenericAddress::Solana([0x33; 32]),
+      GenericAddress::Radix([0x44; 30]),
+    ];
+
+    for address in addresses.iter() {
+      let bytes = address.encode();
+
+      assert_eq!(bytes.len(), 40);
+      assert_eq!(
+        GenericAddress::decode(address.chain_type(), &bytes),
+        Ok(*address)
+      );
+    }
+  }
+
+  #[test]
+  fn casper_layout() {
+    let bytes = GenericAddress::Casper(CasperAddressTag::Contract, [0x11; 32]).encode();
+
+    assert_eq!(&bytes[..8], &[0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(&bytes[8..], &[0x11; 32]);
+  }
+
+  #[test]
+  fn rejects_invalid() {
+    assert_eq!(
+      GenericAddress::decode(ChainType::Evm, &[0; 41]),
+      Err(Error::InvalidAddressLength)
+    );
+    assert_eq!(
+      GenericAddress::decode(ChainType::Evm, &[1; 40]),
+      Err(Error::InvalidAddressPadding)
+    );
+    assert_eq!(
+      GenericAddress::decode(ChainType::Undefined, &[0; 40]),
+      Err(Error::UnknownChainType)
+    );
+
+    let mut bytes = vec![0; 40];
+    bytes[7] = 3;
+    assert_eq!(
+      GenericAddress::decode(ChainType::Casper, &bytes),
+      Err(Error::InvalidAddressTag)
+    );
+
+    // casper tag byte is padding for other chains
+    bytes[7] = 1;
+    assert_eq!(
+      GenericAddress::decode(ChainType::Solana, &bytes),
+      Err(Error::InvalidAddressPadding)
+    );
+  }
+
+  #[test]
+  fn validates_registry_chains() {
+    // near implicit account, 32 bytes
+    let mut bytes = vec![0; 40];
+    bytes[8] = 1;
+
+    assert_eq!(GenericAddress::validate(5, 32, &bytes), Ok(()));
+    assert_eq!(
+      GenericAddress::validate(5, 20, &bytes),
+      Err(Error::InvalidAddressPadding)
+    );
+
+    // built-in encoding wins over the registry length
+    assert_eq!(
+      GenericAddress::validate(ChainType::Evm as u8, 32, &bytes),
+      Err(Error::InvalidAddressPadding)
+    );
+  }
+}
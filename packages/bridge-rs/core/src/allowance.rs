@@ -0,0 +1,80 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{merge_bytes, HashAlgorithm};
+
+// canonical route hash, must match packages/test-vectors/allowance_hash.json
+// each side is chain_type || chain_id || token, side with greater digest goes first,
+// so both directions of a route share one hash
+pub fn get_allowance_hash(
+  algorithm: HashAlgorithm,
+  mint_chain_type: u8,
+  mint_chain_id: u32,
+  mint_token: &[u8],
+  burn_chain_type: u8,
+  burn_chain_id: u32,
+  burn_token: &[u8],
+) -> Vec<u8> {
+  let mint_bytes = merge_bytes(vec![
+    mint_chain_type.to_be_bytes().to_vec(),
+    mint_chain_id.to_be_bytes().to_vec(),
+    mint_token.to_vec(),
+  ]);
+
+  let burn_bytes = merge_bytes(vec![
+    burn_chain_type.to_be_bytes().to_vec(),
+    burn_chain_id.to_be_bytes().to_vec(),
+    burn_token.to_vec(),
+  ]);
+
+  if algorithm.digest(&mint_bytes) > algorithm.digest(&burn_bytes) {
+    algorithm.digest(&merge_bytes(vec![mint_bytes, burn_bytes]))
+  } else {
+    algorithm.digest(&merge_bytes(vec![burn_bytes, mint_bytes]))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::convert::TryFrom;
+
+  use alloc::vec::Vec;
+
+  use super::get_allowance_hash;
+  use crate::{encode_hex, HashAlgorithm};
+
+  const ALLOWANCE_HASH_VECTORS: &str = include_str!("../../../test-vectors/allowance_hash.json");
+
+  fn decode_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+      .step_by(2)
+      .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+      .collect()
+  }
+
+  #[test]
+  fn golden_vectors() {
+    let vectors: serde_json::Value = serde_json::from_str(ALLOWANCE_HASH_VECTORS).unwrap();
+
+    for vector in vectors["vectors"].as_array().unwrap() {
+      let algorithm = vector["hash_algorithm"].as_u64().unwrap() as u8;
+
+      let allowance_hash = get_allowance_hash(
+        HashAlgorithm::try_from(algorithm).unwrap(),
+        vector["mint_chain_type"].as_u64().unwrap() as u8,
+        vector["mint_chain_id"].as_u64().unwrap() as u32,
+        &decode_hex(vector["mint_token"].as_str().unwrap()),
+        vector["burn_chain_type"].as_u64().unwrap() as u8,
+        vector["burn_chain_id"].as_u64().unwrap() as u32,
+        &decode_hex(vector["burn_token"].as_str().unwrap()),
+      );
+
+      assert_eq!(
+        encode_hex(&allowance_hash),
+        vector["allowance_hash"].as_str().unwrap(),
+        "{}",
+        vector["name"]
+      );
+    }
+  }
+}
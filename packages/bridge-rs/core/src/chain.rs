@@ -0,0 +1,92 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use casper_types::{
+  bytesrepr::{self, Bytes, FromBytes, ToBytes},
+  CLType, CLTyped,
+};
+
+// registry entry of a remote chain, keyed by (chain_type, chain_id)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ChainInfo {
+  pub enabled: bool,
+  // significant bytes of a generic address, the rest is zero padding
+  pub address_length: u8,
+  pub name: String,
+  // generic address of the bridge deployed on that chain
+  pub remote_bridge: Bytes,
+}
+
+// nested tuples of the fields in order, same bytes as `to_bytes`
+impl CLTyped for ChainInfo {
+  fn cl_type() -> CLType {
+    <((bool, u8), (String, Bytes))>::cl_type()
+  }
+}
+
+impl ToBytes for ChainInfo {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.enabled.to_bytes()?);
+    result.extend(self.address_length.to_bytes()?);
+    result.extend(self.name.to_bytes()?);
+    result.extend(self.remote_bridge.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.enabled.serialized_length()
+      + self.address_length.serialized_length()
+      + self.name.serialized_length()
+      + self.remote_bridge.serialized_length()
+  }
+}
+
+impl FromBytes for ChainInfo {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (enabled, bytes) = bool::from_bytes(bytes)?;
+    let (address_length, bytes) = u8::from_bytes(bytes)?;
+    let (name, bytes) = String::from_bytes(bytes)?;
+    let (remote_bridge, bytes) = Bytes::from_bytes(bytes)?;
+
+    let info = ChainInfo {
+      enabled,
+      address_length,
+      name,
+      remote_bridge,
+    };
+
+    Ok((info, bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::string::ToString;
+
+  use casper_types::{bytesrepr::Bytes, CLValue};
+
+  use super::ChainInfo;
+
+  #[test]
+  fn cl_value() {
+    let info = ChainInfo {
+      enabled: true,
+      address_length: 20,
+      name: "kovan".to_string(),
+      remote_bridge: Bytes::from([3u8; 20].to_vec()),
+    };
+
+    let value = CLValue::from_t(info.clone()).unwrap();
+    let tuple = CLValue::from_t((
+      (info.enabled, info.address_length),
+      (info.name.clone(), info.remote_bridge.clone()),
+    ))
+    .unwrap();
+
+    assert_eq!(value, tuple);
+    assert_eq!(value.into_t::<ChainInfo>().unwrap(), info);
+  }
+}
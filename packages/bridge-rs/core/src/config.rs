@@ -0,0 +1,102 @@
+use alloc::vec::Vec;
+
+use casper_types::{
+  bytesrepr::{self, FromBytes, ToBytes},
+  CLType, CLTyped,
+};
+
+// settings of a deployed bridge, returned by the get_config view
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BridgeConfig {
+  pub chain_type: u8,
+  pub chain_id: u32,
+  pub hash_algorithm: u8,
+  // in ms
+  pub refund_timeout: u64,
+  // next epoch to be approved
+  pub merkle_epoch: u64,
+  pub schema_version: u32,
+}
+
+// nested tuples of the fields in order, same bytes as `to_bytes`
+impl CLTyped for BridgeConfig {
+  fn cl_type() -> CLType {
+    <((u8, u32), (u8, u64), (u64, u32))>::cl_type()
+  }
+}
+
+impl ToBytes for BridgeConfig {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.chain_type.to_bytes()?);
+    result.extend(self.chain_id.to_bytes()?);
+    result.extend(self.hash_algorithm.to_bytes()?);
+    result.extend(self.refund_timeout.to_bytes()?);
+    result.extend(self.merkle_epoch.to_bytes()?);
+    result.extend(self.schema_version.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.chain_type.serialized_length()
+      + self.chain_id.serialized_length()
+      + self.hash_algorithm.serialized_length()
+      + self.refund_timeout.serialized_length()
+      + self.merkle_epoch.serialized_length()
+      + self.schema_version.serialized_length()
+  }
+}
+
+impl FromBytes for BridgeConfig {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (hash_algorithm, bytes) = u8::from_bytes(bytes)?;
+    let (refund_timeout, bytes) = u64::from_bytes(bytes)?;
+    let (merkle_epoch, bytes) = u64::from_bytes(bytes)?;
+    let (schema_version, bytes) = u32::from_bytes(bytes)?;
+
+    let config = BridgeConfig {
+      chain_type,
+      chain_id,
+      hash_algorithm,
+      refund_timeout,
+      merkle_epoch,
+      schema_version,
+    };
+
+    Ok((config, bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use casper_types::CLValue;
+
+  use super::BridgeConfig;
+
+  #[test]
+  fn cl_value() {
+    let config = BridgeConfig {
+      chain_type: 2,
+      chain_id: 1010,
+      hash_algorithm: 1,
+      refund_timeout: 604_800_000,
+      merkle_epoch: 3,
+      schema_version: 2,
+    };
+
+    let value = CLValue::from_t(config.clone()).unwrap();
+    let tuple = CLValue::from_t((
+      (config.chain_type, config.chain_id),
+      (config.hash_algorithm, config.refund_timeout),
+      (config.merkle_epoch, config.schema_version),
+    ))
+    .unwrap();
+
+    assert_eq!(value, tuple);
+    assert_eq!(value.into_t::<BridgeConfig>().unwrap(), config);
+  }
+}
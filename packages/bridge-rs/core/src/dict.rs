@@ -0,0 +1,130 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use casper_types::U256;
+
+use crate::{encode_hex, get_nonce_key, u256_to_bytes};
+
+// the one item key encoding of a key type, at most 64 chars
+pub trait DictKey {
+  fn to_dict_key(&self) -> String;
+}
+
+// proof hashes
+impl DictKey for U256 {
+  fn to_dict_key(&self) -> String {
+    encode_hex(&u256_to_bytes(self))
+  }
+}
+
+// merkle epochs
+impl DictKey for u64 {
+  fn to_dict_key(&self) -> String {
+    encode_hex(&self.to_be_bytes())
+  }
+}
+
+// route hashes
+impl DictKey for Vec<u8> {
+  fn to_dict_key(&self) -> String {
+    encode_hex(self)
+  }
+}
+
+// (chain_type, chain_id)
+impl DictKey for (u8, u32) {
+  fn to_dict_key(&self) -> String {
+    encode_hex(&self.0.to_be_bytes()) + &encode_hex(&self.1.to_be_bytes())
+  }
+}
+
+// generic address of a burn caller, hashed since 40 bytes don't fit a key
+pub struct CallerKey<'a>(pub &'a [u8]);
+
+impl DictKey for CallerKey<'_> {
+  fn to_dict_key(&self) -> String {
+    get_nonce_key(self.0)
+  }
+}
+
+// values looked up by name once, then reused
+pub struct NameCache<T> {
+  entries: Vec<(String, T)>,
+}
+
+impl<T> NameCache<T> {
+  pub const fn new() -> Self {
+    NameCache {
+      entries: Vec::new(),
+    }
+  }
+}
+
+impl<T> Default for NameCache<T> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<T: Copy> NameCache<T> {
+  pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, name: &str, lookup: F) -> T {
+    if let Some((_, value)) = self
+      .entries
+      .iter()
+      .find(|(cached_name, _)| cached_name == name)
+    {
+      return *value;
+    }
+
+    let value = lookup();
+
+    self.entries.push((name.to_string(), value));
+
+    value
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec;
+
+  use casper_types::U256;
+
+  use super::{CallerKey, DictKey, NameCache};
+
+  #[test]
+  fn key_encodings() {
+    assert_eq!(
+      U256::from(0x0102).to_dict_key(),
+      "0000000000000000000000000000000000000000000000000000000000000102"
+    );
+    assert_eq!(U256::MAX.to_dict_key(), "f".repeat(64));
+    assert_eq!(0x0102u64.to_dict_key(), "0000000000000102");
+    assert_eq!(vec![0xabu8, 0x01, 0x00].to_dict_key(), "ab0100");
+    assert_eq!((1u8, 1337u32).to_dict_key(), "0100000539");
+    assert_eq!(
+      CallerKey(&[vec![0u8; 8], vec![1u8; 32]].concat()).to_dict_key(),
+      "9f8b3c76e9062af32c1ab2caf0d1aac6770820265475ed7fb0b7b32685a641ea"
+    );
+  }
+
+  #[test]
+  fn name_cache() {
+    let mut cache = NameCache::new();
+    let mut lookups = 0;
+
+    let mut get = |cache: &mut NameCache<u32>, name: &str, value: u32| {
+      cache.get_or_insert_with(name, || {
+        lookups += 1;
+        value
+      })
+    };
+
+    assert_eq!(get(&mut cache, "proofs", 1), 1);
+    assert_eq!(get(&mut cache, "proofs", 2), 1);
+    assert_eq!(get(&mut cache, "nonces", 3), 3);
+    assert_eq!(get(&mut cache, "nonces", 4), 3);
+    assert_eq!(get(&mut cache, "proofs", 5), 1);
+    assert_eq!(lookups, 2);
+  }
+}
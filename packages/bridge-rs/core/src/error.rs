@@ -0,0 +1,168 @@
+use core::convert::TryFrom;
+use core::fmt;
+
+use casper_types::ApiError;
+
+// declares `Error` with its catalogue, codes never change once released
+macro_rules! errors {
+  ($($name:ident = $code:literal, $message:expr;)*) => {
+    // reverted as ApiError::User(code)
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    #[repr(u16)]
+    pub enum Error {
+      $($name = $code,)*
+    }
+
+    impl Error {
+      pub const ALL: &'static [Error] = &[$(Error::$name,)*];
+
+      pub fn from_code(code: u16) -> Option<Error> {
+        match code {
+          $($code => Some(Error::$name),)*
+          _ => None,
+        }
+      }
+
+      pub fn code(&self) -> u16 {
+        *self as u16
+      }
+
+      pub fn name(&self) -> &'static str {
+        match self {
+          $(Error::$name => stringify!($name),)*
+        }
+      }
+
+      pub fn message(&self) -> &'static str {
+        match self {
+          $(Error::$name => $message,)*
+        }
+      }
+    }
+  };
+}
+
+// codes of shared failures were narrowed to one site, new sites are appended
+errors! {
+  AlreadyApproved = 0, "burn proof is already approved";
+  AmountExceeded = 1, "burn amount exceeds the token balance";
+  ProofNotApproved = 2, "burn proof is not approved for minting";
+  ProvidedHashIsInvalid = 3, "burn proof hash does not match the mint arguments";
+  InvalidBurnCallerLength = 4, "burn caller is not a generic address of the burn chain";
+  InvalidBurnTokenLength = 5, "burn token is not a generic address of the burn chain";
+  InvalidPackage = 6, "burn proof preimage has an unexpected length";
+  UnknownState = 7, "stored proof state is out of range";
+  UnknownChain = 8, "chain is not registered";
+  UnknownAllowance = 9, "stored allowance is out of range";
+  AllowanceNotFound = 10, "route is not allowed";
+  MissingApproverRole = 11, "caller is not an approver";
+  InvalidAddressTag = 12, "casper address has an unknown tag";
+  MissingAdminRole = 13, "caller is not the admin";
+  ProofRecordNotFound = 14, "burn proof has no record";
+  RefundLocked = 15, "refund timeout has not passed yet";
+  RefundNotAllowed = 16, "burn proof is not cancelled or expired, and caller is not the admin";
+  NotCancellable = 17, "burn proof is already minted or closed";
+  InvalidDeadline = 18, "deadline is not in the future";
+  ProofExpired = 19, "burn proof deadline has passed";
+  // 20 is reserved, see RESERVED_ERROR_CODES
+  InvalidBridgeLength = 21, "remote bridge is not a generic address";
+  UnknownHashAlgorithm = 22, "hash algorithm is out of range";
+  InvalidAddressLength = 23, "address length is zero or longer than a generic address";
+  InvalidAddressPadding = 24, "generic address has non zero padding";
+  ChainDisabled = 25, "chain is disabled";
+  MerkleRootNotFound = 26, "no merkle root is approved for the epoch";
+  InvalidMerkleRoot = 27, "merkle root is not 32 bytes";
+  InvalidMerkleProof = 28, "burn proof is not included in the merkle root";
+  UnknownRole = 29, "role is not known";
+  UnknownSchemaVersion = 30, "stored schema version is newer than the contract";
+  AlreadyInitialized = 31, "install config is already applied";
+  ProofAlreadyExecuted = 32, "burn proof is already minted";
+  InvalidMintCallerLength = 33, "mint caller is not a generic address of the mint chain";
+  InvalidMintTokenLength = 34, "mint token is not a generic address of the mint chain";
+  UnknownChainType = 35, "chain type is undefined or has no built-in encoding";
+  SourceChainNotRemote = 36, "this chain can't be registered as a remote chain";
+  ProofNotBurned = 37, "burn proof is not in burned state";
+  RoleAlreadyGranted = 38, "account already holds the role";
+  RoleNotGranted = 39, "account does not hold the role";
+  LastAdmin = 40, "the last admin can't be revoked";
+  ProofParamsNotFound = 41, "burn proof record has no mint parameters";
+  BurnTimeNotFound = 42, "burn proof record has no burn time";
+  AddressLengthMismatch = 43, "address length differs from the built-in chain type";
+}
+
+// codes of removed errors, never given out again
+pub const RESERVED_ERROR_CODES: &[u16] = &[
+  20, // RemoteBridgeNotFound, never raised
+];
+
+impl From<Error> for ApiError {
+  fn from(error: Error) -> Self {
+    ApiError::User(error.code())
+  }
+}
+
+// gives the api error back if it is not a bridge error
+impl TryFrom<ApiError> for Error {
+  type Error = ApiError;
+
+  fn try_from(error: ApiError) -> Result<Self, ApiError> {
+    match error {
+      ApiError::User(code) => Error::from_code(code).ok_or(error),
+      _ => Err(error),
+    }
+  }
+}
+
+impl fmt::Display for Error {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} ({}): {}", self.name(), self.code(), self.message())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::{string::ToString, vec::Vec};
+  use core::convert::TryFrom;
+
+  use casper_types::ApiError;
+
+  use super::{Error, RESERVED_ERROR_CODES};
+
+  #[test]
+  fn codes_round_trip() {
+    let mut codes: Vec<u16> = Error::ALL.iter().map(Error::code).collect();
+    codes.extend(RESERVED_ERROR_CODES);
+    codes.sort_unstable();
+
+    // no gaps besides reserved codes, so the next code is always codes.len()
+    assert_eq!(codes, (0..codes.len() as u16).collect::<Vec<u16>>());
+
+    for error in Error::ALL {
+      assert_eq!(Error::from_code(error.code()), Some(*error));
+      assert_eq!(Error::try_from(ApiError::from(*error)), Ok(*error));
+    }
+
+    for code in RESERVED_ERROR_CODES {
+      assert_eq!(Error::from_code(*code), None);
+    }
+
+    assert_eq!(Error::from_code(codes.len() as u16), None);
+  }
+
+  #[test]
+  fn catalogue() {
+    assert_eq!(
+      Error::try_from(ApiError::User(32)).map(|error| error.to_string()),
+      Ok("ProofAlreadyExecuted (32): burn proof is already minted".to_string())
+    );
+
+    assert_eq!(
+      Error::try_from(ApiError::User(1000)),
+      Err(ApiError::User(1000))
+    );
+    assert_eq!(
+      Error::try_from(ApiError::MissingArgument),
+      Err(ApiError::MissingArgument)
+    );
+  }
+}
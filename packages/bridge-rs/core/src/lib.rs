@@ -0,0 +1,50 @@
+//! Types, encodings and hashes shared by the bridge contract, tests and off-chain tooling.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod address;
+mod allowance;
+mod chain;
+mod config;
+mod dict;
+mod error;
+mod events;
+mod merkle;
+mod mint;
+mod nonce;
+mod proof;
+mod record;
+mod route;
+mod signatures;
+mod types;
+mod utils;
+
+pub use address::{CasperAddressTag, GenericAddress, GENERIC_ADDRESS_LENGTH};
+pub use allowance::get_allowance_hash;
+pub use chain::ChainInfo;
+pub use config::BridgeConfig;
+pub use dict::{CallerKey, DictKey, NameCache};
+pub use error::{Error, RESERVED_ERROR_CODES};
+pub use events::{
+  AllowanceSet, ApprovedBurnProof, ApprovedMerkleRoot, AttestedBurnCancellation, BridgeConfigured,
+  BridgeEvent, CancelledBurnProof, ChainEnabledSet, ChainSet, ProofOfBurn, ProofOfMint,
+  ProofStatesDropped, RefundTimeoutSet, RefundedBurnProof, RoleGranted, RoleRevoked, Schema,
+  SchemaMigrated, Schemas, BRIDGE_EVENTS_COUNT, BRIDGE_EVENTS_DICT, CES_VERSION, CES_VERSION_KEY,
+  EVENTS_DICT, EVENTS_LENGTH, EVENTS_SCHEMA, EVENT_PREFIX,
+};
+pub use merkle::{compute_merkle_root, get_merkle_path, get_merkle_root, verify_merkle_path};
+pub use mint::MintRequest;
+pub use nonce::{get_nonce_key, Nonce};
+pub use proof::{BurnProof, EXPIRY_GRACE, PROOF_PREIMAGE_LENGTH, PROOF_VERSION};
+pub use record::ProofRecord;
+pub use route::Route;
+pub use signatures::{
+  wire_signature, ALLOWANCE_SET_SIG, APPROVED_BURN_PROOF_SIG, APPROVED_MERKLE_ROOT_SIG,
+  ATTESTED_BURN_CANCELLATION_SIG, BRIDGE_CONFIGURED_SIG, CANCELLED_BURN_PROOF_SIG,
+  CHAIN_ENABLED_SET_SIG, CHAIN_SET_SIG, PROOF_OF_BURN_SIG, PROOF_OF_BURN_V2_SIG, PROOF_OF_MINT_SIG,
+  PROOF_OF_MINT_V2_SIG, PROOF_STATES_DROPPED_SIG, REFUNDED_BURN_PROOF_SIG, REFUND_TIMEOUT_SET_SIG,
+  ROLE_GRANTED_SIG, ROLE_REVOKED_SIG, SCHEMA_MIGRATED_SIG, WIRE_SIGNATURES,
+};
+pub use types::{Allowance, ChainType, HashAlgorithm, States};
+pub use utils::{encode_hex, keccak256, merge_bytes, pad_with_8_bytes, sha256, u256_to_bytes};
@@ -0,0 +1,142 @@
+use alloc::vec::Vec;
+
+use casper_types::{
+  bytesrepr::{self, Bytes, FromBytes, ToBytes},
+  CLType, CLTyped, ContractHash, U256,
+};
+
+use crate::Nonce;
+
+// mint arguments of one burn proof, list element of batch mints
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MintRequest {
+  pub mint_token: ContractHash,
+  pub burn_token: Bytes,
+  pub burn_caller: Bytes,
+  pub burn_chain_type: u8,
+  pub burn_chain_id: u32,
+  pub burn_amount: U256,
+  pub burn_proof_hash: U256,
+  pub burn_nonce: Nonce,
+  pub burn_deadline: u64,
+}
+
+// nested tuples of the fields in order, same bytes as `to_bytes`
+impl CLTyped for MintRequest {
+  fn cl_type() -> CLType {
+    <(
+      (ContractHash, Bytes, Bytes),
+      (u8, u32, U256),
+      (U256, Nonce, u64),
+    )>::cl_type()
+  }
+}
+
+impl ToBytes for MintRequest {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.mint_token.to_bytes()?);
+    result.extend(self.burn_token.to_bytes()?);
+    result.extend(self.burn_caller.to_bytes()?);
+    result.extend(self.burn_chain_type.to_bytes()?);
+    result.extend(self.burn_chain_id.to_bytes()?);
+    result.extend(self.burn_amount.to_bytes()?);
+    result.extend(self.burn_proof_hash.to_bytes()?);
+    result.extend(self.burn_nonce.to_bytes()?);
+    result.extend(self.burn_deadline.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.mint_token.serialized_length()
+      + self.burn_token.serialized_length()
+      + self.burn_caller.serialized_length()
+      + self.burn_chain_type.serialized_length()
+      + self.burn_chain_id.serialized_length()
+      + self.burn_amount.serialized_length()
+      + self.burn_proof_hash.serialized_length()
+      + self.burn_nonce.serialized_length()
+      + self.burn_deadline.serialized_length()
+  }
+}
+
+impl FromBytes for MintRequest {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (mint_token, bytes) = ContractHash::from_bytes(bytes)?;
+    let (burn_token, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_caller, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (burn_chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (burn_amount, bytes) = U256::from_bytes(bytes)?;
+    let (burn_proof_hash, bytes) = U256::from_bytes(bytes)?;
+    let (burn_nonce, bytes) = Nonce::from_bytes(bytes)?;
+    let (burn_deadline, bytes) = u64::from_bytes(bytes)?;
+
+    let request = MintRequest {
+      mint_token,
+      burn_token,
+      burn_caller,
+      burn_chain_type,
+      burn_chain_id,
+      burn_amount,
+      burn_proof_hash,
+      burn_nonce,
+      burn_deadline,
+    };
+
+    Ok((request, bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::{vec, vec::Vec};
+
+  use casper_types::{bytesrepr::Bytes, CLValue, ContractHash};
+
+  use super::MintRequest;
+
+  #[test]
+  fn cl_value() {
+    let request = MintRequest {
+      mint_token: ContractHash::new([1u8; 32]),
+      burn_token: Bytes::from(vec![2u8; 40]),
+      burn_caller: Bytes::from(vec![3u8; 40]),
+      burn_chain_type: 1,
+      burn_chain_id: 1337,
+      burn_amount: 1_000.into(),
+      burn_proof_hash: 7.into(),
+      burn_nonce: 2.into(),
+      burn_deadline: 60_000,
+    };
+
+    let value = CLValue::from_t(request.clone()).unwrap();
+    let tuple = CLValue::from_t((
+      (
+        request.mint_token,
+        request.burn_token.clone(),
+        request.burn_caller.clone(),
+      ),
+      (
+        request.burn_chain_type,
+        request.burn_chain_id,
+        request.burn_amount,
+      ),
+      (
+        request.burn_proof_hash,
+        request.burn_nonce,
+        request.burn_deadline,
+      ),
+    ))
+    .unwrap();
+
+    assert_eq!(value, tuple);
+    assert_eq!(value.into_t::<MintRequest>().unwrap(), request);
+
+    // list element of batch mints
+    let requests = CLValue::from_t(vec![request.clone(), request]).unwrap();
+    assert_eq!(requests.into_t::<Vec<MintRequest>>().unwrap().len(), 2);
+  }
+}
@@ -0,0 +1,272 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use casper_types::{
+  bytesrepr::{self, Bytes, FromBytes, ToBytes},
+  CLType, CLTyped, U256,
+};
+
+use crate::{merge_bytes, u256_to_bytes, Error, HashAlgorithm, Nonce};
+
+// bump on any change of the preimage layout
+pub const PROOF_VERSION: u8 = 1;
+
+// 1 + 40 + 40 + 40 + 40 + 32 + 1 + 4 + 1 + 4 + 32 + 8 + 40 + 40
+pub const PROOF_PREIMAGE_LENGTH: usize = 323;
+
+// block times of both chains drift and a mint may land right at the deadline,
+// so the burn side refunds an expired proof only this long (ms) after it
+pub const EXPIRY_GRACE: u64 = 60 * 60 * 1000;
+
+// everything a burn proof hash commits to
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BurnProof {
+  pub proof_version: u8,
+  pub mint_caller: Bytes,
+  pub burn_caller: Bytes,
+  pub mint_token: Bytes,
+  pub burn_token: Bytes,
+  pub burn_amount: U256,
+  pub mint_chain_type: u8,
+  pub mint_chain_id: u32,
+  pub burn_chain_type: u8,
+  pub burn_chain_id: u32,
+  pub burn_nonce: Nonce,
+  pub burn_deadline: u64,
+  pub mint_bridge: Bytes,
+  pub burn_bridge: Bytes,
+}
+
+impl BurnProof {
+  // packed, big endian, same as abi.encodePacked
+  pub fn preimage(&self) -> Result<Vec<u8>, Error> {
+    #[rustfmt::skip]
+    let data = merge_bytes(vec![
+      self.proof_version.to_be_bytes().to_vec(),
+      self.mint_caller.to_vec(), self.burn_caller.to_vec(),
+      self.mint_token.to_vec(), self.burn_token.to_vec(),
+      u256_to_bytes(&self.burn_amount),
+      self.mint_chain_type.to_be_bytes().to_vec(), self.mint_chain_id.to_be_bytes().to_vec(),
+      self.burn_chain_type.to_be_bytes().to_vec(), self.burn_chain_id.to_be_bytes().to_vec(),
+      u256_to_bytes(&self.burn_nonce),
+      self.burn_deadline.to_be_bytes().to_vec(),
+      // bridge deployments on both sides
+      self.mint_bridge.to_vec(), self.burn_bridge.to_vec()
+    ]);
+
+    if data.len() != PROOF_PREIMAGE_LENGTH {
+      return Err(Error::InvalidPackage);
+    }
+
+    Ok(data)
+  }
+
+  pub fn hash(&self, algorithm: HashAlgorithm) -> Result<Vec<u8>, Error> {
+    let data = self.preimage()?;
+
+    Ok(algorithm.digest(&data))
+  }
+}
+
+// nested tuples of the fields in order, same bytes as `to_bytes`
+impl CLTyped for BurnProof {
+  fn cl_type() -> CLType {
+    <(
+      ((u8, Bytes, Bytes), (Bytes, Bytes, U256), (u8, u32, u8)),
+      ((u32, Nonce, u64), (Bytes, Bytes)),
+    )>::cl_type()
+  }
+}
+
+impl ToBytes for BurnProof {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.proof_version.to_bytes()?);
+    result.extend(self.mint_caller.to_bytes()?);
+    result.extend(self.burn_caller.to_bytes()?);
+    result.extend(self.mint_token.to_bytes()?);
+    result.extend(self.burn_token.to_bytes()?);
+    result.extend(self.burn_amount.to_bytes()?);
+    result.extend(self.mint_chain_type.to_bytes()?);
+    result.extend(self.mint_chain_id.to_bytes()?);
+    result.extend(self.burn_chain_type.to_bytes()?);
+    result.extend(self.burn_chain_id.to_bytes()?);
+    result.extend(self.burn_nonce.to_bytes()?);
+    result.extend(self.burn_deadline.to_bytes()?);
+    result.extend(self.mint_bridge.to_bytes()?);
+    result.extend(self.burn_bridge.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.proof_version.serialized_length()
+      + self.mint_caller.serialized_length()
+      + self.burn_caller.serialized_length()
+      + self.mint_token.serialized_length()
+      + self.burn_token.serialized_length()
+      + self.burn_amount.serialized_length()
+      + self.mint_chain_type.serialized_length()
+      + self.mint_chain_id.serialized_length()
+      + self.burn_chain_type.serialized_length()
+      + self.burn_chain_id.serialized_length()
+      + self.burn_nonce.serialized_length()
+      + self.burn_deadline.serialized_length()
+      + self.mint_bridge.serialized_length()
+      + self.burn_bridge.serialized_length()
+  }
+}
+
+impl FromBytes for BurnProof {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (proof_version, bytes) = u8::from_bytes(bytes)?;
+    let (mint_caller, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_caller, bytes) = Bytes::from_bytes(bytes)?;
+    let (mint_token, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_token, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_amount, bytes) = U256::from_bytes(bytes)?;
+    let (mint_chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (mint_chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (burn_chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (burn_chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (burn_nonce, bytes) = Nonce::from_bytes(bytes)?;
+    let (burn_deadline, bytes) = u64::from_bytes(bytes)?;
+    let (mint_bridge, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_bridge, bytes) = Bytes::from_bytes(bytes)?;
+
+    let proof = BurnProof {
+      proof_version,
+      mint_caller,
+      burn_caller,
+      mint_token,
+      burn_token,
+      burn_amount,
+      mint_chain_type,
+      mint_chain_id,
+      burn_chain_type,
+      burn_chain_id,
+      burn_nonce,
+      burn_deadline,
+      mint_bridge,
+      burn_bridge,
+    };
+
+    Ok((proof, bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use core::convert::TryFrom;
+
+  use alloc::{vec, vec::Vec};
+
+  use casper_types::{bytesrepr::Bytes, CLValue, U256};
+
+  use super::{BurnProof, PROOF_PREIMAGE_LENGTH, PROOF_VERSION};
+  use crate::{encode_hex, HashAlgorithm};
+
+  const BURN_PROOF_HASH_VECTORS: &str = include_str!("../../../test-vectors/burn_proof_hash.json");
+
+  fn decode_hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+      .step_by(2)
+      .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+      .collect()
+  }
+
+  // same vectors must hold for CrossChainBridge.sol getBurnProofHash
+  #[test]
+  fn golden_vectors() {
+    let vectors: serde_json::Value = serde_json::from_str(BURN_PROOF_HASH_VECTORS).unwrap();
+
+    for vector in vectors["vectors"].as_array().unwrap() {
+      let bytes = |field: &str| Bytes::from(decode_hex(vector[field].as_str().unwrap()));
+      let number = |field: &str| U256::from_dec_str(vector[field].as_str().unwrap()).unwrap();
+
+      let proof = BurnProof {
+        proof_version: vector["proof_version"].as_u64().unwrap() as u8,
+        mint_caller: bytes("mint_caller"),
+        burn_caller: bytes("burn_caller"),
+        mint_token: bytes("mint_token"),
+        burn_token: bytes("burn_token"),
+        burn_amount: number("burn_amount"),
+        mint_chain_type: vector["mint_chain_type"].as_u64().unwrap() as u8,
+        mint_chain_id: vector["mint_chain_id"].as_u64().unwrap() as u32,
+        burn_chain_type: vector["burn_chain_type"].as_u64().unwrap() as u8,
+        burn_chain_id: vector["burn_chain_id"].as_u64().unwrap() as u32,
+        burn_nonce: number("burn_nonce"),
+        burn_deadline: vector["burn_deadline"].as_u64().unwrap(),
+        mint_bridge: bytes("mint_bridge"),
+        burn_bridge: bytes("burn_bridge"),
+      };
+
+      let algorithm = HashAlgorithm::try_from(vector["hash_algorithm"].as_u64().unwrap() as u8);
+      let preimage = proof.preimage().unwrap();
+
+      assert_eq!(preimage.len(), PROOF_PREIMAGE_LENGTH);
+      assert_eq!(
+        encode_hex(&preimage),
+        vector["preimage"].as_str().unwrap(),
+        "{}",
+        vector["name"]
+      );
+      assert_eq!(
+        encode_hex(&proof.hash(algorithm.unwrap()).unwrap()),
+        vector["burn_proof_hash"].as_str().unwrap(),
+        "{}",
+        vector["name"]
+      );
+    }
+  }
+
+  #[test]
+  fn cl_value() {
+    let proof = BurnProof {
+      proof_version: PROOF_VERSION,
+      mint_caller: Bytes::from(vec![1u8; 40]),
+      burn_caller: Bytes::from(vec![2u8; 40]),
+      mint_token: Bytes::from(vec![3u8; 40]),
+      burn_token: Bytes::from(vec![4u8; 40]),
+      burn_amount: 1_000.into(),
+      mint_chain_type: 1,
+      mint_chain_id: 1337,
+      burn_chain_type: 2,
+      burn_chain_id: 1010,
+      burn_nonce: 2.into(),
+      burn_deadline: 60_000,
+      mint_bridge: Bytes::from(vec![5u8; 40]),
+      burn_bridge: Bytes::from(vec![6u8; 40]),
+    };
+
+    let value = CLValue::from_t(proof.clone()).unwrap();
+    let tuple = CLValue::from_t((
+      (
+        (
+          proof.proof_version,
+          proof.mint_caller.clone(),
+          proof.burn_caller.clone(),
+        ),
+        (
+          proof.mint_token.clone(),
+          proof.burn_token.clone(),
+          proof.burn_amount,
+        ),
+        (
+          proof.mint_chain_type,
+          proof.mint_chain_id,
+          proof.burn_chain_type,
+        ),
+      ),
+      (
+        (proof.burn_chain_id, proof.burn_nonce, proof.burn_deadline),
+        (proof.mint_bridge.clone(), proof.burn_bridge.clone()),
+      ),
+    ))
+    .unwrap();
+
+    assert_eq!(value, tuple);
+    assert_eq!(value.into_t::<BurnProof>().unwrap(), proof);
+  }
+}
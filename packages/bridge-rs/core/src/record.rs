@@ -0,0 +1,145 @@
+use alloc::vec::Vec;
+
+use casper_types::{
+  account::AccountHash,
+  bytesrepr::{self, FromBytes, ToBytes},
+  CLType, CLTyped,
+};
+
+use crate::BurnProof;
+
+// on-chain history of a proof, next to its state in burn_proof_storage
+// params are known on burn (burn side) or on mint (mint side), not on approve
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct ProofRecord {
+  pub params: Option<BurnProof>,
+  pub burned_at: Option<u64>,
+  pub approved_at: Option<u64>,
+  pub approver: Option<AccountHash>,
+  pub executed_at: Option<u64>,
+  pub cancelled_at: Option<u64>,
+  pub refunded_at: Option<u64>,
+  pub cancel_attested: bool,
+}
+
+// nested tuples of the fields in order, same bytes as `to_bytes`
+impl CLTyped for ProofRecord {
+  fn cl_type() -> CLType {
+    <(
+      (Option<BurnProof>, Option<u64>, Option<u64>),
+      (Option<AccountHash>, Option<u64>, Option<u64>),
+      (Option<u64>, bool),
+    )>::cl_type()
+  }
+}
+
+impl ToBytes for ProofRecord {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.params.to_bytes()?);
+    result.extend(self.burned_at.to_bytes()?);
+    result.extend(self.approved_at.to_bytes()?);
+    result.extend(self.approver.to_bytes()?);
+    result.extend(self.executed_at.to_bytes()?);
+    result.extend(self.cancelled_at.to_bytes()?);
+    result.extend(self.refunded_at.to_bytes()?);
+    result.extend(self.cancel_attested.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.params.serialized_length()
+      + self.burned_at.serialized_length()
+      + self.approved_at.serialized_length()
+      + self.approver.serialized_length()
+      + self.executed_at.serialized_length()
+      + self.cancelled_at.serialized_length()
+      + self.refunded_at.serialized_length()
+      + self.cancel_attested.serialized_length()
+  }
+}
+
+impl FromBytes for ProofRecord {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (params, bytes) = Option::<BurnProof>::from_bytes(bytes)?;
+    let (burned_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (approved_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (approver, bytes) = Option::<AccountHash>::from_bytes(bytes)?;
+    let (executed_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (cancelled_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (refunded_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (cancel_attested, bytes) = bool::from_bytes(bytes)?;
+
+    let record = ProofRecord {
+      params,
+      burned_at,
+      approved_at,
+      approver,
+      executed_at,
+      cancelled_at,
+      refunded_at,
+      cancel_attested,
+    };
+
+    Ok((record, bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec;
+
+  use casper_types::{account::AccountHash, bytesrepr::Bytes, CLValue};
+
+  use super::ProofRecord;
+  use crate::{BurnProof, PROOF_VERSION};
+
+  #[test]
+  fn cl_value() {
+    let record = ProofRecord {
+      params: Some(BurnProof {
+        proof_version: PROOF_VERSION,
+        mint_caller: Bytes::from(vec![1u8; 40]),
+        burn_caller: Bytes::from(vec![2u8; 40]),
+        mint_token: Bytes::from(vec![3u8; 40]),
+        burn_token: Bytes::from(vec![4u8; 40]),
+        burn_amount: 1_000.into(),
+        mint_chain_type: 1,
+        mint_chain_id: 1337,
+        burn_chain_type: 2,
+        burn_chain_id: 1010,
+        burn_nonce: 2.into(),
+        burn_deadline: 60_000,
+        mint_bridge: Bytes::from(vec![5u8; 40]),
+        burn_bridge: Bytes::from(vec![6u8; 40]),
+      }),
+      burned_at: Some(1_000),
+      approved_at: None,
+      approver: Some(AccountHash::new([7u8; 32])),
+      executed_at: None,
+      cancelled_at: Some(2_000),
+      refunded_at: None,
+      cancel_attested: true,
+    };
+
+    let value = CLValue::from_t(record.clone()).unwrap();
+    let tuple = CLValue::from_t((
+      (record.params.clone(), record.burned_at, record.approved_at),
+      (record.approver, record.executed_at, record.cancelled_at),
+      (record.refunded_at, record.cancel_attested),
+    ))
+    .unwrap();
+
+    assert_eq!(value, tuple);
+    assert_eq!(value.into_t::<ProofRecord>().unwrap(), record);
+
+    // return type of get_burn_proof_record
+    let empty = CLValue::from_t(Some(ProofRecord::default())).unwrap();
+    assert_eq!(
+      empty.into_t::<Option<ProofRecord>>().unwrap(),
+      Some(ProofRecord::default())
+    );
+  }
+}
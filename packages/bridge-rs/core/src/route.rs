@@ -0,0 +1,116 @@
+use alloc::vec::Vec;
+
+use casper_types::{
+  bytesrepr::{self, Bytes, FromBytes, ToBytes},
+  CLType, CLTyped,
+};
+
+// set_allowance arguments of one route, list element of the install config
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Route {
+  pub mint_token: Bytes,
+  pub burn_token: Bytes,
+  pub mint_chain_type: u8,
+  pub mint_chain_id: u32,
+  pub burn_chain_type: u8,
+  pub burn_chain_id: u32,
+  pub default_ttl: Option<u64>,
+}
+
+// nested tuples of the fields in order, same bytes as `to_bytes`
+impl CLTyped for Route {
+  fn cl_type() -> CLType {
+    <((Bytes, Bytes), (u8, u32), (u8, u32, Option<u64>))>::cl_type()
+  }
+}
+
+impl ToBytes for Route {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.mint_token.to_bytes()?);
+    result.extend(self.burn_token.to_bytes()?);
+    result.extend(self.mint_chain_type.to_bytes()?);
+    result.extend(self.mint_chain_id.to_bytes()?);
+    result.extend(self.burn_chain_type.to_bytes()?);
+    result.extend(self.burn_chain_id.to_bytes()?);
+    result.extend(self.default_ttl.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.mint_token.serialized_length()
+      + self.burn_token.serialized_length()
+      + self.mint_chain_type.serialized_length()
+      + self.mint_chain_id.serialized_length()
+      + self.burn_chain_type.serialized_length()
+      + self.burn_chain_id.serialized_length()
+      + self.default_ttl.serialized_length()
+  }
+}
+
+impl FromBytes for Route {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (mint_token, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_token, bytes) = Bytes::from_bytes(bytes)?;
+    let (mint_chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (mint_chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (burn_chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (burn_chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (default_ttl, bytes) = Option::<u64>::from_bytes(bytes)?;
+
+    let route = Route {
+      mint_token,
+      burn_token,
+      mint_chain_type,
+      mint_chain_id,
+      burn_chain_type,
+      burn_chain_id,
+      default_ttl,
+    };
+
+    Ok((route, bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::{vec, vec::Vec};
+
+  use casper_types::{bytesrepr::Bytes, CLValue};
+
+  use super::Route;
+
+  #[test]
+  fn cl_value() {
+    let route = Route {
+      mint_token: Bytes::from(vec![1u8; 40]),
+      burn_token: Bytes::from(vec![2u8; 40]),
+      mint_chain_type: 1,
+      mint_chain_id: 1337,
+      burn_chain_type: 2,
+      burn_chain_id: 1010,
+      default_ttl: Some(60_000),
+    };
+
+    let value = CLValue::from_t(route.clone()).unwrap();
+    let tuple = CLValue::from_t((
+      (route.mint_token.clone(), route.burn_token.clone()),
+      (route.mint_chain_type, route.mint_chain_id),
+      (
+        route.burn_chain_type,
+        route.burn_chain_id,
+        route.default_ttl,
+      ),
+    ))
+    .unwrap();
+
+    assert_eq!(value, tuple);
+    assert_eq!(value.into_t::<Route>().unwrap(), route);
+
+    // list element of the install arg
+    let routes = CLValue::from_t(vec![route.clone(), route]).unwrap();
+    assert_eq!(routes.into_t::<Vec<Route>>().unwrap().len(), 2);
+  }
+}
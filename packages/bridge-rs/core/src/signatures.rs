@@ -0,0 +1,90 @@
+// signature of every packed wire layout, sha256(name)[..4], shared by the contract,
+// bridge-events and the test vectors the indexer checks
+macro_rules! wire_signatures {
+  ($($sig:ident = $name:literal $signature:expr;)*) => {
+    $(pub const $sig: [u8; 4] = $signature;)*
+
+    // (name, signature) of every layout
+    pub const WIRE_SIGNATURES: &[(&str, [u8; 4])] = &[$(($name, $sig),)*];
+  };
+}
+
+wire_signatures! {
+  // v1, no deadline and hash algorithm, kept for decoders of older events
+  PROOF_OF_BURN_SIG = "ProofOfBurn" [0xc5, 0xe1, 0x9c, 0x70];
+  PROOF_OF_BURN_V2_SIG = "ProofOfBurnV2" [0x6d, 0xf6, 0x7a, 0xc0];
+  // v1, no hash algorithm, nonce, recipient, executor and block time, kept for decoders
+  PROOF_OF_MINT_SIG = "ProofOfMint" [0xab, 0xba, 0x24, 0x3b];
+  PROOF_OF_MINT_V2_SIG = "ProofOfMintV2" [0x76, 0xd4, 0xe9, 0x96];
+  APPROVED_BURN_PROOF_SIG = "ApprovedBurnProof" [0xa4, 0x39, 0xa6, 0x33];
+  CANCELLED_BURN_PROOF_SIG = "CancelledBurnProof" [0x3b, 0x04, 0x80, 0x4f];
+  REFUNDED_BURN_PROOF_SIG = "RefundedBurnProof" [0x38, 0xd4, 0x94, 0x20];
+  APPROVED_MERKLE_ROOT_SIG = "ApprovedMerkleRoot" [0x74, 0x18, 0x82, 0xb0];
+  ATTESTED_BURN_CANCELLATION_SIG = "AttestedBurnCancellation" [0x10, 0x40, 0xf8, 0xd6];
+  ALLOWANCE_SET_SIG = "AllowanceSet" [0x8e, 0x01, 0xc2, 0xbd];
+  ROLE_GRANTED_SIG = "RoleGranted" [0xa9, 0x08, 0xd6, 0x0c];
+  ROLE_REVOKED_SIG = "RoleRevoked" [0x6b, 0xe1, 0xfa, 0xf7];
+  CHAIN_SET_SIG = "ChainSet" [0xa9, 0x96, 0xba, 0xcc];
+  CHAIN_ENABLED_SET_SIG = "ChainEnabledSet" [0x6b, 0xe9, 0x8c, 0x0f];
+  REFUND_TIMEOUT_SET_SIG = "RefundTimeoutSet" [0xcf, 0x0e, 0x71, 0xcd];
+  BRIDGE_CONFIGURED_SIG = "BridgeConfigured" [0x86, 0x55, 0xea, 0x4b];
+  SCHEMA_MIGRATED_SIG = "SchemaMigrated" [0xbe, 0x3b, 0x9b, 0x33];
+  PROOF_STATES_DROPPED_SIG = "ProofStatesDropped" [0x80, 0xa6, 0x12, 0x22];
+}
+
+// wire layout name -> signature
+pub fn wire_signature(name: &str) -> Option<[u8; 4]> {
+  WIRE_SIGNATURES
+    .iter()
+    .find(|(layout, _)| *layout == name)
+    .map(|(_, signature)| *signature)
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+  };
+
+  use super::{wire_signature, WIRE_SIGNATURES};
+  use crate::{encode_hex, sha256};
+
+  const EVENT_SIGNATURES_VECTORS: &str =
+    include_str!("../../../test-vectors/event_signatures.json");
+
+  #[test]
+  fn name_hashes() {
+    for (name, signature) in WIRE_SIGNATURES.iter() {
+      assert_eq!(&sha256(name.as_bytes())[..4], signature, "{}", name);
+    }
+
+    assert_eq!(wire_signature("ChainSet"), Some([0xa9, 0x96, 0xba, 0xcc]));
+    assert_eq!(wire_signature("Unknown"), None);
+  }
+
+  // same vectors are checked by the indexer
+  #[test]
+  fn golden_vectors() {
+    let vectors: serde_json::Value = serde_json::from_str(EVENT_SIGNATURES_VECTORS).unwrap();
+
+    let signatures: Vec<(String, String)> = vectors["signatures"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|vector| {
+        (
+          vector["name"].as_str().unwrap().to_string(),
+          vector["signature"].as_str().unwrap().to_string(),
+        )
+      })
+      .collect();
+
+    let table: Vec<(String, String)> = WIRE_SIGNATURES
+      .iter()
+      .map(|(name, signature)| (name.to_string(), encode_hex(signature)))
+      .collect();
+
+    assert_eq!(table, signatures);
+  }
+}
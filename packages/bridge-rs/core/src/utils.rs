@@ -0,0 +1,55 @@
+use core::fmt::Write;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use casper_types::U256;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+pub fn sha256(data: &[u8]) -> Vec<u8> {
+  let mut instance = Sha256::new();
+  instance.update(data);
+  let result = instance.finalize();
+  result.to_vec()
+}
+
+// original keccak padding, same as solidity keccak256 (not sha3-256)
+pub fn keccak256(data: &[u8]) -> Vec<u8> {
+  let mut instance = Keccak256::new();
+  instance.update(data);
+  let result = instance.finalize();
+  result.to_vec()
+}
+
+pub fn merge_bytes(vecs: Vec<Vec<u8>>) -> Vec<u8> {
+  let mut data = Vec::new();
+
+  for vec in vecs {
+    data.extend(vec);
+  }
+
+  data
+}
+
+pub fn encode_hex(bytes: &[u8]) -> String {
+  let mut s = String::with_capacity(bytes.len() * 2);
+  for &b in bytes {
+    write!(&mut s, "{:02x}", b).unwrap();
+  }
+  s
+}
+
+// compatible with abi encode (solidity)
+pub fn u256_to_bytes(u: &U256) -> Vec<u8> {
+  let mut buffer = [0u8; 32];
+  u.to_big_endian(&mut buffer);
+  buffer.to_vec()
+}
+
+// 32 bytes address to 40 bytes generic address
+pub fn pad_with_8_bytes(bytes: Vec<u8>) -> Vec<u8> {
+  let pad_bytes = alloc::vec![0; 8];
+
+  merge_bytes(alloc::vec![pad_bytes, bytes])
+}
@@ -1,10 +1,12 @@
 import {
   CasperClient,
   CasperServiceByJsonRPC,
+  CLBool,
   CLByteArray,
   CLKey,
   CLList,
   CLPublicKey,
+  CLString,
   CLU256,
   CLU32,
   CLU8,
@@ -288,6 +290,23 @@ export function setAllowance(
   return buildDeploy(fromAccount, contractHash, "set_allowance", args);
 }
 
+export function setChain(
+  fromAccount: Secp256K1,
+  contractHash: Uint8Array,
+
+  //
+  args: {
+    chain_type: CLU8;
+    chain_id: CLU32;
+    address_length: CLU8;
+    name: CLString;
+    remote_bridge: CLList<CLU8>;
+    enabled: CLBool;
+  },
+) {
+  return buildDeploy(fromAccount, contractHash, "set_chain", args);
+}
+
 export function mintWithBurnProof(
   fromAccount: Secp256K1,
   contractHash: Uint8Array,
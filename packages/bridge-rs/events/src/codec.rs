@@ -0,0 +1,220 @@
+use std::convert::TryInto;
+use std::fmt;
+
+use casper_types::U256;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::DecodeError;
+
+pub(crate) const SIGNATURE_LENGTH: usize = 4;
+
+pub(crate) struct Reader<'a> {
+  event: &'static str,
+  bytes: &'a [u8],
+  offset: usize,
+}
+
+impl<'a> Reader<'a> {
+  // `bytes` is the whole blob, reading starts after the signature
+  pub(crate) fn new(event: &'static str, bytes: &'a [u8]) -> Self {
+    Reader {
+      event,
+      bytes,
+      offset: SIGNATURE_LENGTH,
+    }
+  }
+
+  fn take(&mut self, field: &'static str, length: usize) -> Result<&'a [u8], DecodeError> {
+    let remaining = self.bytes.len() - self.offset;
+
+    if remaining < length {
+      return Err(DecodeError::UnexpectedEnd {
+        event: self.event,
+        field,
+        offset: self.offset,
+        expected: length,
+        remaining,
+      });
+    }
+
+    let value = &self.bytes[self.offset..self.offset + length];
+    self.offset += length;
+
+    Ok(value)
+  }
+
+  fn rest(&mut self) -> &'a [u8] {
+    let value = &self.bytes[self.offset..];
+    self.offset = self.bytes.len();
+
+    value
+  }
+
+  pub(crate) fn finish(self) -> Result<(), DecodeError> {
+    if self.offset < self.bytes.len() {
+      return Err(DecodeError::TrailingBytes {
+        event: self.event,
+        offset: self.offset,
+        remaining: self.bytes.len() - self.offset,
+      });
+    }
+
+    Ok(())
+  }
+}
+
+// fixed width big endian fields, strings take the rest of the blob
+pub(crate) trait WireField: Sized {
+  fn read(reader: &mut Reader, field: &'static str) -> Result<Self, DecodeError>;
+
+  fn write(&self, out: &mut Vec<u8>);
+}
+
+macro_rules! int_field {
+  ($($ty:ty),*) => {
+    $(
+      impl WireField for $ty {
+        fn read(reader: &mut Reader, field: &'static str) -> Result<Self, DecodeError> {
+          let bytes = reader.take(field, std::mem::size_of::<$ty>())?;
+
+          Ok(<$ty>::from_be_bytes(bytes.try_into().unwrap()))
+        }
+
+        fn write(&self, out: &mut Vec<u8>) {
+          out.extend_from_slice(&self.to_be_bytes());
+        }
+      }
+    )*
+  };
+}
+
+int_field!(u8, u32, u64);
+
+impl WireField for bool {
+  fn read(reader: &mut Reader, field: &'static str) -> Result<Self, DecodeError> {
+    let offset = reader.offset;
+
+    match u8::read(reader, field)? {
+      0 => Ok(false),
+      1 => Ok(true),
+      value => Err(DecodeError::InvalidBool {
+        event: reader.event,
+        field,
+        offset,
+        value,
+      }),
+    }
+  }
+
+  fn write(&self, out: &mut Vec<u8>) {
+    out.push(*self as u8);
+  }
+}
+
+impl WireField for U256 {
+  fn read(reader: &mut Reader, field: &'static str) -> Result<Self, DecodeError> {
+    Ok(U256::from_big_endian(reader.take(field, 32)?))
+  }
+
+  fn write(&self, out: &mut Vec<u8>) {
+    let mut bytes = [0u8; 32];
+    self.to_big_endian(&mut bytes);
+
+    out.extend_from_slice(&bytes);
+  }
+}
+
+impl WireField for String {
+  fn read(reader: &mut Reader, field: &'static str) -> Result<Self, DecodeError> {
+    let offset = reader.offset;
+
+    String::from_utf8(reader.rest().to_vec()).map_err(|_| DecodeError::InvalidUtf8 {
+      event: reader.event,
+      field,
+      offset,
+    })
+  }
+
+  fn write(&self, out: &mut Vec<u8>) {
+    out.extend_from_slice(self.as_bytes());
+  }
+}
+
+// byte arrays, hex encoded in json
+macro_rules! fixed_bytes {
+  ($($name:ident($length:expr);)*) => {
+    $(
+      #[derive(PartialEq, Eq, Clone, Copy)]
+      pub struct $name(pub [u8; $length]);
+
+      impl WireField for $name {
+        fn read(reader: &mut Reader, field: &'static str) -> Result<Self, DecodeError> {
+          let bytes = reader.take(field, $length)?;
+
+          Ok($name(bytes.try_into().unwrap()))
+        }
+
+        fn write(&self, out: &mut Vec<u8>) {
+          out.extend_from_slice(&self.0);
+        }
+      }
+
+      impl fmt::Debug for $name {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+          write!(f, "{}({})", stringify!($name), encode_hex(&self.0))
+        }
+      }
+
+      impl Serialize for $name {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+          serializer.serialize_str(&encode_hex(&self.0))
+        }
+      }
+
+      impl<'de> Deserialize<'de> for $name {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+          let hex = String::deserialize(deserializer)?;
+          let bytes = decode_hex(&hex).map_err(<D::Error as de::Error>::custom)?;
+
+          let value = bytes.try_into().map_err(|bytes: Vec<u8>| {
+            <D::Error as de::Error>::invalid_length(
+              bytes.len(),
+              &concat!(stringify!($length), " bytes"),
+            )
+          })?;
+
+          Ok($name(value))
+        }
+      }
+    )*
+  };
+}
+
+fixed_bytes! {
+  // generic address, see bridge_core::GenericAddress
+  Address(40);
+  // proof and allowance hashes, merkle roots, account hashes
+  Hash(32);
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+  bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// optional 0x prefix
+pub(crate) fn decode_hex(hex: &str) -> Result<Vec<u8>, String> {
+  let hex = hex.strip_prefix("0x").unwrap_or(hex);
+
+  if !hex.is_ascii() {
+    return Err(String::from("non ascii hex"));
+  }
+
+  if hex.len() % 2 != 0 {
+    return Err(format!("odd hex length {}", hex.len()));
+  }
+
+  (0..hex.len())
+    .step_by(2)
+    .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| format!("invalid hex at {}", i)))
+    .collect()
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+// offsets are counted from the start of the blob, signature included
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DecodeError {
+  MissingSignature {
+    length: usize,
+  },
+  UnknownSignature {
+    signature: [u8; 4],
+  },
+  UnexpectedEnd {
+    event: &'static str,
+    field: &'static str,
+    offset: usize,
+    expected: usize,
+    remaining: usize,
+  },
+  TrailingBytes {
+    event: &'static str,
+    offset: usize,
+    remaining: usize,
+  },
+  InvalidBool {
+    event: &'static str,
+    field: &'static str,
+    offset: usize,
+    value: u8,
+  },
+  InvalidUtf8 {
+    event: &'static str,
+    field: &'static str,
+    offset: usize,
+  },
+}
+
+impl fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      DecodeError::MissingSignature { length } => {
+        write!(f, "event of {} bytes has no 4 byte signature", length)
+      }
+      DecodeError::UnknownSignature { signature } => {
+        write!(f, "unknown event signature {:02x?}", signature)
+      }
+      DecodeError::UnexpectedEnd {
+        event,
+        field,
+        offset,
+        expected,
+        remaining,
+      } => write!(
+        f,
+        "{}.{} at offset {}: expected {} bytes, {} remaining",
+        event, field, offset, expected, remaining
+      ),
+      DecodeError::TrailingBytes {
+        event,
+        offset,
+        remaining,
+      } => write!(
+        f,
+        "{}: {} trailing bytes at offset {}",
+        event, remaining, offset
+      ),
+      DecodeError::InvalidBool {
+        event,
+        field,
+        offset,
+        value,
+      } => write!(
+        f,
+        "{}.{} at offset {}: {} is not a bool",
+        event, field, offset, value
+      ),
+      DecodeError::InvalidUtf8 {
+        event,
+        field,
+        offset,
+      } => write!(f, "{}.{} at offset {}: invalid utf-8", event, field, offset),
+    }
+  }
+}
+
+impl std::error::Error for DecodeError {}
@@ -0,0 +1,419 @@
+use casper_types::U256;
+use serde::{Deserialize, Serialize};
+
+pub use bridge_core::{
+  ALLOWANCE_SET_SIG, APPROVED_BURN_PROOF_SIG, APPROVED_MERKLE_ROOT_SIG,
+  ATTESTED_BURN_CANCELLATION_SIG, BRIDGE_CONFIGURED_SIG, CANCELLED_BURN_PROOF_SIG,
+  CHAIN_ENABLED_SET_SIG, CHAIN_SET_SIG, PROOF_OF_BURN_SIG, PROOF_OF_BURN_V2_SIG, PROOF_OF_MINT_SIG,
+  PROOF_OF_MINT_V2_SIG, PROOF_STATES_DROPPED_SIG, REFUNDED_BURN_PROOF_SIG, REFUND_TIMEOUT_SET_SIG,
+  ROLE_GRANTED_SIG, ROLE_REVOKED_SIG, SCHEMA_MIGRATED_SIG,
+};
+
+use crate::codec::{Reader, WireField, SIGNATURE_LENGTH};
+use crate::{Address, DecodeError, Hash};
+
+// declares the event structs and `WireEvent`, field order is the wire order
+macro_rules! wire_events {
+  ($($name:ident = $sig:ident, { $($field:ident: $ty:ty,)* })*) => {
+    $(
+      #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+      pub struct $name {
+        $(pub $field: $ty,)*
+      }
+    )*
+
+    #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+    #[serde(tag = "event")]
+    pub enum WireEvent {
+      $($name($name),)*
+    }
+
+    impl WireEvent {
+      pub fn name(&self) -> &'static str {
+        match self {
+          $(WireEvent::$name(_) => stringify!($name),)*
+        }
+      }
+
+      pub fn signature(&self) -> [u8; SIGNATURE_LENGTH] {
+        match self {
+          $(WireEvent::$name(_) => $sig,)*
+        }
+      }
+
+      pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() < SIGNATURE_LENGTH {
+          return Err(DecodeError::MissingSignature { length: bytes.len() });
+        }
+
+        let mut signature = [0u8; SIGNATURE_LENGTH];
+        signature.copy_from_slice(&bytes[..SIGNATURE_LENGTH]);
+
+        match signature {
+          $($sig => {
+            let mut reader = Reader::new(stringify!($name), bytes);
+
+            $(let $field = <$ty as WireField>::read(&mut reader, stringify!($field))?;)*
+
+            reader.finish()?;
+
+            Ok(WireEvent::$name($name { $($field,)* }))
+          })*
+          _ => Err(DecodeError::UnknownSignature { signature }),
+        }
+      }
+
+      pub fn encode(&self) -> Vec<u8> {
+        match self {
+          $(WireEvent::$name(event) => {
+            let mut out = $sig.to_vec();
+            $(event.$field.write(&mut out);)*
+
+            out
+          })*
+        }
+      }
+    }
+  };
+}
+
+// signatures come from the bridge-core table, same layouts as `emit` in the contract
+wire_events! {
+  // v1, emitted before burn deadlines and hash algorithms were added
+  ProofOfBurn = PROOF_OF_BURN_SIG, {
+    mint_token: Address,
+    burn_token: Address,
+    mint_caller: Address,
+    burn_caller: Address,
+    burn_amount: U256,
+    burn_nonce: U256,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    burn_proof_hash: Hash,
+  }
+  ProofOfBurnV2 = PROOF_OF_BURN_V2_SIG, {
+    mint_token: Address,
+    burn_token: Address,
+    mint_caller: Address,
+    burn_caller: Address,
+    burn_amount: U256,
+    burn_nonce: U256,
+    burn_deadline: u64,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    burn_proof_hash: Hash,
+    hash_algorithm: u8,
+  }
+  // v1, emitted before hash algorithm, nonce, recipient, executor and block time were added
+  ProofOfMint = PROOF_OF_MINT_SIG, {
+    mint_token: Address,
+    burn_token: Address,
+    mint_caller: Address,
+    burn_caller: Address,
+    burn_amount: U256,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    burn_proof_hash: Hash,
+  }
+  ProofOfMintV2 = PROOF_OF_MINT_V2_SIG, {
+    mint_token: Address,
+    burn_token: Address,
+    mint_caller: Address,
+    burn_caller: Address,
+    burn_amount: U256,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    burn_proof_hash: Hash,
+    hash_algorithm: u8,
+    burn_nonce: U256,
+    recipient: Address,
+    executor: Hash,
+    block_time: u64,
+  }
+  ApprovedBurnProof = APPROVED_BURN_PROOF_SIG, {
+    burn_proof_hash: Hash,
+  }
+  CancelledBurnProof = CANCELLED_BURN_PROOF_SIG, {
+    burn_proof_hash: Hash,
+  }
+  RefundedBurnProof = REFUNDED_BURN_PROOF_SIG, {
+    burn_token: Address,
+    burn_caller: Address,
+    burn_amount: U256,
+    burn_proof_hash: Hash,
+  }
+  ApprovedMerkleRoot = APPROVED_MERKLE_ROOT_SIG, {
+    epoch: u64,
+    root: Hash,
+  }
+  AttestedBurnCancellation = ATTESTED_BURN_CANCELLATION_SIG, {
+    burn_proof_hash: Hash,
+  }
+  AllowanceSet = ALLOWANCE_SET_SIG, {
+    mint_token: Address,
+    burn_token: Address,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    allowance_hash: Hash,
+    default_ttl: u64,
+  }
+  RoleGranted = ROLE_GRANTED_SIG, {
+    account: Hash,
+    role: String,
+  }
+  RoleRevoked = ROLE_REVOKED_SIG, {
+    account: Hash,
+    role: String,
+  }
+  ChainSet = CHAIN_SET_SIG, {
+    chain_type: u8,
+    chain_id: u32,
+    address_length: u8,
+    remote_bridge: Address,
+    enabled: bool,
+    name: String,
+  }
+  ChainEnabledSet = CHAIN_ENABLED_SET_SIG, {
+    chain_type: u8,
+    chain_id: u32,
+    enabled: bool,
+  }
+  RefundTimeoutSet = REFUND_TIMEOUT_SET_SIG, {
+    refund_timeout: u64,
+  }
+  BridgeConfigured = BRIDGE_CONFIGURED_SIG, {
+    chain_type: u8,
+    chain_id: u32,
+    hash_algorithm: u8,
+    refund_timeout: u64,
+  }
+  SchemaMigrated = SCHEMA_MIGRATED_SIG, {
+    from_version: u32,
+    to_version: u32,
+  }
+  ProofStatesDropped = PROOF_STATES_DROPPED_SIG, {
+    schema_version: u32,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use bridge_core::{sha256, wire_signature, WIRE_SIGNATURES};
+  use serde_json::json;
+
+  use super::*;
+  use crate::codec::decode_hex;
+
+  const BRIDGE_EVENTS_VECTORS: &str = include_str!("../../../test-vectors/bridge_events.json");
+
+  fn approved_merkle_root() -> WireEvent {
+    WireEvent::ApprovedMerkleRoot(ApprovedMerkleRoot {
+      epoch: 2,
+      root: Hash([0x11; 32]),
+    })
+  }
+
+  fn chain_set() -> WireEvent {
+    WireEvent::ChainSet(ChainSet {
+      chain_type: 1,
+      chain_id: 1337,
+      address_length: 20,
+      remote_bridge: Address([0x22; 40]),
+      enabled: true,
+      name: String::from("kovan"),
+    })
+  }
+
+  fn proof_of_mint() -> WireEvent {
+    WireEvent::ProofOfMint(ProofOfMint {
+      mint_token: Address([1; 40]),
+      burn_token: Address([2; 40]),
+      mint_caller: Address([3; 40]),
+      burn_caller: Address([4; 40]),
+      burn_amount: 500.into(),
+      mint_chain_type: 2,
+      mint_chain_id: 1010,
+      burn_chain_type: 1,
+      burn_chain_id: 1337,
+      burn_proof_hash: Hash([5; 32]),
+    })
+  }
+
+  fn proof_of_mint_v2() -> WireEvent {
+    WireEvent::ProofOfMintV2(ProofOfMintV2 {
+      mint_token: Address([1; 40]),
+      burn_token: Address([2; 40]),
+      mint_caller: Address([3; 40]),
+      burn_caller: Address([4; 40]),
+      burn_amount: 500.into(),
+      mint_chain_type: 2,
+      mint_chain_id: 1010,
+      burn_chain_type: 1,
+      burn_chain_id: 1337,
+      burn_proof_hash: Hash([5; 32]),
+      hash_algorithm: 0,
+      burn_nonce: 7.into(),
+      recipient: Address([3; 40]),
+      executor: Hash([6; 32]),
+      block_time: 1_000,
+    })
+  }
+
+  // every layout of the bridge-core table is decodable, under its own name
+  #[test]
+  fn signatures_match_table() {
+    for event in [
+      approved_merkle_root(),
+      chain_set(),
+      proof_of_mint(),
+      proof_of_mint_v2(),
+    ]
+    .iter()
+    {
+      assert_eq!(wire_signature(event.name()), Some(event.signature()));
+      assert_eq!(&sha256(event.name().as_bytes())[..4], &event.signature());
+    }
+
+    // a bare signature is known and runs out of bytes in the event it names
+    for (name, signature) in WIRE_SIGNATURES.iter() {
+      match WireEvent::decode(signature) {
+        Err(DecodeError::UnexpectedEnd { event, .. }) => assert_eq!(event, *name),
+        result => panic!("{}: {:?}", name, result),
+      }
+    }
+  }
+
+  #[test]
+  fn round_trip() {
+    for event in [
+      approved_merkle_root(),
+      chain_set(),
+      proof_of_mint(),
+      proof_of_mint_v2(),
+    ]
+    .iter()
+    {
+      let bytes = event.encode();
+
+      assert_eq!(WireEvent::decode(&bytes).as_ref(), Ok(event));
+    }
+
+    // same as the baseline contract and the indexer
+    assert_eq!(proof_of_mint().encode().len(), 238);
+    // 238 + hash algorithm 1 + 32 + 40 + 32 + 8
+    assert_eq!(proof_of_mint_v2().encode().len(), 351);
+  }
+
+  // same vectors are parsed by the indexer
+  #[test]
+  fn golden_vectors() {
+    let vectors: serde_json::Value = serde_json::from_str(BRIDGE_EVENTS_VECTORS).unwrap();
+
+    for vector in vectors["events"].as_array().unwrap() {
+      let name = vector["name"].as_str().unwrap();
+      let bytes = decode_hex(vector["bytes"].as_str().unwrap()).unwrap();
+
+      assert_eq!(
+        bytes.len() as u64,
+        vector["length"].as_u64().unwrap(),
+        "{}",
+        name
+      );
+
+      let event = WireEvent::decode(&bytes).unwrap();
+
+      assert_eq!(
+        serde_json::to_value(&event).unwrap(),
+        vector["event"],
+        "{}",
+        name
+      );
+      assert_eq!(event.encode(), bytes, "{}", name);
+    }
+  }
+
+  #[test]
+  fn precise_errors() {
+    assert_eq!(
+      WireEvent::decode(&[0xa4, 0x39]),
+      Err(DecodeError::MissingSignature { length: 2 })
+    );
+    assert_eq!(
+      WireEvent::decode(&[1, 2, 3, 4]),
+      Err(DecodeError::UnknownSignature {
+        signature: [1, 2, 3, 4]
+      })
+    );
+
+    let bytes = approved_merkle_root().encode();
+
+    assert_eq!(
+      WireEvent::decode(&bytes[..20]),
+      Err(DecodeError::UnexpectedEnd {
+        event: "ApprovedMerkleRoot",
+        field: "root",
+        offset: 12,
+        expected: 32,
+        remaining: 8,
+      })
+    );
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+
+    assert_eq!(
+      WireEvent::decode(&trailing),
+      Err(DecodeError::TrailingBytes {
+        event: "ApprovedMerkleRoot",
+        offset: 44,
+        remaining: 1,
+      })
+    );
+
+    // sig | chain_type | chain_id | address_length | remote_bridge | enabled
+    let mut bytes = chain_set().encode();
+    bytes[4 + 1 + 4 + 1 + 40] = 2;
+
+    assert_eq!(
+      WireEvent::decode(&bytes),
+      Err(DecodeError::InvalidBool {
+        event: "ChainSet",
+        field: "enabled",
+        offset: 50,
+        value: 2,
+      })
+    );
+  }
+
+  #[test]
+  fn json() {
+    let event = approved_merkle_root();
+    let value = serde_json::to_value(&event).unwrap();
+
+    assert_eq!(
+      value,
+      json!({
+        "event": "ApprovedMerkleRoot",
+        "epoch": 2,
+        "root": "11".repeat(32),
+      })
+    );
+
+    assert_eq!(serde_json::from_value::<WireEvent>(value).unwrap(), event);
+
+    let event = proof_of_mint_v2();
+    let json = serde_json::to_string(&event).unwrap();
+
+    assert_eq!(serde_json::from_str::<WireEvent>(&json).unwrap(), event);
+  }
+}
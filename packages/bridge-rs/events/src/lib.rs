@@ -0,0 +1,12 @@
+//! Decoder for the packed bridge events stored in the `bridge_events` dictionary.
+//!
+//! Every event is a 4 byte signature followed by big endian fields and 40 byte
+//! generic addresses, see `emit` in the contract.
+
+mod codec;
+mod error;
+mod events;
+
+pub use codec::{Address, Hash};
+pub use error::DecodeError;
+pub use events::*;
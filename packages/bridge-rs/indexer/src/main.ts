@@ -7,9 +7,9 @@ import {
   handleProofOfMint,
 } from "./mappings";
 import {
+  parseAnyProofOfBurn,
+  parseAnyProofOfMint,
   parseApprovedBurnProof,
-  parseProofOfBurn,
-  parseProofOfMint,
 } from "./schema";
 import {
   ExecutionResultSuccess,
@@ -121,11 +121,11 @@ const prepare = async (input: GetDeployResult, height: number) => {
   let events: Promise<void>[] = [];
   if (entryPoint === "burn_and_create_proof") {
     events = transforms
-      .flatMap(some => parseTransformWith(some, parseProofOfBurn))
+      .flatMap(some => parseTransformWith(some, parseAnyProofOfBurn))
       .map(event => handleProofOfBurn(createEvent(event)));
   } else if (entryPoint === "mint_with_burn_proof") {
     events = transforms
-      .flatMap(some => parseTransformWith(some, parseProofOfMint))
+      .flatMap(some => parseTransformWith(some, parseAnyProofOfMint))
       .map(event => handleProofOfMint(createEvent(event)));
   } else if (entryPoint === "approve_burn_proof") {
     events = transforms
@@ -6,7 +6,9 @@ import {
   ApprovedBurnProof,
   ChainType,
   ProofOfBurn,
+  ProofOfBurnV2,
   ProofOfMint,
+  ProofOfMintV2,
 } from "./schema";
 
 const getOrCreateBridgeTransfer = async (id: string) => {
@@ -65,7 +67,9 @@ export interface Event<T> {
   timestamp: number;
 }
 
-export const handleProofOfBurn = async (event: Event<ProofOfBurn>) => {
+export const handleProofOfBurn = async (
+  event: Event<ProofOfBurn | ProofOfBurnV2>,
+) => {
   const {
     mint_chain_type,
     burn_chain_type,
@@ -109,7 +113,9 @@ export const handleProofOfBurn = async (event: Event<ProofOfBurn>) => {
   await saveProof(proof);
 };
 
-export const handleProofOfMint = async (event: Event<ProofOfMint>) => {
+export const handleProofOfMint = async (
+  event: Event<ProofOfMint | ProofOfMintV2>,
+) => {
   const {
     mint_chain_type,
     burn_chain_type,
@@ -133,7 +139,11 @@ export const handleProofOfMint = async (event: Event<ProofOfMint>) => {
   const proof: Proof = {
     id: proofOfBurn,
     type: "Mint",
-    nonce: null, // find from burn (?)
+    // v1 events carry no nonce, it comes with the burn
+    nonce:
+      "burn_nonce" in event.params
+        ? event.params.burn_nonce.toNumber()
+        : null,
     src: event.params.burn_chain_id,
     srcType: burn_chain_type,
     dest: event.params.mint_chain_id,
@@ -1,44 +1,119 @@
 import BN from "bn.js";
+import { readFileSync } from "fs";
+import { join } from "path";
 import {
+  APPROVED_BURN_PROOF_SIG,
   ApprovedBurnProof,
-  ChainType,
+  parseAnyProofOfBurn,
   parseApprovedBurnProof,
   parseProofOfBurn,
+  parseAnyProofOfMint,
   parseProofOfMint,
-  ProofOfBurn,
-  ProofOfMint,
+  parseProofOfMintV2,
   PROOF_OF_BURN_SIG,
+  PROOF_OF_BURN_V2_SIG,
+  PROOF_OF_MINT_SIG,
+  PROOF_OF_MINT_V2_SIG,
 } from "./schema";
 
-test("test proof of burn", () => {
-  const buffer = Buffer.from(
-    "09010000c5e19c7000000000000000001a3d2469dabebb61a161cafd7eace57a630d26a04970e96c0e4b78d262c4ccc201010101010101010101010101010101010101010101010101010101010101010101010101010101000000000000000070d1c4dbdaa32b4f27351ce912fb6b877653ad14f30e5547b472dc27362f14740101010101010101010101010101010101010101010101010101010101010101010101010101010100000000000000000000000000000000000000000000000000000000000001f4000000000000000000000000000000000000000000000000000000000000000001000000014df33619aab3cca0a4d1a537443139dc79e5b4bd7edcaf1122d0540b892528a8",
-    "hex",
-  );
-  const result = parseProofOfBurn(buffer);
+// shared with the rust decoder in bridge-events
+const vectors: { bytes: string; event: Record<string, unknown> }[] = JSON.parse(
+  readFileSync(
+    join(__dirname, "../../../test-vectors/bridge_events.json"),
+    "utf8",
+  ),
+).events;
 
-  const bytes40 = Buffer.from(Array.from({ length: 40 }).map(_ => 1));
+const vector = (name: string) => {
+  const found = vectors.find(v => v.event.event === name);
 
-  // const mint_token = bytes40;
-  // const mint_caller = bytes40;
+  if (!found) {
+    throw new Error(`no vector for ${name}`);
+  }
 
-  // TODO: compute burn hash
+  return found;
+};
 
-  expect(result).toBe({
-    _length: 270,
-    _sig: PROOF_OF_BURN_SIG,
-    mint_token: expect.any(Buffer),
-    burn_token: bytes40,
-    mint_caller: expect.any(Buffer),
-    burn_caller: bytes40,
-    burn_proof_hash: Buffer.from([]),
-    burn_amount: new BN(500),
-    mint_chain_type: 2,
-    mint_chain_id: 1010,
-    burn_chain_id: 1,
-    burn_nonce: new BN(0),
-    burn_chain_type: ChainType.Evm,
-  } as ProofOfBurn);
+// signature table of bridge-core
+const signatures: { name: string; signature: string }[] = JSON.parse(
+  readFileSync(
+    join(__dirname, "../../../test-vectors/event_signatures.json"),
+    "utf8",
+  ),
+).signatures;
+
+// uref value as written to global state: u32 little endian length, then the event
+const clValueBytes = (hex: string) => {
+  const bytes = Buffer.from(hex, "hex");
+  const length = Buffer.alloc(4);
+  length.writeUInt32LE(bytes.length);
+
+  return Buffer.concat([length, bytes]);
+};
+
+// vectors hold hex strings and decimal amounts
+const plain = (value: unknown) => {
+  if (Buffer.isBuffer(value)) {
+    return value.toString("hex");
+  }
+
+  if (BN.isBN(value)) {
+    return value.toString();
+  }
+
+  return String(value);
+};
+
+const expectFields = (result: object, event: Record<string, unknown>) => {
+  const { event: _name, ...fields } = event;
+
+  for (const [field, value] of Object.entries(fields)) {
+    expect([field, plain((result as Record<string, unknown>)[field])]).toEqual([
+      field,
+      String(value),
+    ]);
+  }
+};
+
+test("test signatures match the shared table", () => {
+  const used: [string, Buffer][] = [
+    ["ProofOfBurn", PROOF_OF_BURN_SIG],
+    ["ProofOfBurnV2", PROOF_OF_BURN_V2_SIG],
+    ["ProofOfMint", PROOF_OF_MINT_SIG],
+    ["ProofOfMintV2", PROOF_OF_MINT_V2_SIG],
+    ["ApprovedBurnProof", APPROVED_BURN_PROOF_SIG],
+  ];
+
+  for (const [name, sig] of used) {
+    const found = signatures.find(s => s.name === name);
+
+    expect([name, found && found.signature]).toEqual([
+      name,
+      sig.toString("hex"),
+    ]);
+  }
+});
+
+test("test proof of burn v1", () => {
+  const { bytes, event } = vector("ProofOfBurn");
+  const result = parseAnyProofOfBurn(clValueBytes(bytes));
+
+  expect(result._length).toEqual(270);
+  expect(result._sig).toEqual(PROOF_OF_BURN_SIG);
+  expect("burn_deadline" in result).toBe(false);
+  expectFields(result, event);
+});
+
+test("test proof of burn v2", () => {
+  const { bytes, event } = vector("ProofOfBurnV2");
+  const result = parseAnyProofOfBurn(clValueBytes(bytes));
+
+  expect(result._length).toEqual(279);
+  expect(result._sig).toEqual(PROOF_OF_BURN_V2_SIG);
+  expectFields(result, event);
+
+  // v1 parser refuses the v2 signature
+  expect(() => parseProofOfBurn(clValueBytes(bytes))).toThrow();
 });
 
 // TODO: implement
@@ -51,11 +126,27 @@ test("test approve burn proof", () => {
   } as ApprovedBurnProof);
 });
 
-test("test proof of mint", () => {
-  const buffer = Buffer.from("000", "hex");
-  const result = parseProofOfMint(buffer);
+test("test proof of mint v1", () => {
+  const { bytes, event } = vector("ProofOfMint");
+  const result = parseAnyProofOfMint(clValueBytes(bytes));
 
-  expect(result).toBe({
-    // _length: 32,
-  } as ProofOfMint);
+  expect(result._length).toEqual(238);
+  expect(result._sig).toEqual(PROOF_OF_MINT_SIG);
+  expect("hash_algorithm" in result).toBe(false);
+  expectFields(result, event);
+
+  // v2 parser refuses the v1 signature
+  expect(() => parseProofOfMintV2(clValueBytes(bytes))).toThrow();
+});
+
+test("test proof of mint v2", () => {
+  const { bytes, event } = vector("ProofOfMintV2");
+  const result = parseAnyProofOfMint(clValueBytes(bytes));
+
+  expect(result._length).toEqual(351);
+  expect(result._sig).toEqual(PROOF_OF_MINT_V2_SIG);
+  expectFields(result, event);
+
+  // v1 parser refuses the v2 signature
+  expect(() => parseProofOfMint(clValueBytes(bytes))).toThrow();
 });
@@ -6,9 +6,16 @@ const leToBe = (arr: number[]) => {
 };
 
 export const PROOF_OF_BURN_SIG = Buffer.from([0xc5, 0xe1, 0x9c, 0x70]);
+export const PROOF_OF_BURN_V2_SIG = Buffer.from([0x6d, 0xf6, 0x7a, 0xc0]);
 export const PROOF_OF_MINT_SIG = Buffer.from([0xab, 0xba, 0x24, 0x3b]);
+export const PROOF_OF_MINT_V2_SIG = Buffer.from([0x76, 0xd4, 0xe9, 0x96]);
 export const APPROVED_BURN_PROOF_SIG = Buffer.from([0xa4, 0x39, 0xa6, 0x33]);
 
+export enum HashAlgorithm {
+  Sha256 = 0,
+  Keccak256,
+}
+
 export enum ChainType {
   Undefined = 0,
   Evm,
@@ -17,6 +24,7 @@ export enum ChainType {
   Radix,
 }
 
+// v1 layout, emitted by the baseline contract
 export type ProofOfMint = {
   _length: 238;
   _sig: Buffer;
@@ -32,75 +40,123 @@ export type ProofOfMint = {
   burn_proof_hash: Buffer;
 };
 
-const proofOfMint = new Parser()
-  .endianess("big")
-  .array("_length", {
-    type: "uint8",
-    length: 4,
-    formatter: arr => {
-      return leToBe(arr);
-    },
-  })
-  .array("_sig", {
-    type: "uint8",
-    length: 4,
-    formatter: arr => {
-      return Buffer.from(arr, "hex");
-    },
-  })
-  .array("mint_token", {
-    type: "uint8",
-    length: 40,
-    formatter: arr => {
-      return Buffer.from(arr, "hex");
+// v2 appends its fields to the v1 layout
+export type ProofOfMintV2 = Omit<ProofOfMint, "_length"> & {
+  _length: 351;
+  hash_algorithm: HashAlgorithm;
+  burn_nonce: BN;
+  recipient: Buffer;
+  executor: Buffer;
+  block_time: BN;
+};
+
+const proofOfMintFields = () =>
+  new Parser()
+    .endianess("big")
+    .array("_length", {
+      type: "uint8",
+      length: 4,
+      formatter: arr => {
+        return leToBe(arr);
+      },
+    })
+    .array("_sig", {
+      type: "uint8",
+      length: 4,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    })
+    .array("mint_token", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    })
+    .array("burn_token", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    })
+    .array("mint_caller", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    })
+    .array("burn_caller", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    })
+    .array("burn_amount", {
+      type: "uint8",
+      length: 32,
+      formatter: arr => {
+        return new BN(arr);
+      },
+    })
+    .uint8("mint_chain_type", {
+      formatter: n => {
+        return n as ChainType;
+      },
+    })
+    .uint32("mint_chain_id")
+    .uint8("burn_chain_type", {
+      formatter: n => {
+        return n as ChainType;
+      },
+    })
+    .uint32("burn_chain_id")
+    .array("burn_proof_hash", {
+      type: "uint8",
+      length: 32,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    });
+
+const proofOfMint = proofOfMintFields();
+
+const proofOfMintV2 = proofOfMintFields()
+  .uint8("hash_algorithm", {
+    formatter: n => {
+      return n as HashAlgorithm;
     },
   })
-  .array("burn_token", {
+  .array("burn_nonce", {
     type: "uint8",
-    length: 40,
+    length: 32,
     formatter: arr => {
-      return Buffer.from(arr, "hex");
+      return new BN(arr);
     },
   })
-  .array("mint_caller", {
+  .array("recipient", {
     type: "uint8",
     length: 40,
     formatter: arr => {
       return Buffer.from(arr, "hex");
     },
   })
-  .array("burn_caller", {
+  .array("executor", {
     type: "uint8",
-    length: 40,
+    length: 32,
     formatter: arr => {
       return Buffer.from(arr, "hex");
     },
   })
-  .array("burn_amount", {
+  .array("block_time", {
     type: "uint8",
-    length: 32,
+    length: 8,
     formatter: arr => {
       return new BN(arr);
     },
-  })
-  .uint8("mint_chain_type", {
-    formatter: n => {
-      return n as ChainType;
-    },
-  })
-  .uint32("mint_chain_id")
-  .uint8("burn_chain_type", {
-    formatter: n => {
-      return n as ChainType;
-    },
-  })
-  .uint32("burn_chain_id")
-  .array("burn_proof_hash", {
-    type: "uint8",
-    length: 32,
-    formatter: arr => {
-      return Buffer.from(arr, "hex");
-    },
   });
 
 export const parseProofOfMint = (buffer: Buffer) => {
@@ -117,6 +173,20 @@ export const parseProofOfMint = (buffer: Buffer) => {
   return result;
 };
 
+export const parseProofOfMintV2 = (buffer: Buffer) => {
+  const result = proofOfMintV2.parse(buffer) as ProofOfMintV2;
+
+  if (!result._sig.equals(PROOF_OF_MINT_V2_SIG)) {
+    throw new Error("invalid signature");
+  }
+
+  if (result._length !== 351) {
+    throw new Error("invalid event");
+  }
+
+  return result;
+};
+
 export type ApprovedBurnProof = {
   _length: 36;
   _sig: Buffer;
@@ -161,6 +231,7 @@ export const parseApprovedBurnProof = (buffer: Buffer) => {
   return result;
 };
 
+// v1 layout, emitted before burn deadlines and hash algorithms
 export type ProofOfBurn = {
   _length: 270;
   _sig: Buffer;
@@ -177,83 +248,112 @@ export type ProofOfBurn = {
   burn_proof_hash: Buffer;
 };
 
-const proofOfBurn = new Parser()
-  .endianess("big")
-  .array("_length", {
-    type: "uint8",
-    length: 4,
-    formatter: arr => {
-      return leToBe(arr);
-    },
-  })
-  .array("_sig", {
-    type: "uint8",
-    length: 4,
-    formatter: arr => {
-      return Buffer.from(arr, "hex");
-    },
-  })
-  .array("mint_token", {
-    type: "uint8",
-    length: 40,
-    formatter: arr => {
-      return Buffer.from(arr, "hex");
-    },
-  })
-  .array("burn_token", {
-    type: "uint8",
-    length: 40,
-    formatter: arr => {
-      return Buffer.from(arr);
-    },
-  })
-  .array("mint_caller", {
-    type: "uint8",
-    length: 40,
-    formatter: arr => {
-      return Buffer.from(arr);
-    },
-  })
-  .array("burn_caller", {
-    type: "uint8",
-    length: 40,
-    formatter: arr => {
-      return Buffer.from(arr);
-    },
-  })
-  .array("burn_amount", {
-    type: "uint8",
-    length: 32,
-    formatter: arr => {
-      return new BN(arr);
-    },
-  })
-  .array("burn_nonce", {
+// v2 puts the deadline after the nonce and appends the hash algorithm
+export type ProofOfBurnV2 = Omit<ProofOfBurn, "_length"> & {
+  _length: 279;
+  burn_deadline: BN;
+  hash_algorithm: HashAlgorithm;
+};
+
+// length prefix, signature, addresses, amount and nonce, same in both versions
+const proofOfBurnHead = () =>
+  new Parser()
+    .endianess("big")
+    .array("_length", {
+      type: "uint8",
+      length: 4,
+      formatter: arr => {
+        return leToBe(arr);
+      },
+    })
+    .array("_sig", {
+      type: "uint8",
+      length: 4,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    })
+    .array("mint_token", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr, "hex");
+      },
+    })
+    .array("burn_token", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr);
+      },
+    })
+    .array("mint_caller", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr);
+      },
+    })
+    .array("burn_caller", {
+      type: "uint8",
+      length: 40,
+      formatter: arr => {
+        return Buffer.from(arr);
+      },
+    })
+    .array("burn_amount", {
+      type: "uint8",
+      length: 32,
+      formatter: arr => {
+        return new BN(arr);
+      },
+    })
+    .array("burn_nonce", {
+      type: "uint8",
+      length: 32,
+      formatter: arr => {
+        return new BN(arr);
+      },
+    });
+
+// chains and proof hash, same in both versions
+const proofOfBurnTail = (parser: Parser<any>) =>
+  parser
+    .uint8("mint_chain_type", {
+      formatter: n => {
+        return n as ChainType;
+      },
+    })
+    .uint32("mint_chain_id")
+    .uint8("burn_chain_type", {
+      formatter: n => {
+        return n as ChainType;
+      },
+    })
+    .uint32("burn_chain_id")
+    .array("burn_proof_hash", {
+      type: "uint8",
+      length: 32,
+      formatter: arr => {
+        return Buffer.from(arr);
+      },
+    });
+
+const proofOfBurn = proofOfBurnTail(proofOfBurnHead());
+
+const proofOfBurnV2 = proofOfBurnTail(
+  proofOfBurnHead().array("burn_deadline", {
     type: "uint8",
-    length: 32,
+    length: 8,
     formatter: arr => {
       return new BN(arr);
     },
-  })
-  .uint8("mint_chain_type", {
-    formatter: n => {
-      return n as ChainType;
-    },
-  })
-  .uint32("mint_chain_id")
-  .uint8("burn_chain_type", {
-    formatter: n => {
-      return n as ChainType;
-    },
-  })
-  .uint32("burn_chain_id")
-  .array("burn_proof_hash", {
-    type: "uint8",
-    length: 32,
-    formatter: arr => {
-      return Buffer.from(arr);
-    },
-  });
+  }),
+).uint8("hash_algorithm", {
+  formatter: n => {
+    return n as HashAlgorithm;
+  },
+});
 
 export const parseProofOfBurn = (buffer: Buffer) => {
   const result = proofOfBurn.parse(buffer) as ProofOfBurn;
@@ -268,3 +368,42 @@ export const parseProofOfBurn = (buffer: Buffer) => {
 
   return result;
 };
+
+export const parseProofOfBurnV2 = (buffer: Buffer) => {
+  const result = proofOfBurnV2.parse(buffer) as ProofOfBurnV2;
+
+  if (!result._sig.equals(PROOF_OF_BURN_V2_SIG)) {
+    throw new Error("invalid signature");
+  }
+
+  if (result._length !== 279) {
+    throw new Error("invalid event");
+  }
+
+  return result;
+};
+
+// signature follows the u32 length prefix
+const eventSignature = (buffer: Buffer) => buffer.slice(4, 8);
+
+// burns from before and after the upgrade share the entry point
+export const parseAnyProofOfBurn = (
+  buffer: Buffer,
+): ProofOfBurn | ProofOfBurnV2 => {
+  if (eventSignature(buffer).equals(PROOF_OF_BURN_SIG)) {
+    return parseProofOfBurn(buffer);
+  }
+
+  return parseProofOfBurnV2(buffer);
+};
+
+// mints from before and after the upgrade share the entry point
+export const parseAnyProofOfMint = (
+  buffer: Buffer,
+): ProofOfMint | ProofOfMintV2 => {
+  if (eventSignature(buffer).equals(PROOF_OF_MINT_SIG)) {
+    return parseProofOfMint(buffer);
+  }
+
+  return parseProofOfMintV2(buffer);
+};
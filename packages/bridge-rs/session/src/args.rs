@@ -0,0 +1,57 @@
+use alloc::vec::Vec;
+
+use casper_contract::{
+  contract_api::{self, runtime},
+  ext_ffi,
+  unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+  api_error,
+  bytesrepr::{self, FromBytes},
+  ApiError,
+};
+
+fn get_named_arg_size(name: &str) -> Option<usize> {
+  let mut arg_size: usize = 0;
+  let ret = unsafe {
+    ext_ffi::casper_get_named_arg_size(
+      name.as_bytes().as_ptr(),
+      name.len(),
+      &mut arg_size as *mut usize,
+    )
+  };
+
+  match api_error::result_from(ret) {
+    Ok(_) => Some(arg_size),
+    Err(ApiError::MissingArgument) => None,
+    Err(e) => runtime::revert(e),
+  }
+}
+
+// same as runtime::get_named_arg, but missing argument is not an error,
+// copy of the contract helper
+pub fn get_optional_named_arg<T: FromBytes>(name: &str) -> Option<T> {
+  let arg_size = get_named_arg_size(name)?;
+
+  let arg_bytes = if arg_size > 0 {
+    let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
+    let ret = unsafe {
+      ext_ffi::casper_get_named_arg(
+        name.as_bytes().as_ptr(),
+        name.len(),
+        data_non_null_ptr.as_ptr(),
+        arg_size,
+      )
+    };
+    let data = unsafe { Vec::from_raw_parts(data_non_null_ptr.as_ptr(), arg_size, arg_size) };
+
+    api_error::result_from(ret).unwrap_or_revert();
+    data
+  } else {
+    Vec::new()
+  };
+
+  let value = bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument);
+
+  Some(value)
+}
@@ -0,0 +1,54 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+// burns on behalf of the account and keeps the proof hash under a named key,
+// accounts can't read return values of contract calls otherwise
+
+use casper_contract::{
+  contract_api::{runtime, storage},
+  unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+  bytesrepr::Bytes, runtime_args, ContractHash, ContractPackageHash, RuntimeArgs, U256,
+};
+
+use args::get_optional_named_arg;
+
+mod args;
+
+const BURN_PROOF_HASH: &str = "burn_proof_hash";
+
+#[no_mangle]
+pub extern "C" fn call() {
+  let bridge_package_hash = runtime::get_named_arg::<ContractPackageHash>("bridge_package_hash");
+
+  let burn_token = runtime::get_named_arg::<ContractHash>("burn_token");
+  let mint_token = runtime::get_named_arg::<Bytes>("mint_token");
+  let mint_caller = runtime::get_named_arg::<Bytes>("mint_caller");
+  let mint_chain_type = runtime::get_named_arg::<u8>("mint_chain_type");
+  let mint_chain_id = runtime::get_named_arg::<u32>("mint_chain_id");
+  let burn_amount = runtime::get_named_arg::<U256>("burn_amount");
+
+  // optional, missing falls back to the route ttl
+  let deadline = get_optional_named_arg::<u64>("deadline");
+
+  let mut args = runtime_args! {
+    "burn_token" => burn_token,
+    "mint_token" => mint_token,
+    "mint_caller" => mint_caller,
+    "mint_chain_type" => mint_chain_type,
+    "mint_chain_id" => mint_chain_id,
+    "burn_amount" => burn_amount,
+  };
+
+  if let Some(deadline) = deadline {
+    args.insert("deadline", deadline).unwrap_or_revert();
+  }
+
+  let burn_proof_hash: U256 =
+    runtime::call_versioned_contract(bridge_package_hash, None, "burn_and_create_proof", args);
+
+  runtime::put_key(BURN_PROOF_HASH, storage::new_uref(burn_proof_hash).into());
+}
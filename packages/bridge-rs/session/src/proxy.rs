@@ -0,0 +1,80 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+// stored contract in front of the bridge, burns the tokens held by its own package,
+// so the bridge sees a contract package as the immediate caller
+
+use alloc::{string::ToString, vec};
+
+use casper_contract::{
+  contract_api::{runtime, storage},
+  unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{
+  bytesrepr::Bytes, runtime_args, CLType, CLTyped, ContractHash, ContractPackageHash, EntryPoint,
+  EntryPointAccess, EntryPointType, EntryPoints, Parameter, RuntimeArgs, U256,
+};
+
+use args::get_optional_named_arg;
+
+mod args;
+
+const PROXY_PACKAGE_HASH: &str = "proxy_package_hash";
+const PROXY_CONTRACT_HASH: &str = "proxy_contract_hash";
+const BURN_PROOF_HASH: &str = "burn_proof_hash";
+
+#[no_mangle]
+pub extern "C" fn burn() {
+  let bridge_package_hash = runtime::get_named_arg::<ContractPackageHash>("bridge_package_hash");
+
+  let mut args = runtime_args! {
+    "burn_token" => runtime::get_named_arg::<ContractHash>("burn_token"),
+    "mint_token" => runtime::get_named_arg::<Bytes>("mint_token"),
+    "mint_caller" => runtime::get_named_arg::<Bytes>("mint_caller"),
+    "mint_chain_type" => runtime::get_named_arg::<u8>("mint_chain_type"),
+    "mint_chain_id" => runtime::get_named_arg::<u32>("mint_chain_id"),
+    "burn_amount" => runtime::get_named_arg::<U256>("burn_amount"),
+  };
+
+  // forwarded only when given, the bridge applies the route ttl otherwise
+  if let Some(deadline) = get_optional_named_arg::<u64>("deadline") {
+    args.insert("deadline", deadline).unwrap_or_revert();
+  }
+
+  let burn_proof_hash: U256 =
+    runtime::call_versioned_contract(bridge_package_hash, None, "burn_and_create_proof", args);
+
+  runtime::put_key(BURN_PROOF_HASH, storage::new_uref(burn_proof_hash).into());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+  let mut entry_points = EntryPoints::new();
+  entry_points.add_entry_point(EntryPoint::new(
+    "burn",
+    vec![
+      Parameter::new("bridge_package_hash", ContractPackageHash::cl_type()),
+      Parameter::new("burn_token", ContractHash::cl_type()),
+      Parameter::new("mint_token", Bytes::cl_type()),
+      Parameter::new("mint_caller", Bytes::cl_type()),
+      Parameter::new("mint_chain_type", u8::cl_type()),
+      Parameter::new("mint_chain_id", u32::cl_type()),
+      Parameter::new("burn_amount", U256::cl_type()),
+      Parameter::new("deadline", u64::cl_type()), // optional
+    ],
+    CLType::Unit,
+    EntryPointAccess::Public,
+    EntryPointType::Contract,
+  ));
+
+  let (contract_hash, _) = storage::new_contract(
+    entry_points,
+    None,
+    Some(PROXY_PACKAGE_HASH.to_string()),
+    None,
+  );
+
+  runtime::put_key(PROXY_CONTRACT_HASH, contract_hash.into());
+}
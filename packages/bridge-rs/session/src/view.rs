@@ -0,0 +1,80 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+
+// calls a bridge view and keeps the returned value under a named key,
+// accounts can't read return values of contract calls otherwise
+
+use alloc::{string::String, vec::Vec};
+
+use bridge_core::{BridgeConfig, ChainInfo, Nonce, ProofRecord};
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{
+  account::AccountHash,
+  bytesrepr::{Bytes, FromBytes, ToBytes},
+  runtime_args, ApiError, CLTyped, ContractPackageHash, RuntimeArgs, U256,
+};
+
+const VIEW_RESULT: &str = "view_result";
+
+fn view<T: CLTyped + FromBytes + ToBytes>(entry_point: &str, args: RuntimeArgs) {
+  let bridge_package_hash = runtime::get_named_arg::<ContractPackageHash>("bridge_package_hash");
+
+  let result: T = runtime::call_versioned_contract(bridge_package_hash, None, entry_point, args);
+
+  runtime::put_key(VIEW_RESULT, storage::new_uref(result).into());
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+  let entry_point = runtime::get_named_arg::<String>("entry_point");
+
+  // args of the view are passed along with the session args
+  match entry_point.as_str() {
+    "get_proof_state" => view::<u8>(
+      &entry_point,
+      runtime_args! {
+        "proof_hash" => runtime::get_named_arg::<U256>("proof_hash"),
+      },
+    ),
+    "get_allowance" => view::<u8>(
+      &entry_point,
+      runtime_args! {
+        "mint_token" => runtime::get_named_arg::<Bytes>("mint_token"),
+        "burn_token" => runtime::get_named_arg::<Bytes>("burn_token"),
+        "mint_chain_type" => runtime::get_named_arg::<u8>("mint_chain_type"),
+        "mint_chain_id" => runtime::get_named_arg::<u32>("mint_chain_id"),
+        "burn_chain_type" => runtime::get_named_arg::<u8>("burn_chain_type"),
+        "burn_chain_id" => runtime::get_named_arg::<u32>("burn_chain_id"),
+      },
+    ),
+    "get_burn_proof_record" => view::<Option<ProofRecord>>(
+      &entry_point,
+      runtime_args! {
+        "proof_hash" => runtime::get_named_arg::<U256>("proof_hash"),
+      },
+    ),
+    "get_next_nonce" => view::<Nonce>(
+      &entry_point,
+      runtime_args! {
+        "burn_caller" => runtime::get_named_arg::<Bytes>("burn_caller"),
+      },
+    ),
+    "get_chain" => view::<Option<ChainInfo>>(
+      &entry_point,
+      runtime_args! {
+        "chain_type" => runtime::get_named_arg::<u8>("chain_type"),
+        "chain_id" => runtime::get_named_arg::<u32>("chain_id"),
+      },
+    ),
+    "get_config" => view::<BridgeConfig>(&entry_point, runtime_args! {}),
+    "get_roles" => view::<Vec<AccountHash>>(
+      &entry_point,
+      runtime_args! {
+        "role" => runtime::get_named_arg::<String>("role"),
+      },
+    ),
+    _ => runtime::revert(ApiError::InvalidArgument),
+  }
+}
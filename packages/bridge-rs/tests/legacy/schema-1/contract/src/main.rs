@@ -0,0 +1,1513 @@
+#![no_std]
+#![no_main]
+// #![feature(default_alloc_error_handler)]
+
+extern crate alloc;
+
+use casper_types::bytesrepr::Bytes;
+use casper_types::contracts::NamedKeys;
+use endpoints::endpoint;
+// use hex;
+
+use core::convert::TryFrom;
+
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use casper_types::{
+  account::AccountHash,
+  bytesrepr::{FromBytes, ToBytes},
+  CLTyped, CLValue, ContractHash, ContractPackageHash, RuntimeArgs, U128, U256,
+};
+
+use casper_contract::{
+  contract_api::{
+    account,
+    runtime::{self, revert},
+    storage, system,
+  },
+  unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{ApiError, CLType, EntryPoints, Key, Parameter};
+use address::{get_immediate_caller, get_self_package, CasperAddress};
+use erc20::{SuperToken, ERC20Trait};
+use events::{init_events, migrate_events, record_event, record_wire_event};
+use bridge_core::{
+  encode_hex, get_nonce_key, merge_bytes, u256_to_bytes, verify_merkle_path, Allowance,
+  AllowanceSet, ApprovedBurnProof, ApprovedMerkleRoot, AttestedBurnCancellation, BridgeEvent,
+  BurnProof, CancelledBurnProof, ChainEnabledSet, ChainInfo, ChainSet, ChainType, Error,
+  GenericAddress, HashAlgorithm, MintRequest, Nonce, ProofOfBurn, ProofOfMint, RefundTimeoutSet,
+  RefundedBurnProof, RoleGranted, RoleRevoked, States, GENERIC_ADDRESS_LENGTH, PROOF_VERSION,
+};
+use records::ProofRecord;
+use shared::{
+  empty_dict, ensure_dict, ensure_key, get_key, get_optional_named_arg, set_key, u256_to_hex,
+  u32_to_hex, u64_to_hex, u8_to_hex, Dict,
+};
+
+mod address;
+mod endpoints;
+mod erc20;
+mod events;
+mod records;
+mod shared;
+
+trait Thingy {
+  fn to_hex(&self) -> String;
+}
+
+impl Thingy for Vec<u8> {
+  fn to_hex(&self) -> String {
+    return encode_hex(&self);
+  }
+}
+
+impl Thingy for U256 {
+  fn to_hex(&self) -> String {
+    return u256_to_hex(&self);
+  }
+}
+
+impl Thingy for u8 {
+  fn to_hex(&self) -> String {
+    return u8_to_hex(&self);
+  }
+}
+
+impl Thingy for u32 {
+  fn to_hex(&self) -> String {
+    return u32_to_hex(&self);
+  }
+}
+
+const ROLE_APPROVER: &str = "ROLE_APPROVER";
+const ROLE_ADMIN: &str = "ROLE_ADMIN";
+
+const BURN_PROOF_STORAGE_DICT: &str = "burn_proof_storage";
+const PROOF_RECORDS_DICT: &str = "proof_records";
+const ALLOWANCES_DICT: &str = "allowances";
+const ROUTE_TTLS_DICT: &str = "route_ttls";
+const CHAINS_DICT: &str = "chains";
+const MERKLE_ROOTS_DICT: &str = "merkle_roots";
+const NONCES_DICT: &str = "nonces";
+// 
+
+const HASH_ALGORITHM: &str = "hash_algorithm";
+const REFUND_TIMEOUT: &str = "refund_timeout";
+const MERKLE_EPOCH: &str = "merkle_epoch";
+const DEFAULT_REFUND_TIMEOUT: u64 = 7 * 24 * 60 * 60 * 1000; // 7 days in ms
+
+// installer account keys
+const BRIDGE_PACKAGE_HASH: &str = "bridge_package_hash";
+const BRIDGE_CONTRACT_HASH: &str = "bridge_contract_hash";
+
+// layout of the named keys, see `migrate`
+const SCHEMA_VERSION: &str = "schema_version";
+const CURRENT_SCHEMA_VERSION: u32 = 1;
+
+
+fn get_burn_proof_state(proof_hash: U256) -> States {
+  let dict = Dict::at(BURN_PROOF_STORAGE_DICT);
+
+  return dict.get(&proof_hash.to_hex())
+    .map(|v: u8| States::try_from(v).unwrap_or_revert())
+    .unwrap_or(States::Undefined)
+}
+
+fn set_burn_proof_state(proof_hash: U256, state: States)  {
+  let dict = Dict::at(BURN_PROOF_STORAGE_DICT);
+
+  dict.set(&proof_hash.to_hex(), state as u8)
+}
+
+fn find_proof_record(proof_hash: U256) -> Option<ProofRecord> {
+  let dict = Dict::at(PROOF_RECORDS_DICT);
+
+  dict.get(&proof_hash.to_hex())
+}
+
+fn get_proof_record(proof_hash: U256) -> ProofRecord {
+  // if proof not found, provide empty record
+  find_proof_record(proof_hash).unwrap_or_default()
+}
+
+fn set_proof_record(proof_hash: U256, record: ProofRecord) {
+  let dict = Dict::at(PROOF_RECORDS_DICT);
+
+  dict.set(&proof_hash.to_hex(), record)
+}
+
+fn get_allowance_by_hash(hash: Vec<u8>) -> Allowance  {
+  let dict = Dict::at(ALLOWANCES_DICT);
+
+  return dict.get(&hash.to_hex())
+    .map(|v: u8| Allowance::try_from(v).unwrap_or_revert())
+    .unwrap_or(Allowance::Undefined)
+}
+
+fn set_allowance_by_hash(hash: Vec<u8>, allowance: Allowance) {
+  let dict = Dict::at(ALLOWANCES_DICT);
+
+  dict.set(&hash.to_hex(), allowance as u8)
+}
+
+
+// default proof lifetime for a route, in ms
+fn get_route_ttl_by_hash(hash: Vec<u8>) -> u64 {
+  let dict = Dict::at(ROUTE_TTLS_DICT);
+
+  // if ttl not found, proofs on this route never expire
+  dict.get(&hash.to_hex()).unwrap_or(0)
+}
+
+fn set_route_ttl_by_hash(hash: Vec<u8>, ttl: u64) {
+  let dict = Dict::at(ROUTE_TTLS_DICT);
+
+  dict.set(&hash.to_hex(), ttl)
+}
+
+fn get_chain_key(chain_type: u8, chain_id: u32) -> String {
+  u8_to_hex(&chain_type) + &u32_to_hex(&chain_id)
+}
+
+fn find_chain(chain_type: u8, chain_id: u32) -> Option<ChainInfo> {
+  let dict = Dict::at(CHAINS_DICT);
+
+  dict.get(&get_chain_key(chain_type, chain_id))
+}
+
+fn set_chain_info(chain_type: u8, chain_id: u32, info: ChainInfo) {
+  let dict = Dict::at(CHAINS_DICT);
+
+  dict.set(&get_chain_key(chain_type, chain_id), info)
+}
+
+// remote chain must be registered and enabled to be bridged to or from
+fn get_enabled_chain(chain_type: u8, chain_id: u32) -> ChainInfo {
+  let chain = find_chain(chain_type, chain_id).unwrap_or_revert_with(Error::UnknownChain);
+
+  require(chain.enabled, Error::ChainDisabled);
+
+  chain
+}
+
+// root of a batch of approved proof hashes, one per epoch
+fn get_merkle_root_by_epoch(epoch: u64) -> Option<Vec<u8>> {
+  let dict = Dict::at(MERKLE_ROOTS_DICT);
+
+  dict.get(&u64_to_hex(&epoch)).map(|root: Bytes| root.into())
+}
+
+fn set_merkle_root_by_epoch(epoch: u64, root: Bytes) {
+  let dict = Dict::at(MERKLE_ROOTS_DICT);
+
+  dict.set(&u64_to_hex(&epoch), root)
+}
+
+// zero deadline means proof never expires
+fn is_expired(deadline: u64) -> bool {
+  deadline != 0 && get_blocktime() > deadline
+}
+
+// next nonce of a burn caller (generic address)
+fn get_nonce(burn_caller: &[u8]) -> Nonce {
+  let dict = Dict::at(NONCES_DICT);
+  // if caller not found, provide zero nonce
+  dict.get(&get_nonce_key(burn_caller)).unwrap_or_default()
+}
+
+fn set_nonce(burn_caller: &[u8], nonce: Nonce) {
+  let dict = Dict::at(NONCES_DICT);
+
+  dict.set(&get_nonce_key(burn_caller), nonce)
+}
+
+fn has_role(role: &str) -> bool {
+  let caller = runtime::get_caller();
+
+  let role_address: AccountHash = get_key(role).unwrap_or_revert();
+
+  caller == role_address
+}
+
+// TODO: use macro
+fn only_role(role: &str, error: Error) {
+  require(has_role(role), error);
+}
+
+fn get_hash_algorithm() -> HashAlgorithm {
+  let algorithm: u8 = get_key(HASH_ALGORITHM).unwrap_or_revert();
+
+  HashAlgorithm::try_from(algorithm).unwrap_or_revert()
+}
+
+fn get_blocktime() -> u64 {
+  runtime::get_blocktime().into()
+}
+
+
+fn print(s: &str) {
+  #[cfg(feature = "casper-contract/test-support")]
+  runtime::print(s);
+}
+
+// sha256(ProofOfBurn) = c5 e1 9c 70 19c477aefcdafef0a3df24119045c1ed6d916e92bd99388b99ba6216
+// sha256(ProofOfMint) = ab ba 24 3b 9bae2dcfb6e971870ca2e3c2a64f98edb65ab4cdd1dcda54a4fbf369
+// sha256(ApprovedBurnProof) = a4 39 a6 33 2c4168f32836e9fc3a1c1770bd6503c3718aedc53d66544aa65f0191
+
+const PROOF_OF_BURN_SIG: [u8; 4] = [0xC5, 0xE1, 0x9C, 0x70];
+// v1 layout, no longer emitted, kept for decoders of older events
+#[allow(dead_code)]
+const PROOF_OF_MINT_SIG: [u8; 4] = [0xab, 0xba, 0x24, 0x3b];
+const APPROVED_BURN_PROOF_SIG: [u8; 4] = [0xa4, 0x39, 0xa6, 0x33];
+// sha256(ProofOfMintV2) = 76 d4 e9 96 bb0b56c0a288616791e8d4e0b24400df811b7237e8ca29c640629d94
+const PROOF_OF_MINT_V2_SIG: [u8; 4] = [0x76, 0xd4, 0xe9, 0x96];
+// sha256(CancelledBurnProof) = 3b 04 80 4f e29617e26df6a0e55fa279f227c75f1b14283b5d6e02bfd079d163e2
+// sha256(RefundedBurnProof) = 38 d4 94 20 706e12a75fac623c36a5b1ec4779ba10306f7c5a4ef832aa0ba031fc
+const CANCELLED_BURN_PROOF_SIG: [u8; 4] = [0x3b, 0x04, 0x80, 0x4f];
+const REFUNDED_BURN_PROOF_SIG: [u8; 4] = [0x38, 0xd4, 0x94, 0x20];
+// sha256(ApprovedMerkleRoot) = 74 18 82 b0 4842e5073d08c2ca458fe45bb37bc897ab68115c3c026564692b8c37
+const APPROVED_MERKLE_ROOT_SIG: [u8; 4] = [0x74, 0x18, 0x82, 0xb0];
+// sha256(AttestedBurnCancellation) = 10 40 f8 d6 fa8f908dd80dd0e695777f1cf3d293073a2451f53549b042e7b52d3a
+const ATTESTED_BURN_CANCELLATION_SIG: [u8; 4] = [0x10, 0x40, 0xf8, 0xd6];
+// sha256(AllowanceSet) = 8e 01 c2 bd a124ad0b8ce18a96b5fec71b5196170459d796c4fa52948e316bcc16
+// sha256(RoleGranted) = a9 08 d6 0c b05fa462a6af0efc465569450b027feea59f1969e41a9e55de5f1013
+// sha256(RoleRevoked) = 6b e1 fa f7 19ec88256a6a411fc584a8b9d8f7b69f7bd95098ce18a9d0666fe751
+// sha256(ChainSet) = a9 96 ba cc b300f79953971d519d7470ee2c9ecdc9b6e9a05843d6464f065b12fd
+// sha256(ChainEnabledSet) = 6b e9 8c 0f 4cf26cf19a15ef945b65bc617dff44066104181d650f4fc9aca4150b
+// sha256(RefundTimeoutSet) = cf 0e 71 cd cd80bec738ac06fed67653165155395fc8ec8b45b6469ed359b0bb72
+const ALLOWANCE_SET_SIG: [u8; 4] = [0x8e, 0x01, 0xc2, 0xbd];
+const ROLE_GRANTED_SIG: [u8; 4] = [0xa9, 0x08, 0xd6, 0x0c];
+const ROLE_REVOKED_SIG: [u8; 4] = [0x6b, 0xe1, 0xfa, 0xf7];
+const CHAIN_SET_SIG: [u8; 4] = [0xa9, 0x96, 0xba, 0xcc];
+const CHAIN_ENABLED_SET_SIG: [u8; 4] = [0x6b, 0xe9, 0x8c, 0x0f];
+const REFUND_TIMEOUT_SET_SIG: [u8; 4] = [0xcf, 0x0e, 0x71, 0xcd];
+
+#[rustfmt::skip]
+pub fn emit(bridge_event: BridgeEvent) {
+  record_event(&bridge_event);
+
+  let vec_event = match bridge_event {
+    BridgeEvent::ProofOfBurn(ProofOfBurn { mint_token, burn_token, mint_caller, burn_caller, burn_amount, burn_nonce, burn_deadline, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, burn_proof_hash, hash_algorithm }) => {
+      vec![
+        PROOF_OF_BURN_SIG.to_vec(),
+        mint_token.to_vec(),
+        burn_token.to_vec(),
+        mint_caller.to_vec(),
+        burn_caller.to_vec(),
+        u256_to_bytes(&burn_amount),
+        u256_to_bytes(&burn_nonce),
+        burn_deadline.to_be_bytes().to_vec(),
+        mint_chain_type.to_be_bytes().to_vec(),
+        mint_chain_id.to_be_bytes().to_vec(),
+        burn_chain_type.to_be_bytes().to_vec(),
+        burn_chain_id.to_be_bytes().to_vec(),
+        u256_to_bytes(&burn_proof_hash),
+        hash_algorithm.to_be_bytes().to_vec(),
+      ]
+    },
+    BridgeEvent::ProofOfMint(ProofOfMint { mint_token, burn_token, mint_caller, burn_caller, burn_amount, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, burn_proof_hash, hash_algorithm, burn_nonce, recipient, executor, block_time }) => {
+      vec![
+        PROOF_OF_MINT_V2_SIG.to_vec(),
+        mint_token.to_vec(),
+        burn_token.to_vec(),
+        mint_caller.to_vec(),
+        burn_caller.to_vec(),
+        u256_to_bytes(&burn_amount),
+        mint_chain_type.to_be_bytes().to_vec(),
+        mint_chain_id.to_be_bytes().to_vec(),
+        burn_chain_type.to_be_bytes().to_vec(),
+        burn_chain_id.to_be_bytes().to_vec(),
+        u256_to_bytes(&burn_proof_hash),
+        hash_algorithm.to_be_bytes().to_vec(),
+        u256_to_bytes(&burn_nonce),
+        recipient.to_vec(),
+        executor.as_bytes().to_vec(),
+        block_time.to_be_bytes().to_vec(),
+      ]
+    },
+    BridgeEvent::ApprovedBurnProof(ApprovedBurnProof { burn_proof_hash }) => {
+      vec![
+        APPROVED_BURN_PROOF_SIG.to_vec(),
+        u256_to_bytes(&burn_proof_hash)
+      ]
+    },
+    BridgeEvent::CancelledBurnProof(CancelledBurnProof { burn_proof_hash }) => {
+      vec![
+        CANCELLED_BURN_PROOF_SIG.to_vec(),
+        u256_to_bytes(&burn_proof_hash)
+      ]
+    },
+    BridgeEvent::RefundedBurnProof(RefundedBurnProof { burn_token, burn_caller, burn_amount, burn_proof_hash }) => {
+      vec![
+        REFUNDED_BURN_PROOF_SIG.to_vec(),
+        burn_token.to_vec(),
+        burn_caller.to_vec(),
+        u256_to_bytes(&burn_amount),
+        u256_to_bytes(&burn_proof_hash)
+      ]
+    },
+    BridgeEvent::ApprovedMerkleRoot(ApprovedMerkleRoot { epoch, root }) => {
+      vec![
+        APPROVED_MERKLE_ROOT_SIG.to_vec(),
+        epoch.to_be_bytes().to_vec(),
+        root.to_vec()
+      ]
+    },
+    BridgeEvent::AttestedBurnCancellation(AttestedBurnCancellation { burn_proof_hash }) => {
+      vec![
+        ATTESTED_BURN_CANCELLATION_SIG.to_vec(),
+        u256_to_bytes(&burn_proof_hash)
+      ]
+    },
+    BridgeEvent::AllowanceSet(AllowanceSet { mint_token, burn_token, mint_chain_type, mint_chain_id, burn_chain_type, burn_chain_id, allowance_hash, default_ttl }) => {
+      vec![
+        ALLOWANCE_SET_SIG.to_vec(),
+        mint_token.to_vec(),
+        burn_token.to_vec(),
+        mint_chain_type.to_be_bytes().to_vec(),
+        mint_chain_id.to_be_bytes().to_vec(),
+        burn_chain_type.to_be_bytes().to_vec(),
+        burn_chain_id.to_be_bytes().to_vec(),
+        allowance_hash.to_vec(),
+        default_ttl.to_be_bytes().to_vec(),
+      ]
+    },
+    // role name is the variable length tail
+    BridgeEvent::RoleGranted(RoleGranted { role, account }) => {
+      vec![
+        ROLE_GRANTED_SIG.to_vec(),
+        account.as_bytes().to_vec(),
+        role.into_bytes()
+      ]
+    },
+    BridgeEvent::RoleRevoked(RoleRevoked { role, account }) => {
+      vec![
+        ROLE_REVOKED_SIG.to_vec(),
+        account.as_bytes().to_vec(),
+        role.into_bytes()
+      ]
+    },
+    BridgeEvent::ChainSet(ChainSet { chain_type, chain_id, address_length, remote_bridge, enabled, name }) => {
+      vec![
+        CHAIN_SET_SIG.to_vec(),
+        chain_type.to_be_bytes().to_vec(),
+        chain_id.to_be_bytes().to_vec(),
+        address_length.to_be_bytes().to_vec(),
+        remote_bridge.to_vec(),
+        vec![enabled as u8],
+        name.into_bytes()
+      ]
+    },
+    BridgeEvent::ChainEnabledSet(ChainEnabledSet { chain_type, chain_id, enabled }) => {
+      vec![
+        CHAIN_ENABLED_SET_SIG.to_vec(),
+        chain_type.to_be_bytes().to_vec(),
+        chain_id.to_be_bytes().to_vec(),
+        vec![enabled as u8]
+      ]
+    },
+    BridgeEvent::RefundTimeoutSet(RefundTimeoutSet { refund_timeout }) => {
+      vec![
+        REFUND_TIMEOUT_SET_SIG.to_vec(),
+        refund_timeout.to_be_bytes().to_vec()
+      ]
+    },
+  };
+
+  record_wire_event(merge_bytes(vec_event));
+}
+
+fn require<T: Into<ApiError>>(is_true: bool, error: T) {
+  if !is_true {
+    revert(error);
+  }
+}
+
+// TODO: change on main net to chain id = 1, or any other
+const SOURCE_CHAIN_ID: u32 = 1010; // 1010 as test chain id for casper
+const SOURCE_CHAIN_TYPE: ChainType = ChainType::Casper;
+
+// TODO: can't bridge to itself
+// TODO: do not allow to approve hash on chain it was burned (add fee)
+#[no_mangle]
+pub fn approve_burn_proof() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  // 
+
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  let some_burn_proof = get_burn_proof_state(proof_hash);
+
+  require(some_burn_proof == States::Undefined, Error::AlreadyApproved);
+
+  approve(proof_hash);
+}
+
+// relayer bursts: one deploy for many proofs
+#[no_mangle]
+pub fn approve_burn_proofs() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let proof_hashes = runtime::get_named_arg::<Vec<U256>>("proof_hashes");
+
+  // skip already approved (or otherwise known) proofs instead of reverting the batch
+  let skip_approved = runtime::get_named_arg::<bool>("skip_approved");
+
+  for proof_hash in proof_hashes {
+    if get_burn_proof_state(proof_hash) != States::Undefined {
+      require(skip_approved, Error::AlreadyApproved);
+      continue;
+    }
+
+    approve(proof_hash);
+  }
+}
+
+// approves every proof hash under the root at once, epochs are sequential
+#[no_mangle]
+pub fn approve_merkle_root() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let root = runtime::get_named_arg::<Bytes>("root");
+
+  require(root.len() == 32, Error::InvalidMerkleRoot);
+
+  let epoch: u64 = get_key(MERKLE_EPOCH).unwrap_or_revert();
+
+  set_merkle_root_by_epoch(epoch, root.clone());
+  set_key(MERKLE_EPOCH, epoch + 1);
+
+  emit(BridgeEvent::ApprovedMerkleRoot(ApprovedMerkleRoot { epoch, root }))
+}
+
+fn approve(proof_hash: U256) {
+  set_burn_proof_state(proof_hash, States::Approved);
+
+  let mut record = get_proof_record(proof_hash);
+  record.approved_at = Some(get_blocktime());
+  record.approver = Some(runtime::get_caller());
+
+  set_proof_record(proof_hash, record);
+
+  emit(BridgeEvent::ApprovedBurnProof(ApprovedBurnProof {
+    burn_proof_hash: proof_hash,
+  }))
+}
+
+// account, contract or package which called the bridge directly
+fn get_generic_caller() -> Vec<u8> {
+  let caller = get_immediate_caller();
+
+  // 8 + 32, tagged
+  caller.to_generic().encode()
+}
+
+// this bridge deployment
+fn get_generic_bridge() -> Vec<u8> {
+  // 8 + 32, tagged as package
+  CasperAddress::Package(get_self_package()).to_generic().encode()
+}
+
+fn get_generic_token(token: ContractHash) -> Vec<u8> {
+  // 8 + 32, tagged as contract
+  CasperAddress::Contract(token).to_generic().encode()
+}
+
+// generic address from arguments must be canonical for the chain it lives on
+fn require_address(chain_type: u8, chain_id: u32, address: &Bytes, length_error: Error) {
+  require(address.len() == GENERIC_ADDRESS_LENGTH, length_error);
+
+  if is_source_chain(chain_type, chain_id) {
+    GenericAddress::decode(SOURCE_CHAIN_TYPE, address).unwrap_or_revert();
+  } else {
+    let chain = get_enabled_chain(chain_type, chain_id);
+
+    GenericAddress::validate(chain_type, chain.address_length, address).unwrap_or_revert();
+  }
+}
+
+fn is_source_chain(chain_type: u8, chain_id: u32) -> bool {
+  chain_type == SOURCE_CHAIN_TYPE as u8 && chain_id == SOURCE_CHAIN_ID
+}
+
+fn get_allowance_hash(
+  mint_chain_type: u8,
+  mint_chain_id: u32,
+  burn_chain_type: u8,
+  burn_chain_id: u32,
+  mint_token: Vec<u8>,
+  burn_token: Vec<u8>,
+) -> Vec<u8> {
+  bridge_core::get_allowance_hash(
+    get_hash_algorithm(),
+    mint_chain_type,
+    mint_chain_id,
+    &mint_token,
+    burn_chain_type,
+    burn_chain_id,
+    &burn_token,
+  )
+}
+
+fn get_burn_proof_hash(proof: &BurnProof) -> Vec<u8> {
+  proof.hash(get_hash_algorithm()).unwrap_or_revert()
+}
+
+// TODO: add fee
+// TODO: add bot detection
+#[no_mangle]
+pub fn mint_with_burn_proof() {
+  let (mint_token, params, burn_proof_hash) = get_mint_proof();
+
+  require(
+    get_burn_proof_state(burn_proof_hash) == States::Approved,
+    Error::NotApprovedOrExecuted,
+  );
+
+  execute_mint(mint_token, params, burn_proof_hash);
+}
+
+// mints several approved proofs at once, one token call per token,
+// any invalid proof reverts the whole batch
+#[no_mangle]
+pub fn mint_with_burn_proofs() {
+  let requests = runtime::get_named_arg::<Vec<MintRequest>>("requests");
+
+  let mut amounts: BTreeMap<ContractHash, U256> = BTreeMap::new();
+
+  for request in requests {
+    let (mint_token, params, burn_proof_hash) = verify_mint_request(request);
+
+    // executed proofs fail here, so duplicates in the batch revert too
+    require(
+      get_burn_proof_state(burn_proof_hash) == States::Approved,
+      Error::NotApprovedOrExecuted,
+    );
+
+    let amount = amounts.entry(mint_token).or_insert_with(U256::zero);
+    *amount = amount.checked_add(params.burn_amount).unwrap_or_revert();
+
+    record_mint(params, burn_proof_hash);
+  }
+
+  // recipient is the immediate caller for every proof of the batch
+  let recipient = get_immediate_caller();
+
+  for (mint_token, amount) in amounts {
+    SuperToken::new(mint_token).mint(recipient.into(), amount);
+  }
+}
+
+// proof is approved as a leaf of a merkle root, instead of one by one
+#[no_mangle]
+pub fn mint_with_merkle_proof() {
+  let (mint_token, params, burn_proof_hash) = get_mint_proof();
+
+  let epoch = runtime::get_named_arg::<u64>("epoch");
+  let merkle_path = runtime::get_named_arg::<Vec<Bytes>>("merkle_path");
+
+  let root = get_merkle_root_by_epoch(epoch).unwrap_or_revert_with(Error::MerkleRootNotFound);
+
+  // double spend guard, leaf is marked as executed same as single approvals
+  let state = get_burn_proof_state(burn_proof_hash);
+
+  require(
+    state == States::Undefined || state == States::Approved,
+    Error::NotApprovedOrExecuted,
+  );
+
+  let merkle_path: Vec<Vec<u8>> = merkle_path.into_iter().map(Vec::from).collect();
+
+  require(
+    verify_merkle_path(&root, &u256_to_bytes(&burn_proof_hash), &merkle_path),
+    Error::InvalidMerkleProof,
+  );
+
+  execute_mint(mint_token, params, burn_proof_hash);
+}
+
+// reads mint arguments and checks them against the provided proof hash
+fn get_mint_proof() -> (ContractHash, BurnProof, U256) {
+  let request = MintRequest {
+    mint_token: runtime::get_named_arg::<ContractHash>("mint_token"), // use native address type explicitly
+    burn_token: runtime::get_named_arg::<Bytes>("burn_token"),
+    burn_caller: runtime::get_named_arg::<Bytes>("burn_caller"),
+    burn_chain_type: runtime::get_named_arg::<u8>("burn_chain_type"),
+    burn_chain_id: runtime::get_named_arg::<u32>("burn_chain_id"),
+    burn_amount: runtime::get_named_arg::<U256>("burn_amount"),
+    burn_proof_hash: runtime::get_named_arg::<U256>("burn_proof_hash"),
+    burn_nonce: runtime::get_named_arg::<Nonce>("burn_nonce"),
+    burn_deadline: runtime::get_named_arg::<u64>("burn_deadline"),
+  };
+
+  verify_mint_request(request)
+}
+
+fn verify_mint_request(request: MintRequest) -> (ContractHash, BurnProof, U256) {
+  let MintRequest {
+    mint_token,
+    burn_token,
+    burn_caller,
+    burn_chain_type,
+    burn_chain_id,
+    burn_amount,
+    burn_proof_hash,
+    burn_nonce,
+    burn_deadline,
+  } = request;
+
+  require_address(burn_chain_type, burn_chain_id, &burn_caller, Error::InvalidCallerLength);
+  require_address(burn_chain_type, burn_chain_id, &burn_token, Error::InvalidTokenLength);
+
+  let allowance_hash = get_allowance_hash(
+    SOURCE_CHAIN_TYPE as u8,
+    SOURCE_CHAIN_ID,
+    burn_chain_type,
+    burn_chain_id,
+    get_generic_token(mint_token),
+    burn_token.clone().into()
+  );
+
+  require(
+    get_allowance_by_hash(allowance_hash) == Allowance::Allowed,
+     Error::AllowanceNotFound
+  );
+
+  require(!is_expired(burn_deadline), Error::ProofExpired);
+
+  let remote_bridge = get_enabled_chain(burn_chain_type, burn_chain_id).remote_bridge;
+
+  let params = BurnProof {
+    proof_version: PROOF_VERSION,
+    mint_caller: get_generic_caller().into(),
+    burn_caller,
+    mint_token: get_generic_token(mint_token).into(),
+    burn_token,
+    burn_amount,
+    mint_chain_type: SOURCE_CHAIN_TYPE as u8,
+    mint_chain_id: SOURCE_CHAIN_ID,
+    burn_chain_type,
+    burn_chain_id,
+    burn_nonce,
+    burn_deadline,
+    mint_bridge: get_generic_bridge().into(),
+    burn_bridge: remote_bridge,
+  };
+
+  let computed_burn_proof_hash = get_burn_proof_hash(&params);
+
+  let are_hashes_equal = computed_burn_proof_hash == u256_to_bytes(&burn_proof_hash);
+
+  require(are_hashes_equal, Error::ProvidedHashIsInvalid);
+
+  (mint_token, params, burn_proof_hash)
+}
+
+fn execute_mint(mint_token: ContractHash, params: BurnProof, burn_proof_hash: U256) {
+  let burn_amount = params.burn_amount;
+
+  record_mint(params, burn_proof_hash);
+
+  let token = SuperToken::new(mint_token);
+  let recipient = get_immediate_caller();
+
+  token.mint(recipient.into(), burn_amount);
+}
+
+// marks the proof as executed, token transfer is up to the caller
+fn record_mint(params: BurnProof, burn_proof_hash: U256) {
+  set_burn_proof_state(burn_proof_hash, States::Executed);
+
+  let mut record = get_proof_record(burn_proof_hash);
+  record.params = Some(params.clone());
+  record.executed_at = Some(get_blocktime());
+
+  set_proof_record(burn_proof_hash, record);
+
+  emit(BridgeEvent::ProofOfMint(ProofOfMint {
+    mint_token: params.mint_token,
+    burn_token: params.burn_token,
+    mint_caller: params.mint_caller,
+    burn_caller: params.burn_caller,
+    burn_amount: params.burn_amount,
+    mint_chain_type: params.mint_chain_type,
+    mint_chain_id: params.mint_chain_id,
+    burn_chain_type: params.burn_chain_type,
+    burn_chain_id: params.burn_chain_id,
+    burn_proof_hash,
+    hash_algorithm: get_hash_algorithm() as u8,
+    burn_nonce: params.burn_nonce,
+    recipient: get_generic_caller().into(),
+    executor: runtime::get_caller(),
+    block_time: get_blocktime(),
+  }));
+}
+
+#[no_mangle]
+pub fn set_allowance() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  // 
+
+  let mint_token = runtime::get_named_arg::<Bytes>("mint_token");
+  let burn_token = runtime::get_named_arg::<Bytes>("burn_token");
+
+  let mint_chain_type = runtime::get_named_arg::<u8>("mint_chain_type");
+  let mint_chain_id = runtime::get_named_arg::<u32>("mint_chain_id");
+
+  let burn_chain_type = runtime::get_named_arg::<u8>("burn_chain_type");
+  let burn_chain_id = runtime::get_named_arg::<u32>("burn_chain_id");
+
+  // optional, proof lifetime in ms applied when burner gives no deadline
+  let default_ttl = get_optional_named_arg::<u64>("default_ttl");
+
+  require_address(mint_chain_type, mint_chain_id, &mint_token, Error::InvalidTokenLength);
+  require_address(burn_chain_type, burn_chain_id, &burn_token, Error::InvalidTokenLength);
+
+  let allowance_hash = get_allowance_hash(
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type,
+    burn_chain_id, 
+    mint_token.to_vec(),
+    burn_token.to_vec(),
+  );
+
+  if let Some(ttl) = default_ttl {
+    set_route_ttl_by_hash(allowance_hash.clone(), ttl);
+  }
+
+  set_allowance_by_hash(allowance_hash.clone(), Allowance::Allowed);
+
+  emit(BridgeEvent::AllowanceSet(AllowanceSet {
+    mint_token,
+    burn_token,
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type,
+    burn_chain_id,
+    default_ttl: get_route_ttl_by_hash(allowance_hash.clone()),
+    allowance_hash: allowance_hash.into(),
+  }));
+}
+
+#[no_mangle]
+pub fn burn_and_create_proof() {
+  let burn_token = runtime::get_named_arg::<ContractHash>("burn_token"); // use native address type explicitly
+
+  let mint_token = runtime::get_named_arg::<Bytes>("mint_token");
+  let mint_caller = runtime::get_named_arg::<Bytes>("mint_caller");
+
+  let mint_chain_type = runtime::get_named_arg::<u8>("mint_chain_type");
+  let mint_chain_id = runtime::get_named_arg::<u32>("mint_chain_id");
+
+  let burn_amount = runtime::get_named_arg::<U256>("burn_amount");
+
+  // optional, absolute block time in ms
+  let deadline = get_optional_named_arg::<u64>("deadline");
+
+  require_address(mint_chain_type, mint_chain_id, &mint_caller, Error::InvalidCallerLength);
+  require_address(mint_chain_type, mint_chain_id, &mint_token, Error::InvalidTokenLength);
+
+  let allowance_hash = get_allowance_hash(
+    mint_chain_type,
+    mint_chain_id,
+    SOURCE_CHAIN_TYPE as u8,
+    SOURCE_CHAIN_ID,
+    mint_token.clone().into(),
+    get_generic_token(burn_token),
+  );
+
+  let burn_deadline = match deadline {
+    Some(deadline) => {
+      require(deadline > get_blocktime(), Error::InvalidDeadline);
+      deadline
+    }
+    None => match get_route_ttl_by_hash(allowance_hash.clone()) {
+      0 => 0,
+      ttl => get_blocktime().saturating_add(ttl),
+    },
+  };
+
+  require(
+    get_allowance_by_hash(allowance_hash) == Allowance::Allowed,
+     Error::AllowanceNotFound
+  );
+
+  let remote_bridge = get_enabled_chain(mint_chain_type, mint_chain_id).remote_bridge;
+
+  let burn_nonce = get_nonce(&get_generic_caller());
+  let caller: Key = get_immediate_caller().into();
+
+  let token = SuperToken::new(burn_token);
+
+  let balance = token.balance_of(caller);
+
+  print(&format!("balance {}", balance));
+
+  require(burn_amount <= balance, Error::AmountExceeded);
+
+  let params = BurnProof {
+    proof_version: PROOF_VERSION,
+    mint_caller: mint_caller.clone(),
+    burn_caller: get_generic_caller().into(),
+    mint_token: mint_token.clone(),
+    burn_token: get_generic_token(burn_token).into(),
+    burn_amount,
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type: SOURCE_CHAIN_TYPE as u8,
+    burn_chain_id: SOURCE_CHAIN_ID,
+    burn_nonce,
+    burn_deadline,
+    mint_bridge: remote_bridge,
+    burn_bridge: get_generic_bridge().into(),
+  };
+
+  let computed_burn_proof_hash = get_burn_proof_hash(&params);
+
+  print(&format!("burn_proof_hash {}", computed_burn_proof_hash.to_hex()));
+
+  let burn_proof_hash = U256::from_big_endian(&computed_burn_proof_hash);
+
+  set_burn_proof_state(burn_proof_hash, States::Burned);
+
+  set_proof_record(burn_proof_hash, ProofRecord {
+    params: Some(params),
+    burned_at: Some(get_blocktime()),
+    ..ProofRecord::default()
+  });
+
+  token.burn(caller, burn_amount);
+
+  emit(BridgeEvent::ProofOfBurn(ProofOfBurn {
+    mint_token,
+    burn_token: get_generic_token(burn_token).into(),
+    mint_caller,
+    burn_caller: get_generic_caller().into(),
+    burn_amount,
+    burn_nonce,
+    burn_deadline,
+    mint_chain_type,
+    mint_chain_id,
+    burn_chain_type: SOURCE_CHAIN_TYPE as u8,
+    burn_chain_id: SOURCE_CHAIN_ID,
+    burn_proof_hash,
+    hash_algorithm: get_hash_algorithm() as u8,
+  }));
+
+  let next_nonce = burn_nonce + 1;
+  set_nonce(&get_generic_caller(), next_nonce);
+
+  // contracts get the hash directly, accounts via the burn session
+  runtime::ret(CLValue::from_t(burn_proof_hash).unwrap_or_revert())
+}
+
+// destination side: approver marks a proof that must never be minted here,
+// relayer then attests the cancellation on the burn side
+#[no_mangle]
+pub fn cancel_burn_proof() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  let state = get_burn_proof_state(proof_hash);
+
+  require(
+    state == States::Undefined || state == States::Approved,
+    Error::NotCancellable,
+  );
+
+  set_burn_proof_state(proof_hash, States::Cancelled);
+
+  let mut record = get_proof_record(proof_hash);
+  record.cancelled_at = Some(get_blocktime());
+
+  set_proof_record(proof_hash, record);
+
+  emit(BridgeEvent::CancelledBurnProof(CancelledBurnProof {
+    burn_proof_hash: proof_hash,
+  }))
+}
+
+// burn side: destination cancelled the proof, so it can be refunded
+#[no_mangle]
+pub fn attest_burn_cancellation() {
+  // guards
+  only_role(ROLE_APPROVER, Error::MissingApproverRole);
+  //
+
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  require(
+    get_burn_proof_state(proof_hash) == States::Burned,
+    Error::ProofRecordNotFound,
+  );
+
+  let mut record = find_proof_record(proof_hash).unwrap_or_revert_with(Error::ProofRecordNotFound);
+  record.cancel_attested = true;
+
+  set_proof_record(proof_hash, record);
+
+  emit(BridgeEvent::AttestedBurnCancellation(AttestedBurnCancellation {
+    burn_proof_hash: proof_hash,
+  }))
+}
+
+#[no_mangle]
+pub fn refund_burn_proof() {
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  require(
+    get_burn_proof_state(proof_hash) == States::Burned,
+    Error::ProofRecordNotFound,
+  );
+
+  let mut record = find_proof_record(proof_hash).unwrap_or_revert_with(Error::ProofRecordNotFound);
+  let params = record.params.clone().unwrap_or_revert_with(Error::ProofRecordNotFound);
+  let burned_at = record.burned_at.unwrap_or_revert_with(Error::ProofRecordNotFound);
+
+  let refund_timeout: u64 = get_key(REFUND_TIMEOUT).unwrap_or_revert();
+
+  require(
+    get_blocktime() >= burned_at.saturating_add(refund_timeout),
+    Error::RefundLocked,
+  );
+
+  // either destination side cancelled it, it can't be minted anymore
+  // or admin decided to refund
+  require(
+    record.cancel_attested || is_expired(params.burn_deadline) || has_role(ROLE_ADMIN),
+    Error::RefundNotAllowed,
+  );
+
+  set_burn_proof_state(proof_hash, States::Refunded);
+
+  record.refunded_at = Some(get_blocktime());
+
+  set_proof_record(proof_hash, record);
+
+  let burn_token = match CasperAddress::from_generic(&params.burn_token).unwrap_or_revert() {
+    CasperAddress::Contract(hash) => hash,
+    _ => revert(Error::InvalidAddressTag),
+  };
+  let burner = CasperAddress::from_generic(&params.burn_caller).unwrap_or_revert();
+
+  let token = SuperToken::new(burn_token);
+
+  token.mint(burner.into(), params.burn_amount);
+
+  emit(BridgeEvent::RefundedBurnProof(RefundedBurnProof {
+    burn_token: params.burn_token,
+    burn_caller: params.burn_caller,
+    burn_amount: params.burn_amount,
+    burn_proof_hash: proof_hash,
+  }));
+}
+
+// view
+#[no_mangle]
+pub fn get_burn_proof_record() {
+  let proof_hash = runtime::get_named_arg::<U256>("proof_hash");
+
+  let record = find_proof_record(proof_hash);
+
+  runtime::ret(CLValue::from_t(record).unwrap_or_revert())
+}
+
+// view, lets clients precompute the proof hash before burning
+#[no_mangle]
+pub fn get_next_nonce() {
+  let burn_caller = runtime::get_named_arg::<Bytes>("burn_caller");
+
+  let nonce = get_nonce(&burn_caller);
+
+  runtime::ret(CLValue::from_t(nonce).unwrap_or_revert())
+}
+
+#[no_mangle]
+pub fn set_refund_timeout() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let refund_timeout = runtime::get_named_arg::<u64>("refund_timeout");
+
+  set_key(REFUND_TIMEOUT, refund_timeout);
+
+  emit(BridgeEvent::RefundTimeoutSet(RefundTimeoutSet { refund_timeout }));
+}
+
+// roles are held by a single account, granting one revokes it from the previous holder
+#[no_mangle]
+pub fn set_role() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let role = runtime::get_named_arg::<String>("role");
+  let account = runtime::get_named_arg::<AccountHash>("account");
+
+  require(role == ROLE_ADMIN || role == ROLE_APPROVER, Error::UnknownRole);
+
+  let previous: AccountHash = get_key(&role).unwrap_or_revert();
+
+  set_key(&role, account);
+
+  emit(BridgeEvent::RoleRevoked(RoleRevoked {
+    role: role.clone(),
+    account: previous,
+  }));
+
+  emit(BridgeEvent::RoleGranted(RoleGranted { role, account }));
+}
+
+
+// registers or replaces a remote chain, new chain types need no upgrade
+#[no_mangle]
+pub fn set_chain() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let chain_type = runtime::get_named_arg::<u8>("chain_type");
+  let chain_id = runtime::get_named_arg::<u32>("chain_id");
+  let address_length = runtime::get_named_arg::<u8>("address_length");
+  let name = runtime::get_named_arg::<String>("name");
+  let remote_bridge = runtime::get_named_arg::<Bytes>("remote_bridge");
+
+  // optional, enabled by default
+  let enabled = get_optional_named_arg::<bool>("enabled").unwrap_or(true);
+
+  require(chain_type != ChainType::Undefined as u8, Error::UnknownChain);
+  require(!is_source_chain(chain_type, chain_id), Error::UnknownChain);
+
+  require(
+    address_length > 0 && address_length as usize <= GENERIC_ADDRESS_LENGTH,
+    Error::InvalidAddressLength,
+  );
+
+  // built-in chain types keep their own address length
+  if let Ok(known) = ChainType::try_from(chain_type) {
+    require(known.address_length() == Some(address_length), Error::InvalidAddressLength);
+  }
+
+  require(remote_bridge.len() == GENERIC_ADDRESS_LENGTH, Error::InvalidBridgeLength);
+  GenericAddress::validate(chain_type, address_length, &remote_bridge).unwrap_or_revert();
+
+  set_chain_info(chain_type, chain_id, ChainInfo {
+    enabled,
+    address_length,
+    name: name.clone(),
+    remote_bridge: remote_bridge.clone(),
+  });
+
+  emit(BridgeEvent::ChainSet(ChainSet {
+    chain_type,
+    chain_id,
+    address_length,
+    remote_bridge,
+    enabled,
+    name,
+  }));
+}
+
+// disabled chain keeps its routes, but nothing can be burned to or minted from it
+#[no_mangle]
+pub fn set_chain_enabled() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  let chain_type = runtime::get_named_arg::<u8>("chain_type");
+  let chain_id = runtime::get_named_arg::<u32>("chain_id");
+  let enabled = runtime::get_named_arg::<bool>("enabled");
+
+  let mut chain = find_chain(chain_type, chain_id).unwrap_or_revert_with(Error::UnknownChain);
+  chain.enabled = enabled;
+
+  set_chain_info(chain_type, chain_id, chain);
+
+  emit(BridgeEvent::ChainEnabledSet(ChainEnabledSet {
+    chain_type,
+    chain_id,
+    enabled,
+  }));
+}
+
+// view
+#[no_mangle]
+pub fn get_chain() {
+  let chain_type = runtime::get_named_arg::<u8>("chain_type");
+  let chain_id = runtime::get_named_arg::<u32>("chain_id");
+
+  let chain = find_chain(chain_type, chain_id);
+
+  runtime::ret(CLValue::from_t(chain).unwrap_or_revert())
+}
+
+// run by the upgrade session right after add_contract_version, the named keys
+// of the previous version are already carried over
+#[no_mangle]
+pub fn migrate() {
+  // guards
+  only_role(ROLE_ADMIN, Error::MissingAdminRole);
+  //
+
+  // versions installed before schema versioning have no key
+  let mut version = get_key::<u32>(SCHEMA_VERSION).unwrap_or(0);
+
+  require(version <= CURRENT_SCHEMA_VERSION, Error::UnknownSchemaVersion);
+
+  while version < CURRENT_SCHEMA_VERSION {
+    migrate_from(version);
+    version += 1;
+  }
+
+  set_key(SCHEMA_VERSION, version);
+}
+
+// one arm per schema version, bump CURRENT_SCHEMA_VERSION with each new arm
+fn migrate_from(version: u32) {
+  match version {
+    // unversioned installs, create whatever keys the old layout lacks
+    0 => {
+      for name in [
+        NONCES_DICT,
+        ROUTE_TTLS_DICT,
+        CHAINS_DICT,
+        BURN_PROOF_STORAGE_DICT,
+        ALLOWANCES_DICT,
+        PROOF_RECORDS_DICT,
+        MERKLE_ROOTS_DICT,
+      ]
+      .iter()
+      {
+        ensure_dict(name);
+      }
+
+      ensure_key(MERKLE_EPOCH, 0u64);
+      ensure_key(REFUND_TIMEOUT, DEFAULT_REFUND_TIMEOUT);
+      ensure_key(HASH_ALGORITHM, HashAlgorithm::Sha256 as u8);
+
+      migrate_events();
+    }
+    _ => revert(Error::UnknownSchemaVersion),
+  }
+}
+
+#[no_mangle]
+pub extern "C" fn call() {
+  // the installer keeps the package hash, running the wasm again upgrades
+  match runtime::get_key(BRIDGE_PACKAGE_HASH) {
+    Some(package_key) => upgrade(package_key),
+    None => install(),
+  }
+}
+
+fn upgrade(package_key: Key) {
+  let package_hash = ContractPackageHash::new(package_key.into_hash().unwrap_or_revert());
+
+  let previous_contract_hash = runtime::get_key(BRIDGE_CONTRACT_HASH)
+    .and_then(Key::into_hash)
+    .map(ContractHash::new)
+    .unwrap_or_revert();
+
+  // optional, the previous version stays callable by default
+  let disable_previous = get_optional_named_arg::<bool>("disable_previous").unwrap_or(false);
+
+  // named keys of the previous version are carried over, only new entry points here
+  let (contract_hash, _) =
+    storage::add_contract_version(package_hash, get_entry_points(), NamedKeys::new());
+
+  runtime::call_contract::<()>(contract_hash, "migrate", RuntimeArgs::new());
+
+  if disable_previous {
+    storage::disable_contract_version(package_hash, previous_contract_hash).unwrap_or_revert();
+  }
+
+  runtime::put_key(BRIDGE_CONTRACT_HASH, contract_hash.into());
+}
+
+fn install() {
+  let mut named_keys = NamedKeys::new();
+
+  named_keys.insert(NONCES_DICT.to_string(), empty_dict(NONCES_DICT).into());
+
+  named_keys.insert(
+    ROUTE_TTLS_DICT.to_string(),
+    empty_dict(ROUTE_TTLS_DICT).into(),
+  );
+
+  named_keys.insert(
+    CHAINS_DICT.to_string(),
+    empty_dict(CHAINS_DICT).into(),
+  );
+
+  named_keys.insert(
+    BURN_PROOF_STORAGE_DICT.to_string(),
+    empty_dict(BURN_PROOF_STORAGE_DICT).into(),
+  );
+
+  named_keys.insert(
+    ALLOWANCES_DICT.to_string(),
+  empty_dict(ALLOWANCES_DICT).into(),
+  );
+
+  named_keys.insert(
+    PROOF_RECORDS_DICT.to_string(),
+    empty_dict(PROOF_RECORDS_DICT).into(),
+  );
+
+  named_keys.insert(
+    ROLE_ADMIN.to_string(),
+    storage::new_uref(runtime::get_caller()).into()
+  );
+
+  named_keys.insert(
+    MERKLE_ROOTS_DICT.to_string(),
+    empty_dict(MERKLE_ROOTS_DICT).into(),
+  );
+
+  named_keys.insert(
+    MERKLE_EPOCH.to_string(),
+    storage::new_uref(0u64).into()
+  );
+
+  init_events(&mut named_keys);
+
+  named_keys.insert(
+    REFUND_TIMEOUT.to_string(),
+    storage::new_uref(DEFAULT_REFUND_TIMEOUT).into()
+  );
+
+  // optional, sha256 by default
+  let hash_algorithm = get_optional_named_arg::<u8>(HASH_ALGORITHM)
+    .map(|v| HashAlgorithm::try_from(v).unwrap_or_revert())
+    .unwrap_or(HashAlgorithm::Sha256);
+
+  named_keys.insert(
+    HASH_ALGORITHM.to_string(),
+    storage::new_uref(hash_algorithm as u8).into()
+  );
+
+  named_keys.insert(
+    ROLE_APPROVER.to_string(), 
+  storage::new_uref(runtime::get_caller()).into()
+  );
+
+  named_keys.insert(
+    SCHEMA_VERSION.to_string(),
+    storage::new_uref(CURRENT_SCHEMA_VERSION).into()
+  );
+
+  let (contract_hash, _) = storage::new_contract(
+    get_entry_points(),
+    Some(named_keys),
+    Some(BRIDGE_PACKAGE_HASH.to_string()),
+    Some("bridge_access_token".to_string()),
+  );
+
+  // TODO: should expose on prod?
+  runtime::put_key(BRIDGE_CONTRACT_HASH, contract_hash.into());
+}
+
+fn get_entry_points() -> EntryPoints {
+  let mut entry_points = EntryPoints::new();
+  entry_points.add_entry_point(endpoint(
+    "set_allowance",
+    vec![
+      Parameter::new("mint_token", Bytes::cl_type()),
+      Parameter::new("burn_token", Bytes::cl_type()),
+      Parameter::new("mint_chain_type", u8::cl_type()),
+      Parameter::new("mint_chain_id", u32::cl_type()),
+      Parameter::new("burn_chain_type", u8::cl_type()),
+      Parameter::new("burn_chain_id", u32::cl_type()),
+      Parameter::new("default_ttl", u64::cl_type()), // optional
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "burn_and_create_proof",
+    vec![
+      Parameter::new("burn_token", ContractHash::cl_type()),
+      Parameter::new("mint_token", Bytes::cl_type()),
+      Parameter::new("mint_caller", Bytes::cl_type()),
+      Parameter::new("mint_chain_type", u8::cl_type()),
+      Parameter::new("mint_chain_id", u32::cl_type()),
+      Parameter::new("burn_amount", U256::cl_type()),
+      Parameter::new("deadline", u64::cl_type()), // optional
+    ],
+    U256::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "mint_with_burn_proof",
+    vec![
+      Parameter::new("mint_token", ContractHash::cl_type()),
+      Parameter::new("burn_token", Bytes::cl_type()),
+      Parameter::new("burn_caller", Bytes::cl_type()),
+      Parameter::new("burn_chain_type", u8::cl_type()),
+      Parameter::new("burn_chain_id", u32::cl_type()),
+      Parameter::new("burn_amount", U256::cl_type()),
+      Parameter::new("burn_proof_hash", U256::cl_type()),
+      Parameter::new("burn_nonce", Nonce::cl_type()),
+      Parameter::new("burn_deadline", u64::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "mint_with_burn_proofs",
+    vec![Parameter::new("requests", Vec::<MintRequest>::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "approve_burn_proof",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "approve_merkle_root",
+    vec![Parameter::new("root", Bytes::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "mint_with_merkle_proof",
+    vec![
+      Parameter::new("mint_token", ContractHash::cl_type()),
+      Parameter::new("burn_token", Bytes::cl_type()),
+      Parameter::new("burn_caller", Bytes::cl_type()),
+      Parameter::new("burn_chain_type", u8::cl_type()),
+      Parameter::new("burn_chain_id", u32::cl_type()),
+      Parameter::new("burn_amount", U256::cl_type()),
+      Parameter::new("burn_proof_hash", U256::cl_type()),
+      Parameter::new("burn_nonce", Nonce::cl_type()),
+      Parameter::new("burn_deadline", u64::cl_type()),
+      Parameter::new("epoch", u64::cl_type()),
+      Parameter::new("merkle_path", Vec::<Bytes>::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "approve_burn_proofs",
+    vec![
+      Parameter::new("proof_hashes", Vec::<U256>::cl_type()),
+      Parameter::new("skip_approved", bool::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "cancel_burn_proof",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "attest_burn_cancellation",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "refund_burn_proof",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_burn_proof_record",
+    vec![Parameter::new("proof_hash", U256::cl_type())],
+    Option::<ProofRecord>::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_next_nonce",
+    vec![Parameter::new("burn_caller", Bytes::cl_type())],
+    Nonce::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "set_refund_timeout",
+    vec![Parameter::new("refund_timeout", u64::cl_type())],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "set_role",
+    vec![
+      Parameter::new("role", String::cl_type()),
+      Parameter::new("account", AccountHash::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "set_chain",
+    vec![
+      Parameter::new("chain_type", u8::cl_type()),
+      Parameter::new("chain_id", u32::cl_type()),
+      Parameter::new("address_length", u8::cl_type()),
+      Parameter::new("name", String::cl_type()),
+      Parameter::new("remote_bridge", Bytes::cl_type()),
+      Parameter::new("enabled", bool::cl_type()), // optional
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "set_chain_enabled",
+    vec![
+      Parameter::new("chain_type", u8::cl_type()),
+      Parameter::new("chain_id", u32::cl_type()),
+      Parameter::new("enabled", bool::cl_type()),
+    ],
+    CLType::Unit,
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint(
+    "get_chain",
+    vec![
+      Parameter::new("chain_type", u8::cl_type()),
+      Parameter::new("chain_id", u32::cl_type()),
+    ],
+    Option::<ChainInfo>::cl_type(),
+    None,
+  ));
+
+  entry_points.add_entry_point(endpoint("migrate", vec![], CLType::Unit, None));
+
+  entry_points
+}
@@ -0,0 +1,82 @@
+use alloc::vec::Vec;
+
+use bridge_core::BurnProof;
+use casper_types::{
+  account::AccountHash,
+  bytesrepr::{self, FromBytes, ToBytes},
+  CLType, CLTyped,
+};
+
+// on-chain history of a proof, next to its state in burn_proof_storage
+// params are known on burn (burn side) or on mint (mint side), not on approve
+#[derive(Default)]
+pub struct ProofRecord {
+  pub params: Option<BurnProof>,
+  pub burned_at: Option<u64>,
+  pub approved_at: Option<u64>,
+  pub approver: Option<AccountHash>,
+  pub executed_at: Option<u64>,
+  pub cancelled_at: Option<u64>,
+  pub refunded_at: Option<u64>,
+  pub cancel_attested: bool,
+}
+
+impl CLTyped for ProofRecord {
+  fn cl_type() -> CLType {
+    CLType::Any
+  }
+}
+
+impl ToBytes for ProofRecord {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.params.to_bytes()?);
+    result.extend(self.burned_at.to_bytes()?);
+    result.extend(self.approved_at.to_bytes()?);
+    result.extend(self.approver.to_bytes()?);
+    result.extend(self.executed_at.to_bytes()?);
+    result.extend(self.cancelled_at.to_bytes()?);
+    result.extend(self.refunded_at.to_bytes()?);
+    result.extend(self.cancel_attested.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.params.serialized_length()
+      + self.burned_at.serialized_length()
+      + self.approved_at.serialized_length()
+      + self.approver.serialized_length()
+      + self.executed_at.serialized_length()
+      + self.cancelled_at.serialized_length()
+      + self.refunded_at.serialized_length()
+      + self.cancel_attested.serialized_length()
+  }
+}
+
+impl FromBytes for ProofRecord {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (params, bytes) = Option::<BurnProof>::from_bytes(bytes)?;
+    let (burned_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (approved_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (approver, bytes) = Option::<AccountHash>::from_bytes(bytes)?;
+    let (executed_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (cancelled_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (refunded_at, bytes) = Option::<u64>::from_bytes(bytes)?;
+    let (cancel_attested, bytes) = bool::from_bytes(bytes)?;
+
+    let record = ProofRecord {
+      params,
+      burned_at,
+      approved_at,
+      approver,
+      executed_at,
+      cancelled_at,
+      refunded_at,
+      cancel_attested,
+    };
+
+    Ok((record, bytes))
+  }
+}
@@ -0,0 +1,162 @@
+use core::convert::TryInto;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use core::fmt::Write;
+use core::write;
+
+use casper_types::{
+  account::AccountHash,
+  bytesrepr::{FromBytes, ToBytes},
+  CLTyped, CLValue, URef, U256,
+};
+
+use casper_contract::{
+  contract_api::{
+    self, account, runtime,
+    storage::{self, new_dictionary},
+    system,
+  },
+  ext_ffi,
+  unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{api_error, bytesrepr, ApiError, Key};
+
+use bridge_core::{encode_hex, u256_to_bytes};
+
+pub struct Dict {
+  uref: URef,
+}
+
+impl Dict {
+  pub fn at(name: &str) -> Dict {
+    let key: Key = runtime::get_key(name).unwrap_or_revert();
+    let uref: URef = *key.as_uref().unwrap_or_revert();
+    Dict { uref }
+  }
+
+  pub fn get<T: CLTyped + FromBytes>(&self, key: &str) -> Option<T> {
+    storage::dictionary_get(self.uref, key)
+      .unwrap_or_revert()
+      .unwrap_or_default()
+  }
+
+  pub fn set<T: CLTyped + ToBytes>(&self, key: &str, value: T) {
+    storage::dictionary_put(self.uref, key, Some(value));
+  }
+
+  pub fn remove<T: CLTyped + ToBytes>(&self, key: &str) {
+    storage::dictionary_put(self.uref, key, Option::<T>::None);
+  }
+}
+
+pub fn get_key<T: FromBytes + CLTyped>(name: &str) -> Option<T> {
+  match runtime::get_key(name) {
+    None => None,
+    Some(value) => {
+      let key = value.try_into().unwrap_or_revert();
+      let value = storage::read(key).unwrap_or_revert().unwrap_or_revert();
+      Some(value)
+    }
+  }
+}
+
+pub fn set_key<T: ToBytes + CLTyped>(name: &str, value: T) {
+  match runtime::get_key(name) {
+    Some(key) => {
+      let key_ref = key.try_into().unwrap_or_revert();
+      storage::write(key_ref, value);
+    }
+    None => {
+      let key = storage::new_uref(value).into();
+      runtime::put_key(name, key);
+    }
+  }
+}
+
+fn get_named_arg_size(name: &str) -> Option<usize> {
+  let mut arg_size: usize = 0;
+  let ret = unsafe {
+    ext_ffi::casper_get_named_arg_size(
+      name.as_bytes().as_ptr(),
+      name.len(),
+      &mut arg_size as *mut usize,
+    )
+  };
+
+  match api_error::result_from(ret) {
+    Ok(_) => Some(arg_size),
+    Err(ApiError::MissingArgument) => None,
+    Err(e) => runtime::revert(e),
+  }
+}
+
+// same as runtime::get_named_arg, but missing argument is not an error
+pub fn get_optional_named_arg<T: FromBytes>(name: &str) -> Option<T> {
+  let arg_size = get_named_arg_size(name)?;
+
+  let arg_bytes = if arg_size > 0 {
+    let data_non_null_ptr = contract_api::alloc_bytes(arg_size);
+    let ret = unsafe {
+      ext_ffi::casper_get_named_arg(
+        name.as_bytes().as_ptr(),
+        name.len(),
+        data_non_null_ptr.as_ptr(),
+        arg_size,
+      )
+    };
+    let data = unsafe { Vec::from_raw_parts(data_non_null_ptr.as_ptr(), arg_size, arg_size) };
+
+    api_error::result_from(ret).unwrap_or_revert();
+    data
+  } else {
+    Vec::new()
+  };
+
+  let value = bytesrepr::deserialize(arg_bytes).unwrap_or_revert_with(ApiError::InvalidArgument);
+
+  Some(value)
+}
+
+pub fn empty_dict(name: &str) -> URef {
+  let dict = new_dictionary(name).unwrap_or_revert();
+
+  dict
+}
+
+// upgrades, created only if the installed version lacks the key
+
+pub fn ensure_dict(name: &str) {
+  if !runtime::has_key(name) {
+    empty_dict(name);
+  }
+}
+
+pub fn ensure_key<T: ToBytes + CLTyped>(name: &str, value: T) {
+  if !runtime::has_key(name) {
+    set_key(name, value);
+  }
+}
+
+// utils
+
+pub fn u256_to_hex(u: &U256) -> String {
+  let bytes = u256_to_bytes(u);
+  encode_hex(&bytes)
+}
+
+pub fn u8_to_hex(u: &u8) -> String {
+  let bytes = u.to_be_bytes().to_vec();
+  encode_hex(&bytes)
+}
+
+pub fn u32_to_hex(u: &u32) -> String {
+  let bytes = u.to_be_bytes().to_vec();
+  encode_hex(&bytes)
+}
+
+pub fn u64_to_hex(u: &u64) -> String {
+  let bytes = u.to_be_bytes().to_vec();
+  encode_hex(&bytes)
+}
@@ -0,0 +1,44 @@
+use casper_types::ApiError;
+
+// reverted as ApiError::User(code)
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u16)]
+pub enum Error {
+  AlreadyApproved = 0,
+  AmountExceeded,        // 1
+  NotApprovedOrExecuted, // 2
+  ProvidedHashIsInvalid, // 3
+  InvalidCallerLength,   // 4
+  InvalidTokenLength,    // 5
+  InvalidPackage,        // 6
+  UnknownState,          // 7
+  UnknownChain,          // 8
+  UnknownAllowance,      // 9
+  AllowanceNotFound,     // 10
+  MissingApproverRole,   // 11
+  InvalidAddressTag,     // 12
+  MissingAdminRole,      // 13
+  ProofRecordNotFound,   // 14
+  RefundLocked,          // 15
+  RefundNotAllowed,      // 16
+  NotCancellable,        // 17
+  InvalidDeadline,       // 18
+  ProofExpired,          // 19
+  RemoteBridgeNotFound,  // 20
+  InvalidBridgeLength,   // 21
+  UnknownHashAlgorithm,  // 22
+  InvalidAddressLength,  // 23
+  InvalidAddressPadding, // 24
+  ChainDisabled,         // 25
+  MerkleRootNotFound,    // 26
+  InvalidMerkleRoot,     // 27
+  InvalidMerkleProof,    // 28
+  UnknownRole,           // 29
+  UnknownSchemaVersion,  // 30
+}
+
+impl From<Error> for ApiError {
+  fn from(error: Error) -> Self {
+    ApiError::User(error as u16)
+  }
+}
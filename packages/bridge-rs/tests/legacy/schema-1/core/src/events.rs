@@ -0,0 +1,333 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use casper_types::{
+  account::AccountHash,
+  bytesrepr::{self, Bytes, FromBytes, ToBytes},
+  CLType, CLTyped, U256,
+};
+
+use crate::Nonce;
+
+// casper event standard (make-software/casper-event-standard), named keys of the contract
+pub const EVENTS_DICT: &str = "__events";
+pub const EVENTS_LENGTH: &str = "__events_length";
+pub const EVENTS_SCHEMA: &str = "__events_schema";
+pub const CES_VERSION_KEY: &str = "__events_ces_version";
+pub const CES_VERSION: &str = "0.1.0";
+
+// every event is serialized as "event_<Name>" followed by its fields
+pub const EVENT_PREFIX: &str = "event_";
+
+// packed wire format of the same events (4 byte signature | fields), keyed by
+// decimal index from 0 to count - 1, relayers resume from their last index
+pub const BRIDGE_EVENTS_DICT: &str = "bridge_events";
+pub const BRIDGE_EVENTS_COUNT: &str = "bridge_events_count";
+
+// field names and types of one event, in serialization order
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Schema(pub Vec<(String, CLType)>);
+
+impl CLTyped for Schema {
+  fn cl_type() -> CLType {
+    CLType::Any
+  }
+}
+
+impl ToBytes for Schema {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend((self.0.len() as u32).to_bytes()?);
+
+    for (name, cl_type) in self.0.iter() {
+      result.extend(name.to_bytes()?);
+      result.extend(cl_type.to_bytes()?);
+    }
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.0.iter().fold(
+      bytesrepr::U32_SERIALIZED_LENGTH,
+      |length, (name, cl_type)| length + name.serialized_length() + cl_type.serialized_length(),
+    )
+  }
+}
+
+impl FromBytes for Schema {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (fields, bytes) = Vec::<(String, CLType)>::from_bytes(bytes)?;
+
+    Ok((Schema(fields), bytes))
+  }
+}
+
+// event name -> schema, stored under `EVENTS_SCHEMA`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Schemas(pub BTreeMap<String, Schema>);
+
+impl CLTyped for Schemas {
+  fn cl_type() -> CLType {
+    CLType::Any
+  }
+}
+
+impl ToBytes for Schemas {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    self.0.to_bytes()
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.0.serialized_length()
+  }
+}
+
+impl FromBytes for Schemas {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (schemas, bytes) = BTreeMap::<String, Schema>::from_bytes(bytes)?;
+
+    Ok((Schemas(schemas), bytes))
+  }
+}
+
+// declares the event structs, their ces encoding and the `BridgeEvent` enum
+macro_rules! bridge_events {
+  ($($name:ident { $($field:ident: $ty:ty,)* })*) => {
+    $(
+      #[derive(Debug, PartialEq, Eq, Clone)]
+      pub struct $name {
+        $(pub $field: $ty,)*
+      }
+
+      impl $name {
+        pub const NAME: &'static str = stringify!($name);
+
+        pub fn schema() -> Schema {
+          Schema(vec![$((String::from(stringify!($field)), <$ty>::cl_type()),)*])
+        }
+      }
+
+      impl CLTyped for $name {
+        fn cl_type() -> CLType {
+          CLType::Any
+        }
+      }
+
+      impl ToBytes for $name {
+        fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+          let mut result = bytesrepr::allocate_buffer(self)?;
+
+          result.extend(concat!("event_", stringify!($name)).to_bytes()?);
+          $(result.extend(self.$field.to_bytes()?);)*
+
+          Ok(result)
+        }
+
+        fn serialized_length(&self) -> usize {
+          concat!("event_", stringify!($name)).serialized_length()
+            $(+ self.$field.serialized_length())*
+        }
+      }
+
+      impl FromBytes for $name {
+        fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+          let (name, bytes) = String::from_bytes(bytes)?;
+
+          if name != concat!("event_", stringify!($name)) {
+            return Err(bytesrepr::Error::Formatting);
+          }
+
+          $(let ($field, bytes) = <$ty>::from_bytes(bytes)?;)*
+
+          Ok(($name { $($field,)* }, bytes))
+        }
+      }
+    )*
+
+    #[derive(Debug, PartialEq, Eq, Clone)]
+    pub enum BridgeEvent {
+      $($name($name),)*
+    }
+
+    impl BridgeEvent {
+      pub fn name(&self) -> &'static str {
+        match self {
+          $(BridgeEvent::$name(_) => $name::NAME,)*
+        }
+      }
+
+      pub fn schemas() -> Schemas {
+        let mut schemas = BTreeMap::new();
+        $(schemas.insert(String::from($name::NAME), $name::schema());)*
+
+        Schemas(schemas)
+      }
+    }
+
+    impl ToBytes for BridgeEvent {
+      fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        match self {
+          $(BridgeEvent::$name(event) => event.to_bytes(),)*
+        }
+      }
+
+      fn serialized_length(&self) -> usize {
+        match self {
+          $(BridgeEvent::$name(event) => event.serialized_length(),)*
+        }
+      }
+    }
+
+    impl FromBytes for BridgeEvent {
+      fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (name, _) = String::from_bytes(bytes)?;
+
+        match name.strip_prefix(EVENT_PREFIX) {
+          $(Some($name::NAME) => {
+            let (event, bytes) = $name::from_bytes(bytes)?;
+            Ok((BridgeEvent::$name(event), bytes))
+          })*
+          _ => Err(bytesrepr::Error::Formatting),
+        }
+      }
+    }
+  };
+}
+
+// chain types are u8, hash_algorithm is `HashAlgorithm as u8`
+bridge_events! {
+  ProofOfBurn {
+    mint_token: Bytes,
+    burn_token: Bytes,
+    mint_caller: Bytes,
+    burn_caller: Bytes,
+    burn_amount: U256,
+    burn_nonce: Nonce,
+    burn_deadline: u64,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    burn_proof_hash: U256,
+    hash_algorithm: u8,
+  }
+  ProofOfMint {
+    mint_token: Bytes,
+    burn_token: Bytes,
+    mint_caller: Bytes,
+    burn_caller: Bytes,
+    burn_amount: U256,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    burn_proof_hash: U256,
+    hash_algorithm: u8,
+    // v2, appended so the v1 layout stays a prefix
+    burn_nonce: Nonce,
+    // generic address the tokens were minted to
+    recipient: Bytes,
+    // account which signed the mint deploy, relayer or user
+    executor: AccountHash,
+    block_time: u64,
+  }
+  ApprovedBurnProof {
+    burn_proof_hash: U256,
+  }
+  CancelledBurnProof {
+    burn_proof_hash: U256,
+  }
+  RefundedBurnProof {
+    burn_token: Bytes,
+    burn_caller: Bytes,
+    burn_amount: U256,
+    burn_proof_hash: U256,
+  }
+  ApprovedMerkleRoot {
+    epoch: u64,
+    root: Bytes,
+  }
+  AttestedBurnCancellation {
+    burn_proof_hash: U256,
+  }
+  // admin changes
+  AllowanceSet {
+    mint_token: Bytes,
+    burn_token: Bytes,
+    mint_chain_type: u8,
+    mint_chain_id: u32,
+    burn_chain_type: u8,
+    burn_chain_id: u32,
+    allowance_hash: Bytes,
+    // route ttl after the change, 0 if none
+    default_ttl: u64,
+  }
+  RoleGranted {
+    role: String,
+    account: AccountHash,
+  }
+  RoleRevoked {
+    role: String,
+    account: AccountHash,
+  }
+  ChainSet {
+    chain_type: u8,
+    chain_id: u32,
+    address_length: u8,
+    remote_bridge: Bytes,
+    enabled: bool,
+    name: String,
+  }
+  // per chain pause
+  ChainEnabledSet {
+    chain_type: u8,
+    chain_id: u32,
+    enabled: bool,
+  }
+  RefundTimeoutSet {
+    refund_timeout: u64,
+  }
+  // FeeUpdated {}, no fees yet
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec;
+
+  use casper_types::bytesrepr::{self, Bytes, FromBytes, ToBytes};
+
+  use super::{ApprovedMerkleRoot, BridgeEvent, CancelledBurnProof};
+
+  #[test]
+  fn round_trip() {
+    let event = BridgeEvent::ApprovedMerkleRoot(ApprovedMerkleRoot {
+      epoch: 3,
+      root: Bytes::from(vec![7; 32]),
+    });
+
+    let bytes = event.to_bytes().unwrap();
+
+    assert_eq!(bytes.len(), event.serialized_length());
+    assert_eq!(bytesrepr::deserialize::<BridgeEvent>(bytes), Ok(event));
+  }
+
+  #[test]
+  fn name_prefix() {
+    let bytes = CancelledBurnProof {
+      burn_proof_hash: 1.into(),
+    }
+    .to_bytes()
+    .unwrap();
+
+    // u32 length + "event_CancelledBurnProof" + u256
+    assert_eq!(&bytes[4..28], b"event_CancelledBurnProof");
+    assert_eq!(
+      ApprovedMerkleRoot::from_bytes(&bytes).map(|_| ()),
+      Err(bytesrepr::Error::Formatting)
+    );
+  }
+}
@@ -0,0 +1,34 @@
+//! Types, encodings and hashes shared by the bridge contract, tests and off-chain tooling.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+mod address;
+mod allowance;
+mod chain;
+mod error;
+mod events;
+mod merkle;
+mod mint;
+mod nonce;
+mod proof;
+mod types;
+mod utils;
+
+pub use address::{CasperAddressTag, GenericAddress, GENERIC_ADDRESS_LENGTH};
+pub use allowance::get_allowance_hash;
+pub use chain::ChainInfo;
+pub use error::Error;
+pub use events::{
+  AllowanceSet, ApprovedBurnProof, ApprovedMerkleRoot, AttestedBurnCancellation, BridgeEvent,
+  CancelledBurnProof, ChainEnabledSet, ChainSet, ProofOfBurn, ProofOfMint, RefundTimeoutSet,
+  RefundedBurnProof, RoleGranted, RoleRevoked, Schema, Schemas, BRIDGE_EVENTS_COUNT,
+  BRIDGE_EVENTS_DICT, CES_VERSION, CES_VERSION_KEY, EVENTS_DICT, EVENTS_LENGTH, EVENTS_SCHEMA,
+  EVENT_PREFIX,
+};
+pub use merkle::{compute_merkle_root, get_merkle_path, get_merkle_root, verify_merkle_path};
+pub use mint::MintRequest;
+pub use nonce::{get_nonce_key, Nonce};
+pub use proof::{BurnProof, PROOF_PREIMAGE_LENGTH, PROOF_VERSION};
+pub use types::{Allowance, ChainType, HashAlgorithm, States};
+pub use utils::{encode_hex, keccak256, merge_bytes, pad_with_8_bytes, sha256, u256_to_bytes};
@@ -0,0 +1,97 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{merge_bytes, sha256};
+
+// sha256 tree over burn proof hashes, pairs are sorted before hashing,
+// so an inclusion path needs no left/right flags (same as openzeppelin MerkleProof)
+fn hash_pair(a: &[u8], b: &[u8]) -> Vec<u8> {
+  if a < b {
+    sha256(&merge_bytes(vec![a.to_vec(), b.to_vec()]))
+  } else {
+    sha256(&merge_bytes(vec![b.to_vec(), a.to_vec()]))
+  }
+}
+
+pub fn compute_merkle_root(leaf: &[u8], path: &[Vec<u8>]) -> Vec<u8> {
+  path
+    .iter()
+    .fold(leaf.to_vec(), |node, sibling| hash_pair(&node, sibling))
+}
+
+pub fn verify_merkle_path(root: &[u8], leaf: &[u8], path: &[Vec<u8>]) -> bool {
+  compute_merkle_root(leaf, path) == root
+}
+
+// odd node of a level is carried up as is
+fn next_level(level: &[Vec<u8>]) -> Vec<Vec<u8>> {
+  level
+    .chunks(2)
+    .map(|pair| match pair {
+      [a, b] => hash_pair(a, b),
+      _ => pair[0].clone(),
+    })
+    .collect()
+}
+
+// off-chain side: root and inclusion paths of a batch
+pub fn get_merkle_root(leaves: &[Vec<u8>]) -> Option<Vec<u8>> {
+  let mut level = leaves.to_vec();
+
+  while level.len() > 1 {
+    level = next_level(&level);
+  }
+
+  level.pop()
+}
+
+pub fn get_merkle_path(leaves: &[Vec<u8>], index: usize) -> Vec<Vec<u8>> {
+  let mut path = Vec::new();
+  let mut level = leaves.to_vec();
+  let mut index = index;
+
+  while level.len() > 1 {
+    if let Some(sibling) = level.get(index ^ 1) {
+      path.push(sibling.clone());
+    }
+
+    level = next_level(&level);
+    index /= 2;
+  }
+
+  path
+}
+
+#[cfg(test)]
+mod tests {
+  use alloc::vec::Vec;
+
+  use super::{get_merkle_path, get_merkle_root, verify_merkle_path};
+  use crate::sha256;
+
+  #[test]
+  fn every_leaf_is_included() {
+    for size in 1..=7u8 {
+      let leaves: Vec<Vec<u8>> = (0..size).map(|i| sha256(&[i])).collect();
+      let root = get_merkle_root(&leaves).unwrap();
+
+      for (index, leaf) in leaves.iter().enumerate() {
+        let path = get_merkle_path(&leaves, index);
+
+        assert!(verify_merkle_path(&root, leaf, &path));
+      }
+    }
+  }
+
+  #[test]
+  fn rejects_foreign_leaf() {
+    let leaves: Vec<Vec<u8>> = (0..4u8).map(|i| sha256(&[i])).collect();
+    let root = get_merkle_root(&leaves).unwrap();
+
+    let path = get_merkle_path(&leaves, 0);
+
+    assert!(!verify_merkle_path(&root, &sha256(&[9]), &path));
+    assert!(!verify_merkle_path(&root, &leaves[0], &[]));
+    assert_eq!(get_merkle_root(&[]), None);
+  }
+}
@@ -0,0 +1,14 @@
+use alloc::string::String;
+
+use casper_types::U256;
+
+use crate::{encode_hex, sha256};
+
+// committed as 32 bytes big endian (uint256 on evm), same width on every chain
+pub type Nonce = U256;
+
+// nonces are counted per burn caller, so unrelated users never contend,
+// caller is a generic address (40 bytes), hashed to fit a dictionary key
+pub fn get_nonce_key(burn_caller: &[u8]) -> String {
+  encode_hex(&sha256(burn_caller))
+}
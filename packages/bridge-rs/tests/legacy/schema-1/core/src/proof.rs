@@ -0,0 +1,149 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use casper_types::{
+  bytesrepr::{self, Bytes, FromBytes, ToBytes},
+  CLType, CLTyped, U256,
+};
+
+use crate::{merge_bytes, u256_to_bytes, Error, HashAlgorithm, Nonce};
+
+// bump on any change of the preimage layout
+pub const PROOF_VERSION: u8 = 1;
+
+// 1 + 40 + 40 + 40 + 40 + 32 + 1 + 4 + 1 + 4 + 32 + 8 + 40 + 40
+pub const PROOF_PREIMAGE_LENGTH: usize = 323;
+
+// everything a burn proof hash commits to
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BurnProof {
+  pub proof_version: u8,
+  pub mint_caller: Bytes,
+  pub burn_caller: Bytes,
+  pub mint_token: Bytes,
+  pub burn_token: Bytes,
+  pub burn_amount: U256,
+  pub mint_chain_type: u8,
+  pub mint_chain_id: u32,
+  pub burn_chain_type: u8,
+  pub burn_chain_id: u32,
+  pub burn_nonce: Nonce,
+  pub burn_deadline: u64,
+  pub mint_bridge: Bytes,
+  pub burn_bridge: Bytes,
+}
+
+impl BurnProof {
+  // packed, big endian, same as abi.encodePacked
+  pub fn preimage(&self) -> Result<Vec<u8>, Error> {
+    #[rustfmt::skip]
+    let data = merge_bytes(vec![
+      self.proof_version.to_be_bytes().to_vec(),
+      self.mint_caller.to_vec(), self.burn_caller.to_vec(),
+      self.mint_token.to_vec(), self.burn_token.to_vec(),
+      u256_to_bytes(&self.burn_amount),
+      self.mint_chain_type.to_be_bytes().to_vec(), self.mint_chain_id.to_be_bytes().to_vec(),
+      self.burn_chain_type.to_be_bytes().to_vec(), self.burn_chain_id.to_be_bytes().to_vec(),
+      u256_to_bytes(&self.burn_nonce),
+      self.burn_deadline.to_be_bytes().to_vec(),
+      // bridge deployments on both sides
+      self.mint_bridge.to_vec(), self.burn_bridge.to_vec()
+    ]);
+
+    if data.len() != PROOF_PREIMAGE_LENGTH {
+      return Err(Error::InvalidPackage);
+    }
+
+    Ok(data)
+  }
+
+  pub fn hash(&self, algorithm: HashAlgorithm) -> Result<Vec<u8>, Error> {
+    let data = self.preimage()?;
+
+    Ok(algorithm.digest(&data))
+  }
+}
+
+impl CLTyped for BurnProof {
+  fn cl_type() -> CLType {
+    CLType::Any
+  }
+}
+
+impl ToBytes for BurnProof {
+  fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut result = bytesrepr::allocate_buffer(self)?;
+
+    result.extend(self.proof_version.to_bytes()?);
+    result.extend(self.mint_caller.to_bytes()?);
+    result.extend(self.burn_caller.to_bytes()?);
+    result.extend(self.mint_token.to_bytes()?);
+    result.extend(self.burn_token.to_bytes()?);
+    result.extend(self.burn_amount.to_bytes()?);
+    result.extend(self.mint_chain_type.to_bytes()?);
+    result.extend(self.mint_chain_id.to_bytes()?);
+    result.extend(self.burn_chain_type.to_bytes()?);
+    result.extend(self.burn_chain_id.to_bytes()?);
+    result.extend(self.burn_nonce.to_bytes()?);
+    result.extend(self.burn_deadline.to_bytes()?);
+    result.extend(self.mint_bridge.to_bytes()?);
+    result.extend(self.burn_bridge.to_bytes()?);
+
+    Ok(result)
+  }
+
+  fn serialized_length(&self) -> usize {
+    self.proof_version.serialized_length()
+      + self.mint_caller.serialized_length()
+      + self.burn_caller.serialized_length()
+      + self.mint_token.serialized_length()
+      + self.burn_token.serialized_length()
+      + self.burn_amount.serialized_length()
+      + self.mint_chain_type.serialized_length()
+      + self.mint_chain_id.serialized_length()
+      + self.burn_chain_type.serialized_length()
+      + self.burn_chain_id.serialized_length()
+      + self.burn_nonce.serialized_length()
+      + self.burn_deadline.serialized_length()
+      + self.mint_bridge.serialized_length()
+      + self.burn_bridge.serialized_length()
+  }
+}
+
+impl FromBytes for BurnProof {
+  fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+    let (proof_version, bytes) = u8::from_bytes(bytes)?;
+    let (mint_caller, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_caller, bytes) = Bytes::from_bytes(bytes)?;
+    let (mint_token, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_token, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_amount, bytes) = U256::from_bytes(bytes)?;
+    let (mint_chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (mint_chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (burn_chain_type, bytes) = u8::from_bytes(bytes)?;
+    let (burn_chain_id, bytes) = u32::from_bytes(bytes)?;
+    let (burn_nonce, bytes) = Nonce::from_bytes(bytes)?;
+    let (burn_deadline, bytes) = u64::from_bytes(bytes)?;
+    let (mint_bridge, bytes) = Bytes::from_bytes(bytes)?;
+    let (burn_bridge, bytes) = Bytes::from_bytes(bytes)?;
+
+    let proof = BurnProof {
+      proof_version,
+      mint_caller,
+      burn_caller,
+      mint_token,
+      burn_token,
+      burn_amount,
+      mint_chain_type,
+      mint_chain_id,
+      burn_chain_type,
+      burn_chain_id,
+      burn_nonce,
+      burn_deadline,
+      mint_bridge,
+      burn_bridge,
+    };
+
+    Ok((proof, bytes))
+  }
+}
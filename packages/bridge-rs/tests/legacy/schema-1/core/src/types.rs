@@ -0,0 +1,106 @@
+use core::convert::TryFrom;
+
+use alloc::vec::Vec;
+
+use crate::{keccak256, sha256, Error};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum States {
+  Undefined = 0,
+  Burned,    // 1
+  Approved,  // 2
+  Executed,  // 3
+  Refunded,  // 4
+  Cancelled, // 5
+}
+
+impl TryFrom<u8> for States {
+  type Error = Error;
+
+  fn try_from(val: u8) -> Result<Self, Error> {
+    match val {
+      0 => Ok(States::Undefined),
+      1 => Ok(States::Burned),
+      2 => Ok(States::Approved),
+      3 => Ok(States::Executed),
+      4 => Ok(States::Refunded),
+      5 => Ok(States::Cancelled),
+      _ => Err(Error::UnknownState),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum ChainType {
+  Undefined = 0,
+  Evm,    // 1
+  Casper, // 2
+  Solana, // 3
+  Radix,  // 4
+}
+
+impl TryFrom<u8> for ChainType {
+  type Error = Error;
+
+  fn try_from(val: u8) -> Result<Self, Error> {
+    match val {
+      1 => Ok(ChainType::Evm),
+      2 => Ok(ChainType::Casper),
+      3 => Ok(ChainType::Solana),
+      4 => Ok(ChainType::Radix),
+      _ => Err(Error::UnknownChain),
+    }
+  }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum Allowance {
+  Undefined = 0,
+  Allowed, // 1
+  Blocked, // 2
+}
+
+impl TryFrom<u8> for Allowance {
+  type Error = Error;
+
+  fn try_from(val: u8) -> Result<Self, Error> {
+    match val {
+      0 => Ok(Allowance::Undefined),
+      1 => Ok(Allowance::Allowed),
+      2 => Ok(Allowance::Blocked),
+      _ => Err(Error::UnknownAllowance),
+    }
+  }
+}
+
+// digest used for proof and allowance hashes, fixed per deployment
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[repr(u8)]
+pub enum HashAlgorithm {
+  Sha256 = 0,
+  Keccak256, // 1
+}
+
+impl HashAlgorithm {
+  pub fn digest(&self, data: &[u8]) -> Vec<u8> {
+    match self {
+      HashAlgorithm::Sha256 => sha256(data),
+      HashAlgorithm::Keccak256 => keccak256(data),
+    }
+  }
+}
+
+impl TryFrom<u8> for HashAlgorithm {
+  type Error = Error;
+
+  fn try_from(val: u8) -> Result<Self, Error> {
+    match val {
+      0 => Ok(HashAlgorithm::Sha256),
+      1 => Ok(HashAlgorithm::Keccak256),
+      _ => Err(Error::UnknownHashAlgorithm),
+    }
+  }
+}
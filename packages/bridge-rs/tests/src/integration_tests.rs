@@ -6,54 +6,2133 @@ mod shared;
 
 #[cfg(test)]
 mod tests {
-    use crate::shared::pad_with_8_bytes;
-    use std::{path::PathBuf, str::FromStr};
+    use crate::shared::{evm_address, pad_with_8_bytes, pad_with_tag, CONTRACT_TAG, PACKAGE_TAG};
+    use std::{convert::TryFrom, path::PathBuf, str::FromStr};
+
+    use bridge_core::{
+        get_allowance_hash, get_merkle_path, get_merkle_root, wire_signature, Allowance,
+        AllowanceSet, ApprovedBurnProof, ApprovedMerkleRoot, AttestedBurnCancellation,
+        BridgeConfig, BridgeConfigured, BridgeEvent, BurnProof, ChainEnabledSet, ChainInfo,
+        ChainType, Error, HashAlgorithm, MintRequest, ProofOfMint, ProofRecord, ProofStatesDropped,
+        RefundTimeoutSet, RoleGranted, RoleRevoked, Route, SchemaMigrated, States, EXPIRY_GRACE,
+        PROOF_VERSION,
+    };
+
+    use bridge_events::{self as wire, WireEvent};
+
+    use casper_engine_test_support::{
+        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
+        DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, DEFAULT_GENESIS_CONFIG,
+        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
+    };
+    use casper_execution_engine::core::engine_state::{
+        run_genesis_request::RunGenesisRequest, GenesisAccount,
+    };
+    use casper_types::{
+        account::AccountHash,
+        bytesrepr::{Bytes, FromBytes, ToBytes},
+        runtime_args, Key, Motes, PublicKey, RuntimeArgs, SecretKey, URef, U256, U512,
+    };
+
+    use crate::{
+        shared::{decode_hex, encode_hex, merge_bytes, u256_to_bytes, u256_to_hex},
+        test_fixture::TestFixture,
+    };
+
+    const ALLOWANCE_HASH_VECTORS: &str = include_str!("../../../test-vectors/allowance_hash.json");
+
+    // same vectors must hold for CrossChainBridge.sol getAllowanceHash
+    #[test]
+    fn allowance_hash_golden_vectors() {
+        let vectors: serde_json::Value = serde_json::from_str(ALLOWANCE_HASH_VECTORS).unwrap();
+        let vectors = vectors["vectors"].as_array().unwrap();
+
+        for hash_algorithm in [0u8, 1u8].iter() {
+            let mut deployed = TestFixture::deploy_with_args(runtime_args! {
+                "hash_algorithm" => *hash_algorithm,
+            });
+
+            for chain_id in [1, 137, 1337].iter() {
+                deployed.set_chain(
+                    ChainType::Evm as u8,
+                    *chain_id,
+                    20,
+                    "evm",
+                    Bytes::from(evm_address(3)),
+                    true,
+                );
+            }
+
+            for vector in vectors
+                .iter()
+                .filter(|v| v["hash_algorithm"].as_u64() == Some(*hash_algorithm as u64))
+            {
+                let name = vector["name"].as_str().unwrap();
+
+                let mint_chain_type = vector["mint_chain_type"].as_u64().unwrap() as u8;
+                let mint_chain_id = vector["mint_chain_id"].as_u64().unwrap() as u32;
+                let mint_token = decode_hex(vector["mint_token"].as_str().unwrap());
+
+                let burn_chain_type = vector["burn_chain_type"].as_u64().unwrap() as u8;
+                let burn_chain_id = vector["burn_chain_id"].as_u64().unwrap() as u32;
+                let burn_token = decode_hex(vector["burn_token"].as_str().unwrap());
+
+                let expected = vector["allowance_hash"].as_str().unwrap();
+
+                // reference implementation
+                let allowance_hash = get_allowance_hash(
+                    HashAlgorithm::try_from(*hash_algorithm).unwrap(),
+                    mint_chain_type,
+                    mint_chain_id,
+                    &mint_token,
+                    burn_chain_type,
+                    burn_chain_id,
+                    &burn_token,
+                );
+
+                assert_eq!(encode_hex(&allowance_hash), expected, "{}", name);
+
+                // contract implementation
+                deployed.set_allowance(
+                    Bytes::from(mint_token),
+                    Bytes::from(burn_token),
+                    mint_chain_type,
+                    mint_chain_id,
+                    burn_chain_type,
+                    burn_chain_id,
+                    true,
+                );
+
+                assert_eq!(deployed.get_allowance(expected), Some(1), "{}", name);
+            }
+        }
+    }
+
+    // generic addresses must be left padded for the chain they live on
+    #[test]
+    fn set_allowance_validates_addresses() {
+        let mut deployed = TestFixture::deploy();
+
+        let mint_token = pad_with_tag(
+            CONTRACT_TAG,
+            deployed.token_contract_hash().as_bytes().to_vec(),
+        );
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+
+        let mut allow = |mint_token: Vec<u8>, burn_token: Vec<u8>, is_ok: bool| {
+            deployed.set_allowance(
+                Bytes::from(mint_token),
+                Bytes::from(burn_token),
+                ChainType::Casper as u8,
+                1010,
+                ChainType::Evm as u8,
+                1337,
+                is_ok,
+            )
+        };
+
+        // unpadded evm token
+        allow(mint_token.clone(), vec![2; 40], false);
+        // unknown casper tag
+        allow(pad_with_tag(3, vec![1; 32]), evm_address(2), false);
+        // 41 bytes
+        allow(
+            mint_token.clone(),
+            [vec![0], evm_address(2)].concat(),
+            false,
+        );
+
+        allow(mint_token.clone(), evm_address(2), true);
+    }
+
+    // remote chains are managed by admin, unknown chain types need no upgrade
+    #[test]
+    fn chain_registry() {
+        let mut deployed = TestFixture::deploy();
+
+        let mint_token = Bytes::from(pad_with_tag(
+            CONTRACT_TAG,
+            deployed.token_contract_hash().as_bytes().to_vec(),
+        ));
+
+        // near implicit account, 32 bytes
+        let near_chain_type = 7;
+        let near_address = pad_with_8_bytes(vec![4; 32]);
+
+        // built-in chain type with foreign address length
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            32,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            false,
+        );
+        assert_eq!(deployed.last_error(), Some(Error::AddressLengthMismatch));
+        // longer than a generic address
+        deployed.set_chain(
+            near_chain_type,
+            1,
+            41,
+            "near",
+            Bytes::from(near_address.clone()),
+            false,
+        );
+        assert_eq!(deployed.last_error(), Some(Error::InvalidAddressLength));
+        // this chain is not remote
+        deployed.set_chain(
+            ChainType::Casper as u8,
+            1010,
+            32,
+            "casper",
+            Bytes::from(pad_with_tag(PACKAGE_TAG, vec![3; 32])),
+            false,
+        );
+        // remote bridge does not fit address length
+        deployed.set_chain(
+            near_chain_type,
+            1,
+            20,
+            "near",
+            Bytes::from(near_address.clone()),
+            false,
+        );
+
+        // route to unregistered chain
+        deployed.set_allowance(
+            mint_token.clone(),
+            Bytes::from(near_address.clone()),
+            ChainType::Casper as u8,
+            1010,
+            near_chain_type,
+            1,
+            false,
+        );
+
+        deployed.set_chain(
+            near_chain_type,
+            1,
+            32,
+            "near",
+            Bytes::from(near_address.clone()),
+            true,
+        );
+
+        let chain = |deployed: &mut TestFixture, chain_id: u32| {
+            deployed.view::<Option<ChainInfo>>(
+                "get_chain",
+                runtime_args! {
+                    "chain_type" => near_chain_type,
+                    "chain_id" => chain_id,
+                },
+            )
+        };
+        let near = ChainInfo {
+            enabled: true,
+            address_length: 32,
+            name: "near".to_string(),
+            remote_bridge: Bytes::from(near_address.clone()),
+        };
+
+        assert_eq!(chain(&mut deployed, 1), Some(Some(near.clone())));
+        assert_eq!(chain(&mut deployed, 2), Some(None));
+
+        deployed.set_allowance(
+            mint_token.clone(),
+            Bytes::from(near_address.clone()),
+            ChainType::Casper as u8,
+            1010,
+            near_chain_type,
+            1,
+            true,
+        );
+
+        // nothing can be burned to disabled chain
+        deployed.set_chain_enabled(near_chain_type, 1, false, true);
+
+        // only the flag changes
+        assert_eq!(
+            chain(&mut deployed, 1),
+            Some(Some(ChainInfo {
+                enabled: false,
+                ..near
+            }))
+        );
+
+        let token = deployed.token_contract_hash();
+
+        deployed.burn_and_create_proof(
+            token,
+            Bytes::from(near_address.clone()),
+            Bytes::from(near_address.clone()),
+            near_chain_type,
+            1,
+            0.into(),
+            false,
+        );
+
+        // only registered chains can be toggled
+        deployed.set_chain_enabled(near_chain_type, 2, true, false);
+    }
+
+    #[test]
+    fn approve_burn_proofs_in_batch() {
+        let mut deployed = TestFixture::deploy();
+
+        let status = |deployed: &TestFixture, proof_hash: u64| {
+            deployed
+                .get_burn_proof_status(&u256_to_hex(&proof_hash.into()))
+                .map(|v| States::try_from(v).unwrap())
+        };
+
+        deployed.approve_burn_proof(1.into(), true);
+
+        // already approved proof reverts the whole batch
+        deployed.approve_burn_proofs(vec![2.into(), 1.into()], false, false);
+        assert!(status(&deployed, 2) == None);
+
+        // or is skipped
+        deployed.approve_burn_proofs(vec![2.into(), 1.into(), 3.into()], true, true);
+        assert!(status(&deployed, 1) == Some(States::Approved));
+        assert!(status(&deployed, 2) == Some(States::Approved));
+        assert!(status(&deployed, 3) == Some(States::Approved));
+    }
+
+    // approver posts one root for a batch, users mint with inclusion paths
+    #[test]
+    fn mint_with_merkle_proof() {
+        let mut deployed = TestFixture::deploy();
+
+        let token = deployed.token_contract_hash();
+        let account = deployed.account();
+
+        let bridge = pad_with_tag(
+            PACKAGE_TAG,
+            deployed.contract_package_hash().value().to_vec(),
+        );
+        let remote_bridge = evm_address(3);
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(remote_bridge.clone()),
+            true,
+        );
+
+        deployed.set_allowance(
+            Bytes::from(pad_with_tag(CONTRACT_TAG, token.as_bytes().to_vec())),
+            Bytes::from(evm_address(2)),
+            ChainType::Casper as u8,
+            1010,
+            ChainType::Evm as u8,
+            1337,
+            true,
+        );
+
+        let burn_proof = |burn_nonce: u64| BurnProof {
+            proof_version: PROOF_VERSION,
+            mint_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+            burn_caller: Bytes::from(evm_address(1)),
+            mint_token: Bytes::from(pad_with_tag(CONTRACT_TAG, token.as_bytes().to_vec())),
+            burn_token: Bytes::from(evm_address(2)),
+            burn_amount: 500.into(),
+            mint_chain_type: ChainType::Casper as u8,
+            mint_chain_id: 1010,
+            burn_chain_type: ChainType::Evm as u8,
+            burn_chain_id: 1337,
+            burn_nonce: burn_nonce.into(),
+            burn_deadline: 0,
+            mint_bridge: Bytes::from(bridge.clone()),
+            burn_bridge: Bytes::from(remote_bridge.clone()),
+        };
+
+        let leaves: Vec<Vec<u8>> = (0..5)
+            .map(|nonce| burn_proof(nonce).hash(HashAlgorithm::Sha256).unwrap())
+            .collect();
+
+        // root must be 32 bytes
+        deployed.approve_merkle_root(Bytes::from(vec![1; 31]), false);
+        deployed.approve_merkle_root(Bytes::from(get_merkle_root(&leaves).unwrap()), true);
+
+        let mut minter_fn = |index: usize, epoch: u64, path: Vec<Vec<u8>>, is_ok: bool| {
+            deployed.mint_with_merkle_proof(
+                token,
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(1)),
+                ChainType::Evm as u8,
+                1337,
+                U256::from_big_endian(&leaves[index]),
+                500.into(),
+                (index as u64).into(),
+                0,
+                epoch,
+                path.into_iter().map(Bytes::from).collect(),
+                is_ok,
+            )
+        };
+
+        // path of another leaf
+        minter_fn(3, 0, get_merkle_path(&leaves, 2), false);
+        // unknown epoch
+        minter_fn(3, 1, get_merkle_path(&leaves, 3), false);
+
+        minter_fn(3, 0, get_merkle_path(&leaves, 3), true);
+        minter_fn(4, 0, get_merkle_path(&leaves, 4), true);
+
+        // leaf is executed, cant mint twice
+        minter_fn(3, 0, get_merkle_path(&leaves, 3), false);
+
+        let some = deployed
+            .get_burn_proof_status(&encode_hex(&leaves[3]))
+            .map(|v| States::try_from(v).unwrap());
+        assert!(some == Some(States::Executed));
+
+        let account_balance = deployed.get_balance(&account.to_string());
+        assert!(account_balance == 1_000.into());
+    }
+
+    #[test]
+    fn mint_with_burn_proofs_in_batch() {
+        let mut deployed = TestFixture::deploy();
+
+        let token = deployed.token_contract_hash();
+        let account = deployed.account();
+
+        let bridge = pad_with_tag(
+            PACKAGE_TAG,
+            deployed.contract_package_hash().value().to_vec(),
+        );
+        let remote_bridge = evm_address(3);
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(remote_bridge.clone()),
+            true,
+        );
+
+        deployed.set_allowance(
+            Bytes::from(pad_with_tag(CONTRACT_TAG, token.as_bytes().to_vec())),
+            Bytes::from(evm_address(2)),
+            ChainType::Casper as u8,
+            1010,
+            ChainType::Evm as u8,
+            1337,
+            true,
+        );
+
+        let request = |burn_nonce: u64, burn_amount: u64| {
+            let burn_proof_hash = BurnProof {
+                proof_version: PROOF_VERSION,
+                mint_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+                burn_caller: Bytes::from(evm_address(1)),
+                mint_token: Bytes::from(pad_with_tag(CONTRACT_TAG, token.as_bytes().to_vec())),
+                burn_token: Bytes::from(evm_address(2)),
+                burn_amount: burn_amount.into(),
+                mint_chain_type: ChainType::Casper as u8,
+                mint_chain_id: 1010,
+                burn_chain_type: ChainType::Evm as u8,
+                burn_chain_id: 1337,
+                burn_nonce: burn_nonce.into(),
+                burn_deadline: 0,
+                mint_bridge: Bytes::from(bridge.clone()),
+                burn_bridge: Bytes::from(remote_bridge.clone()),
+            }
+            .hash(HashAlgorithm::Sha256)
+            .unwrap();
+
+            MintRequest {
+                mint_token: token,
+                burn_token: Bytes::from(evm_address(2)),
+                burn_caller: Bytes::from(evm_address(1)),
+                burn_chain_type: ChainType::Evm as u8,
+                burn_chain_id: 1337,
+                burn_amount: burn_amount.into(),
+                burn_proof_hash: U256::from_big_endian(&burn_proof_hash),
+                burn_nonce: burn_nonce.into(),
+                burn_deadline: 0,
+            }
+        };
+
+        let requests = vec![request(0, 100), request(1, 200), request(2, 300)];
+
+        for request in requests.iter().take(2) {
+            deployed.approve_burn_proof(request.burn_proof_hash, true);
+        }
+
+        // last proof is not approved yet, nothing is minted
+        deployed.mint_with_burn_proofs(requests.clone(), false);
+        assert!(deployed.get_balance(&account.to_string()) == 0.into());
+        assert_eq!(deployed.last_error(), Some(Error::ProofNotApproved));
+
+        deployed.approve_burn_proof(requests[2].burn_proof_hash, true);
+
+        // same proof twice in one batch
+        deployed.mint_with_burn_proofs(vec![requests[0].clone(), requests[0].clone()], false);
+        assert_eq!(deployed.last_error(), Some(Error::ProofAlreadyExecuted));
+
+        deployed.mint_with_burn_proofs(requests.clone(), true);
+        assert!(deployed.get_balance(&account.to_string()) == 600.into());
+
+        // mint event links back to the burn by nonce
+        match deployed.get_event(deployed.events_length() - 1) {
+            BridgeEvent::ProofOfMint(ProofOfMint {
+                burn_nonce,
+                burn_proof_hash,
+                recipient,
+                executor,
+                ..
+            }) => {
+                assert_eq!(burn_nonce, 2.into());
+                assert_eq!(burn_proof_hash, requests[2].burn_proof_hash);
+                assert_eq!(
+                    recipient,
+                    Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec()))
+                );
+                assert_eq!(executor, account);
+            }
+            event => panic!("unexpected event {:?}", event),
+        }
+
+        for request in requests.iter() {
+            let status = deployed
+                .get_burn_proof_status(&u256_to_hex(&request.burn_proof_hash))
+                .map(|v| States::try_from(v).unwrap());
+            assert!(status == Some(States::Executed));
+        }
+
+        // executed proofs can't be minted again
+        deployed.mint_with_burn_proofs(vec![requests[1].clone()], false);
+    }
+
+    // events are readable through __events / __events_length, without parsing transforms
+    #[test]
+    fn ces_events() {
+        let mut deployed = TestFixture::deploy();
+
+        // install config, one admin and one approver
+        assert_eq!(deployed.events_length(), 3);
+
+        deployed.approve_burn_proof(7.into(), true);
+        deployed.approve_merkle_root(Bytes::from(vec![1; 32]), true);
+
+        // reverted calls leave no events
+        deployed.approve_burn_proof(7.into(), false);
+
+        assert_eq!(deployed.events_length(), 5);
+        assert_eq!(
+            deployed.get_event(3),
+            BridgeEvent::ApprovedBurnProof(ApprovedBurnProof {
+                burn_proof_hash: 7.into(),
+            })
+        );
+        assert_eq!(
+            deployed.get_event(4),
+            BridgeEvent::ApprovedMerkleRoot(ApprovedMerkleRoot {
+                epoch: 0,
+                root: Bytes::from(vec![1; 32]),
+            })
+        );
+    }
+
+    // relayer reads from its cursor up to bridge_events_count
+    #[test]
+    fn bridge_events_by_cursor() {
+        let mut deployed = TestFixture::deploy();
+
+        // install events
+        let installed = deployed.bridge_events_count();
+        assert_eq!(installed, 3);
+
+        deployed.approve_burn_proof(1.into(), true);
+        deployed.approve_burn_proof(2.into(), true);
+
+        let count = deployed.bridge_events_count();
+        assert_eq!(count, installed + 2);
+
+        for cursor in installed..count {
+            let event = deployed.get_bridge_event(cursor).unwrap();
+
+            let mut burn_proof_hash = [0u8; 32];
+            burn_proof_hash.copy_from_slice(&u256_to_bytes(&(cursor - installed + 1).into()));
+
+            assert_eq!(
+                WireEvent::decode(&event),
+                Ok(WireEvent::ApprovedBurnProof(wire::ApprovedBurnProof {
+                    burn_proof_hash: wire::Hash(burn_proof_hash),
+                }))
+            );
+        }
+
+        assert_eq!(deployed.get_bridge_event(count), None);
+
+        deployed.approve_burn_proof(3.into(), true);
+        assert_eq!(deployed.bridge_events_count(), count + 1);
+
+        // same bytes also written to a new uref for the indexer
+        assert_eq!(
+            deployed.last_event_urefs(),
+            vec![deployed.get_bridge_event(count).unwrap()]
+        );
+    }
+
+    // every wire event the contract writes starts with the bridge-core signature of its layout
+    #[test]
+    fn wire_signatures() {
+        let mut deployed = TestFixture::deploy();
+        let (account, account_2) = TestFixture::account_hashes();
+
+        let token = deployed.token_contract_hash();
+        let mint_token = evm_address(2);
+        let burn_token = pad_with_tag(CONTRACT_TAG, token.value().to_vec());
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.set_chain_enabled(ChainType::Evm as u8, 1337, true, true);
+        deployed.set_allowance(
+            Bytes::from(mint_token.clone()),
+            Bytes::from(burn_token),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+        deployed.set_refund_timeout(10, true);
+
+        deployed.mint(1_000.into(), account);
+        deployed.burn_with_session(
+            token,
+            Bytes::from(mint_token),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            None,
+            true,
+        );
+
+        deployed.approve_burn_proof(1.into(), true);
+        deployed.approve_merkle_root(Bytes::from(vec![1; 32]), true);
+        deployed.grant_role("ROLE_APPROVER", account_2, true);
+        deployed.revoke_role("ROLE_APPROVER", account_2, true);
+
+        let count = deployed.bridge_events_count();
+        assert_eq!(count, u64::from(deployed.events_length()));
+
+        for index in 0..count {
+            let bytes = deployed.get_bridge_event(index).unwrap();
+
+            // both sides of the bridge only emit the v2 layouts
+            let name = match deployed.get_event(index as u32).name() {
+                "ProofOfBurn" => "ProofOfBurnV2",
+                "ProofOfMint" => "ProofOfMintV2",
+                name => name,
+            };
+            let signature = wire_signature(name).unwrap();
+
+            assert_eq!((name, &bytes[..4]), (name, &signature[..]));
+
+            let event = WireEvent::decode(&bytes).unwrap();
+            assert_eq!((event.name(), event.signature()), (name, signature));
+        }
+    }
+
+    #[test]
+    fn admin_events() {
+        let mut deployed = TestFixture::deploy();
+
+        let account = deployed.account();
+        let account_2 = deployed.account_2();
+
+        let installed = deployed.events_length();
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.set_chain_enabled(ChainType::Evm as u8, 1337, false, true);
+        deployed.set_refund_timeout(10, true);
+
+        deployed.grant_role("ROLE_NOBODY", account_2, false);
+        deployed.grant_role("ROLE_APPROVER", account_2, true);
+        deployed.revoke_role("ROLE_APPROVER", account, true);
+
+        // approver role moved away
+        deployed.approve_burn_proof(1.into(), false);
+
+        let events: Vec<BridgeEvent> = (installed..deployed.events_length())
+            .map(|index| deployed.get_event(index))
+            .collect();
+
+        let names: Vec<&str> = events.iter().map(|event| event.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "ChainSet",
+                "ChainEnabledSet",
+                "RefundTimeoutSet",
+                "RoleGranted",
+                "RoleRevoked"
+            ]
+        );
+
+        assert_eq!(
+            events[1],
+            BridgeEvent::ChainEnabledSet(ChainEnabledSet {
+                chain_type: ChainType::Evm as u8,
+                chain_id: 1337,
+                enabled: false,
+            })
+        );
+        assert_eq!(
+            events[2],
+            BridgeEvent::RefundTimeoutSet(RefundTimeoutSet { refund_timeout: 10 })
+        );
+        assert_eq!(
+            events[3],
+            BridgeEvent::RoleGranted(RoleGranted {
+                role: "ROLE_APPROVER".to_string(),
+                account: account_2,
+            })
+        );
+        assert_eq!(
+            events[4],
+            BridgeEvent::RoleRevoked(RoleRevoked {
+                role: "ROLE_APPROVER".to_string(),
+                account,
+            })
+        );
+    }
+
+    #[test]
+    fn role_holders() {
+        let mut deployed = TestFixture::deploy();
+
+        let account = deployed.account();
+        let account_2 = deployed.account_2();
+
+        deployed.grant_role("ROLE_APPROVER", account_2, true);
+        deployed.grant_role("ROLE_APPROVER", account_2, false);
+        assert_eq!(deployed.last_error(), Some(Error::RoleAlreadyGranted));
+
+        // both approvers are active at once
+        deployed.approve_burn_proof_as(account, 1.into(), true);
+        deployed.approve_burn_proof_as(account_2, 2.into(), true);
+
+        for proof_hash in [U256::from(1), U256::from(2)].iter() {
+            assert_eq!(
+                deployed.get_burn_proof_status(&u256_to_hex(proof_hash)),
+                Some(States::Approved as u8)
+            );
+        }
+
+        // revoking one approver keeps the other
+        deployed.revoke_role("ROLE_APPROVER", account, true);
+        deployed.approve_burn_proof_as(account, 3.into(), false);
+        assert_eq!(deployed.last_error(), Some(Error::MissingApproverRole));
+        deployed.approve_burn_proof_as(account_2, 3.into(), true);
+
+        deployed.revoke_role("ROLE_APPROVER", account, false);
+        assert_eq!(deployed.last_error(), Some(Error::RoleNotGranted));
+
+        // the only admin stays
+        deployed.revoke_role("ROLE_ADMIN", account, false);
+        assert_eq!(deployed.last_error(), Some(Error::LastAdmin));
+
+        deployed.grant_role("ROLE_ADMIN", account_2, true);
+        deployed.revoke_role("ROLE_ADMIN", account, true);
+
+        // no admin left for the deployer
+        deployed.grant_role("ROLE_APPROVER", account, false);
+        assert_eq!(deployed.last_error(), Some(Error::MissingAdminRole));
+    }
+
+    #[test]
+    fn upgrade_keeps_state() {
+        let mut deployed = TestFixture::deploy();
+
+        let previous = deployed.contract_hash();
+        assert_eq!(deployed.schema_version(), 2);
+
+        let installed = deployed.events_length();
+
+        deployed.approve_burn_proof(1.into(), true);
+        deployed.set_refund_timeout(10, true);
+
+        deployed.upgrade(false, true);
+
+        let current = deployed.contract_hash();
+        assert_ne!(current, previous);
+        assert_eq!(deployed.schema_version(), 2);
+
+        // named keys of the previous version are shared
+        assert_eq!(
+            deployed.get_burn_proof_status(&u256_to_hex(&1.into())),
+            Some(States::Approved as u8)
+        );
+        // no SchemaMigrated, the layout is already current
+        assert_eq!(deployed.events_length(), installed + 2);
+
+        deployed.approve_burn_proof(1.into(), false);
+        deployed.approve_burn_proof(2.into(), true);
+
+        // previous version stays callable unless disabled
+        deployed.set_refund_timeout_at(previous, 20, true);
+
+        deployed.upgrade(true, true);
+
+        deployed.set_refund_timeout_at(current, 30, false);
+        deployed.set_refund_timeout(30, true);
+        assert_eq!(deployed.events_length(), installed + 5);
+    }
+
+    // read-only entry points, through the view session
+    #[test]
+    fn views() {
+        let mut deployed = TestFixture::deploy();
+        let (account, account_2) = TestFixture::account_hashes();
+
+        let config = BridgeConfig {
+            chain_type: ChainType::Casper as u8,
+            chain_id: 1010,
+            hash_algorithm: HashAlgorithm::Sha256 as u8,
+            refund_timeout: 7 * 24 * 60 * 60 * 1000,
+            merkle_epoch: 0,
+            schema_version: 2,
+        };
+        assert_eq!(
+            deployed.view::<BridgeConfig>("get_config", runtime_args! {}),
+            Some(config.clone())
+        );
+
+        deployed.set_refund_timeout(10, true);
+        assert_eq!(
+            deployed.view::<BridgeConfig>("get_config", runtime_args! {}),
+            Some(BridgeConfig {
+                refund_timeout: 10,
+                ..config
+            })
+        );
+
+        // roles
+        let roles = |deployed: &mut TestFixture, role: &str| {
+            deployed.view::<Vec<AccountHash>>(
+                "get_roles",
+                runtime_args! {
+                    "role" => role.to_string(),
+                },
+            )
+        };
+
+        assert_eq!(roles(&mut deployed, "ROLE_ADMIN"), Some(vec![account]));
+        assert_eq!(roles(&mut deployed, "ROLE_APPROVER"), Some(vec![account]));
+
+        deployed.grant_role("ROLE_APPROVER", account_2, true);
+        assert_eq!(
+            roles(&mut deployed, "ROLE_APPROVER"),
+            Some(vec![account, account_2])
+        );
+
+        assert_eq!(roles(&mut deployed, "ROLE_MINTER"), None);
+        assert_eq!(deployed.last_error(), Some(Error::UnknownRole));
+
+        // proof states, undefined for unknown proofs
+        let proof_state = |deployed: &mut TestFixture, proof_hash: U256| {
+            deployed.view::<u8>(
+                "get_proof_state",
+                runtime_args! {
+                    "proof_hash" => proof_hash,
+                },
+            )
+        };
+
+        assert_eq!(
+            proof_state(&mut deployed, 1.into()),
+            Some(States::Undefined as u8)
+        );
+        deployed.approve_burn_proof(1.into(), true);
+        assert_eq!(
+            proof_state(&mut deployed, 1.into()),
+            Some(States::Approved as u8)
+        );
+
+        // allowances, either direction of a route
+        let token = deployed.token_contract_hash();
+        let mint_token = Bytes::from(evm_address(2));
+        let burn_token = Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec()));
+
+        let allowance =
+            |deployed: &mut TestFixture, mint: (&Bytes, u8, u32), burn: (&Bytes, u8, u32)| {
+                deployed.view::<u8>(
+                    "get_allowance",
+                    runtime_args! {
+                        "mint_token" => mint.0.clone(),
+                        "burn_token" => burn.0.clone(),
+                        "mint_chain_type" => mint.1,
+                        "mint_chain_id" => mint.2,
+                        "burn_chain_type" => burn.1,
+                        "burn_chain_id" => burn.2,
+                    },
+                )
+            };
+
+        let evm = (&mint_token, ChainType::Evm as u8, 1337);
+        let casper = (&burn_token, ChainType::Casper as u8, 1010);
+
+        assert_eq!(
+            allowance(&mut deployed, evm, casper),
+            Some(Allowance::Undefined as u8)
+        );
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.set_allowance(
+            mint_token.clone(),
+            burn_token.clone(),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+
+        assert_eq!(
+            allowance(&mut deployed, evm, casper),
+            Some(Allowance::Allowed as u8)
+        );
+        assert_eq!(
+            allowance(&mut deployed, casper, evm),
+            Some(Allowance::Allowed as u8)
+        );
+
+        // chains, none for unknown ones
+        let chain = |deployed: &mut TestFixture, chain_id: u32| {
+            deployed.view::<Option<ChainInfo>>(
+                "get_chain",
+                runtime_args! {
+                    "chain_type" => ChainType::Evm as u8,
+                    "chain_id" => chain_id,
+                },
+            )
+        };
+
+        assert_eq!(
+            chain(&mut deployed, 1337),
+            Some(Some(ChainInfo {
+                enabled: true,
+                address_length: 20,
+                name: "kovan".to_string(),
+                remote_bridge: Bytes::from(evm_address(3)),
+            }))
+        );
+        assert_eq!(chain(&mut deployed, 1), Some(None));
+
+        // nonces and proof records of a burn
+        let burn_caller = Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec()));
+        let next_nonce = |deployed: &mut TestFixture| {
+            deployed.view::<U256>(
+                "get_next_nonce",
+                runtime_args! {
+                    "burn_caller" => burn_caller.clone(),
+                },
+            )
+        };
+        let proof_record = |deployed: &mut TestFixture, proof_hash: U256| {
+            deployed.view::<Option<ProofRecord>>(
+                "get_burn_proof_record",
+                runtime_args! {
+                    "proof_hash" => proof_hash,
+                },
+            )
+        };
+
+        assert_eq!(next_nonce(&mut deployed), Some(0.into()));
+
+        deployed.mint(1_000.into(), account);
+        deployed.burn_with_session(
+            token,
+            mint_token,
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            None,
+            true,
+        );
+        let burn_proof_hash = deployed.session_burn_proof_hash();
+
+        assert_eq!(next_nonce(&mut deployed), Some(1.into()));
+
+        let record = proof_record(&mut deployed, burn_proof_hash)
+            .unwrap()
+            .unwrap();
+        assert_eq!(record.burned_at, Some(0));
+        assert_eq!(record.params.unwrap().burn_caller, burn_caller);
+
+        // approved on this side, params come with the mint
+        assert_eq!(
+            proof_record(&mut deployed, 1.into()),
+            Some(Some(ProofRecord {
+                approved_at: Some(0),
+                approver: Some(account),
+                ..ProofRecord::default()
+            }))
+        );
+        assert_eq!(proof_record(&mut deployed, 2.into()), Some(None));
+    }
+
+    // burn side record, read back through the view
+    #[test]
+    fn burn_proof_record() {
+        let mut deployed = TestFixture::deploy();
+        let account = deployed.account();
+
+        let token = deployed.token_contract_hash();
+        let mint_token = evm_address(2);
+        let burn_token = pad_with_tag(CONTRACT_TAG, token.value().to_vec());
+        let bridge = pad_with_tag(
+            PACKAGE_TAG,
+            deployed.contract_package_hash().value().to_vec(),
+        );
+        let now: u64 = 1_000_000_000;
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.set_allowance(
+            Bytes::from(mint_token.clone()),
+            Bytes::from(burn_token.clone()),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+
+        deployed.set_block_time(now);
+        deployed.mint(1_000.into(), account);
+        deployed.burn_with_session(
+            token,
+            Bytes::from(mint_token.clone()),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            Some(now + 60_000),
+            true,
+        );
+        let burn_proof_hash = deployed.session_burn_proof_hash();
+
+        let record = deployed
+            .view::<Option<ProofRecord>>(
+                "get_burn_proof_record",
+                runtime_args! {
+                    "proof_hash" => burn_proof_hash,
+                },
+            )
+            .expect("should be viewed")
+            .expect("should be recorded");
+
+        let params = record.params.expect("should have params");
+        assert_eq!(params.proof_version, PROOF_VERSION);
+        assert_eq!(params.mint_caller, Bytes::from(evm_address(7)));
+        assert_eq!(
+            params.burn_caller,
+            Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec()))
+        );
+        assert_eq!(params.mint_token, Bytes::from(mint_token));
+        assert_eq!(params.burn_token, Bytes::from(burn_token));
+        assert_eq!(params.burn_amount, 400.into());
+        assert_eq!(params.mint_chain_type, ChainType::Evm as u8);
+        assert_eq!(params.mint_chain_id, 1337);
+        assert_eq!(params.burn_chain_type, ChainType::Casper as u8);
+        assert_eq!(params.burn_chain_id, 1010);
+        assert_eq!(params.burn_nonce, 0.into());
+        assert_eq!(params.burn_deadline, now + 60_000);
+        assert_eq!(params.mint_bridge, Bytes::from(evm_address(3)));
+        assert_eq!(params.burn_bridge, Bytes::from(bridge));
+
+        // the record holds everything the proof hash commits to
+        assert_eq!(
+            U256::from_big_endian(&params.hash(HashAlgorithm::Sha256).unwrap()),
+            burn_proof_hash
+        );
+
+        assert_eq!(record.burned_at, Some(now));
+        assert_eq!(record.approved_at, None);
+        assert_eq!(record.approver, None);
+        assert_eq!(record.executed_at, None);
+        assert_eq!(record.cancelled_at, None);
+        assert_eq!(record.refunded_at, None);
+        assert!(!record.cancel_attested);
+    }
+
+    // install from before schema versioning, roles come from the old access model
+    #[test]
+    fn proof_record_items() {
+        let mut deployed = TestFixture::deploy();
+        let account = deployed.account();
+
+        let record_item = |deployed: &TestFixture, proof_hash: u64| {
+            deployed.get_dict_item::<ProofRecord>("proof_records", &u256_to_hex(&proof_hash.into()))
+        };
+
+        // missing item reads as none
+        assert_eq!(record_item(&deployed, 7), None);
+        assert_eq!(
+            deployed.view::<Option<ProofRecord>>(
+                "get_burn_proof_record",
+                runtime_args! {
+                    "proof_hash" => U256::from(7),
+                },
+            ),
+            Some(None)
+        );
+
+        // first update starts from an empty record
+        deployed.set_block_time(1_000);
+        deployed.approve_burn_proof(7.into(), true);
+        let approved = ProofRecord {
+            approved_at: Some(1_000),
+            approver: Some(account),
+            ..Default::default()
+        };
+        assert_eq!(record_item(&deployed, 7), Some(approved.clone()));
+
+        // later updates keep the stored fields
+        deployed.set_block_time(2_000);
+        deployed.cancel_burn_proof(7.into(), true);
+        assert_eq!(
+            record_item(&deployed, 7),
+            Some(ProofRecord {
+                cancelled_at: Some(2_000),
+                ..approved
+            })
+        );
+
+        // one call writing the same dictionaries several times
+        deployed.approve_burn_proofs(vec![8.into(), 9.into()], false, true);
+        for proof_hash in [8, 9].iter() {
+            assert_eq!(
+                deployed.get_burn_proof_status(&u256_to_hex(&(*proof_hash).into())),
+                Some(States::Approved as u8)
+            );
+            assert_eq!(
+                record_item(&deployed, *proof_hash).and_then(|record| record.approved_at),
+                Some(2_000)
+            );
+        }
+    }
+
+    #[test]
+    fn upgrade_from_schema_0() {
+        let mut deployed = TestFixture::deploy_legacy(0);
+        let account = deployed.account();
+
+        let token = deployed.token_contract_hash();
+        let mint_token = evm_address(2);
+        let mint_caller = evm_address(7);
+        // casper addresses were padded without a tag
+        let burn_token = pad_with_8_bytes(token.value().to_vec());
+        let burn_caller = pad_with_8_bytes(account.as_bytes().to_vec());
+
+        deployed.set_allowance(
+            Bytes::from(mint_token.clone()),
+            Bytes::from(burn_token.clone()),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+        deployed.approve_burn_proof(1.into(), true);
+
+        deployed.mint(1_000.into(), account);
+        deployed.burn_and_create_proof(
+            token,
+            Bytes::from(mint_token.clone()),
+            Bytes::from(mint_caller.clone()),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            true,
+        );
+
+        // 234 byte preimage of the old layout, nonce counted per token
+        let burn_proof_hash = HashAlgorithm::Sha256.digest(&merge_bytes(vec![
+            mint_caller,
+            burn_caller,
+            mint_token.clone(),
+            burn_token.clone(),
+            u256_to_bytes(&400.into()),
+            vec![ChainType::Evm as u8],
+            1337u32.to_be_bytes().to_vec(),
+            vec![ChainType::Casper as u8],
+            1010u32.to_be_bytes().to_vec(),
+            u256_to_bytes(&0.into()),
+        ]));
+        let legacy_allowance_hash = get_allowance_hash(
+            HashAlgorithm::Sha256,
+            ChainType::Evm as u8,
+            1337,
+            &mint_token,
+            ChainType::Casper as u8,
+            1010,
+            &burn_token,
+        );
+
+        // the route to keep, in the current address format
+        let route = Route {
+            mint_token: Bytes::from(mint_token.clone()),
+            burn_token: Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec())),
+            mint_chain_type: ChainType::Evm as u8,
+            mint_chain_id: 1337,
+            burn_chain_type: ChainType::Casper as u8,
+            burn_chain_id: 1010,
+            default_ttl: None,
+        };
+        let allowance_hash = get_allowance_hash(
+            HashAlgorithm::Sha256,
+            ChainType::Evm as u8,
+            1337,
+            &route.mint_token,
+            ChainType::Casper as u8,
+            1010,
+            &route.burn_token,
+        );
+
+        // routes that were never allowed can't be carried over
+        deployed.upgrade_with_legacy_routes(
+            vec![Route {
+                mint_token: Bytes::from(evm_address(9)),
+                ..route.clone()
+            }],
+            false,
+        );
+        assert_eq!(deployed.last_error(), Some(Error::AllowanceNotFound));
+
+        deployed.upgrade_with_legacy_routes(vec![route.clone()], true);
+        assert_eq!(deployed.schema_version(), 2);
+
+        // proof states of the old version are kept as they are
+        assert_eq!(
+            deployed.get_burn_proof_status(&u256_to_hex(&1.into())),
+            Some(States::Approved as u8)
+        );
+        assert_eq!(
+            deployed.get_burn_proof_status(&encode_hex(&burn_proof_hash)),
+            Some(States::Burned as u8)
+        );
+        assert_eq!(
+            deployed.get_dict_item::<U256>("nonces", &encode_hex(&token.value())),
+            Some(1.into())
+        );
+
+        // the allowance moved to the current route hash
+        assert_eq!(
+            deployed.get_allowance(&encode_hex(&legacy_allowance_hash)),
+            None
+        );
+        assert_eq!(
+            deployed.view::<u8>(
+                "get_allowance",
+                runtime_args! {
+                    "mint_token" => route.mint_token.clone(),
+                    "burn_token" => route.burn_token.clone(),
+                    "mint_chain_type" => route.mint_chain_type,
+                    "mint_chain_id" => route.mint_chain_id,
+                    "burn_chain_type" => route.burn_chain_type,
+                    "burn_chain_id" => route.burn_chain_id,
+                },
+            ),
+            Some(Allowance::Allowed as u8)
+        );
+
+        // installer was the approver, the upgrading account holds the access token
+        assert_eq!(deployed.get_role_holders("ROLE_ADMIN"), vec![account]);
+        assert_eq!(deployed.get_role_holders("ROLE_APPROVER"), vec![account]);
+
+        let events: Vec<BridgeEvent> = (0..deployed.events_length())
+            .map(|index| deployed.get_event(index))
+            .collect();
+        assert_eq!(
+            events,
+            vec![
+                BridgeEvent::RoleGranted(RoleGranted {
+                    role: "ROLE_ADMIN".to_string(),
+                    account,
+                }),
+                BridgeEvent::RoleGranted(RoleGranted {
+                    role: "ROLE_APPROVER".to_string(),
+                    account,
+                }),
+                BridgeEvent::AllowanceSet(AllowanceSet {
+                    mint_token: route.mint_token.clone(),
+                    burn_token: route.burn_token.clone(),
+                    mint_chain_type: ChainType::Evm as u8,
+                    mint_chain_id: 1337,
+                    burn_chain_type: ChainType::Casper as u8,
+                    burn_chain_id: 1010,
+                    allowance_hash: Bytes::from(allowance_hash),
+                    default_ttl: 0,
+                }),
+                BridgeEvent::ProofStatesDropped(ProofStatesDropped { schema_version: 0 }),
+                BridgeEvent::SchemaMigrated(SchemaMigrated {
+                    from_version: 0,
+                    to_version: 2,
+                }),
+            ]
+        );
+
+        // the carried over route burns once its chain is registered
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.burn_with_session(
+            token,
+            route.mint_token.clone(),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            100.into(),
+            None,
+            true,
+        );
+        assert_eq!(deployed.get_balance(&account.to_string()), 500.into());
+
+        deployed.approve_burn_proof(1.into(), false);
+        assert_eq!(deployed.last_error(), Some(Error::AlreadyApproved));
+        deployed.approve_burn_proof(2.into(), true);
+        deployed.set_refund_timeout(10, true);
+
+        // old burns have no record to refund from
+        deployed.refund_burn_proof(U256::from_big_endian(&burn_proof_hash), false);
+        assert_eq!(deployed.last_error(), Some(Error::ProofRecordNotFound));
+    }
+
+    // single account roles and no chain id key
+    #[test]
+    fn upgrade_from_schema_1() {
+        let mut deployed = TestFixture::deploy_legacy(1);
+        let (account, account_2) = TestFixture::account_hashes();
+
+        let token = deployed.token_contract_hash();
+        let mint_token = evm_address(2);
+        let burn_token = pad_with_tag(CONTRACT_TAG, token.value().to_vec());
+        let burn_caller = pad_with_8_bytes(account.as_bytes().to_vec());
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.set_allowance(
+            Bytes::from(mint_token.clone()),
+            Bytes::from(burn_token.clone()),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+        deployed.approve_burn_proof(1.into(), true);
+
+        deployed.mint(1_000.into(), account);
+        deployed.burn_with_session(
+            token,
+            Bytes::from(mint_token.clone()),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            None,
+            true,
+        );
+        let burn_proof_hash = deployed.session_burn_proof_hash();
+
+        let allowance_hash = get_allowance_hash(
+            HashAlgorithm::Sha256,
+            ChainType::Evm as u8,
+            1337,
+            &mint_token,
+            ChainType::Casper as u8,
+            1010,
+            &burn_token,
+        );
+
+        deployed.upgrade(false, true);
+        assert_eq!(deployed.schema_version(), 2);
+
+        assert_eq!(
+            deployed.get_burn_proof_status(&u256_to_hex(&1.into())),
+            Some(States::Approved as u8)
+        );
+        assert_eq!(
+            deployed.get_burn_proof_status(&u256_to_hex(&burn_proof_hash)),
+            Some(States::Burned as u8)
+        );
+        assert_eq!(
+            deployed.get_allowance(&encode_hex(&allowance_hash)),
+            Some(Allowance::Allowed as u8)
+        );
+        // same hash as before, read through the current contract
+        assert_eq!(
+            deployed.view::<u8>(
+                "get_allowance",
+                runtime_args! {
+                    "mint_token" => Bytes::from(mint_token.clone()),
+                    "burn_token" => Bytes::from(burn_token.clone()),
+                    "mint_chain_type" => ChainType::Evm as u8,
+                    "mint_chain_id" => 1337u32,
+                    "burn_chain_type" => ChainType::Casper as u8,
+                    "burn_chain_id" => 1010u32,
+                },
+            ),
+            Some(Allowance::Allowed as u8)
+        );
+        assert_eq!(deployed.get_nonce(&burn_caller), 1.into());
+
+        // single holders became lists
+        assert_eq!(deployed.get_role_holders("ROLE_ADMIN"), vec![account]);
+        assert_eq!(deployed.get_role_holders("ROLE_APPROVER"), vec![account]);
+
+        assert_eq!(
+            deployed.get_event(deployed.events_length() - 1),
+            BridgeEvent::SchemaMigrated(SchemaMigrated {
+                from_version: 1,
+                to_version: 2,
+            })
+        );
+
+        deployed.grant_role("ROLE_APPROVER", account_2, true);
+        deployed.approve_burn_proof_as(account_2, 2.into(), true);
+        deployed.approve_burn_proof(1.into(), false);
+        assert_eq!(deployed.last_error(), Some(Error::AlreadyApproved));
+    }
+
+    // roles, chain id, remote chains and routes from the install deploy alone
+    #[test]
+    fn install_config() {
+        let (account, account_2) = TestFixture::account_hashes();
+
+        let kovan = ChainInfo {
+            enabled: true,
+            address_length: 20,
+            name: "kovan".to_string(),
+            remote_bridge: Bytes::from(evm_address(3)),
+        };
 
-    use casper_engine_test_support::{
-        DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, ARG_AMOUNT,
-        DEFAULT_ACCOUNT_ADDR, DEFAULT_ACCOUNT_INITIAL_BALANCE, DEFAULT_GENESIS_CONFIG,
-        DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
-    };
-    use casper_execution_engine::core::engine_state::{
-        run_genesis_request::RunGenesisRequest, GenesisAccount,
-    };
-    use casper_types::{
-        account::AccountHash,
-        bytesrepr::{Bytes, FromBytes, ToBytes},
-        runtime_args, Key, Motes, PublicKey, RuntimeArgs, SecretKey, URef, U256, U512,
-    };
+        let route = Route {
+            mint_token: Bytes::from(evm_address(1)),
+            burn_token: Bytes::from(pad_with_tag(CONTRACT_TAG, vec![1; 32])),
+            mint_chain_type: ChainType::Evm as u8,
+            mint_chain_id: 1337,
+            burn_chain_type: ChainType::Casper as u8,
+            // this chain, validated as casper address
+            burn_chain_id: 7,
+            default_ttl: Some(60_000),
+        };
 
-    use crate::{
-        shared::{encode_hex, merge_bytes, sha256, u256_to_bytes, u256_to_hex},
-        test_fixture::TestFixture,
-    };
+        let mut deployed = TestFixture::deploy_with_args(runtime_args! {
+            "admin" => account_2,
+            "approvers" => vec![account, account_2],
+            "chain_id" => 7u32,
+            "chains" => vec![(ChainType::Evm as u8, 1337u32, kovan)],
+            "routes" => vec![route.clone()],
+        });
+
+        let allowance_hash = get_allowance_hash(
+            HashAlgorithm::Sha256,
+            route.mint_chain_type,
+            route.mint_chain_id,
+            &route.mint_token,
+            route.burn_chain_type,
+            route.burn_chain_id,
+            &route.burn_token,
+        );
+
+        assert_eq!(
+            deployed.get_allowance(&encode_hex(&allowance_hash)),
+            Some(Allowance::Allowed as u8)
+        );
+
+        let events: Vec<BridgeEvent> = (0..deployed.events_length())
+            .map(|index| deployed.get_event(index))
+            .collect();
+
+        let names: Vec<&str> = events.iter().map(|event| event.name()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "BridgeConfigured",
+                "RoleGranted",
+                "RoleGranted",
+                "RoleGranted",
+                "ChainSet",
+                "AllowanceSet"
+            ]
+        );
+
+        assert_eq!(
+            events[0],
+            BridgeEvent::BridgeConfigured(BridgeConfigured {
+                chain_type: ChainType::Casper as u8,
+                chain_id: 7,
+                hash_algorithm: HashAlgorithm::Sha256 as u8,
+                refund_timeout: 7 * 24 * 60 * 60 * 1000,
+            })
+        );
+
+        let granted: Vec<(String, AccountHash)> = events[1..4]
+            .iter()
+            .map(|event| match event {
+                BridgeEvent::RoleGranted(RoleGranted { role, account }) => (role.clone(), *account),
+                event => panic!("unexpected event {:?}", event),
+            })
+            .collect();
+        assert_eq!(
+            granted,
+            vec![
+                ("ROLE_ADMIN".to_string(), account_2),
+                ("ROLE_APPROVER".to_string(), account),
+                ("ROLE_APPROVER".to_string(), account_2),
+            ]
+        );
+
+        // deployer is one of the approvers, but not the admin
+        deployed.approve_burn_proof(1.into(), true);
+        deployed.set_refund_timeout(10, false);
+        assert_eq!(deployed.last_error(), Some(Error::MissingAdminRole));
+    }
+
+    // contract callers burn their own package balance and are recorded by package
+    #[test]
+    fn burn_through_contract() {
+        let mut deployed = TestFixture::deploy();
+
+        let token = deployed.token_contract_hash();
+        let proxy = deployed.deploy_proxy();
+        let proxy_caller = pad_with_tag(PACKAGE_TAG, proxy.value().to_vec());
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.set_allowance(
+            Bytes::from(evm_address(2)),
+            Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec())),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+
+        deployed.mint_to(1_000.into(), Key::from(proxy));
+
+        // more than the package holds
+        deployed.burn_with_proxy(
+            proxy,
+            token,
+            Bytes::from(evm_address(2)),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            1_001.into(),
+            None,
+            false,
+        );
+        assert_eq!(deployed.last_error(), Some(Error::AmountExceeded));
+
+        deployed.burn_with_proxy(
+            proxy,
+            token,
+            Bytes::from(evm_address(2)),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            Some(60_000),
+            true,
+        );
+
+        let event = deployed.get_event(deployed.events_length() - 1);
+        let proof_of_burn = match event {
+            BridgeEvent::ProofOfBurn(proof_of_burn) => proof_of_burn,
+            event => panic!("unexpected event {:?}", event),
+        };
+
+        assert_eq!(proof_of_burn.burn_caller, Bytes::from(proxy_caller.clone()));
+        assert_eq!(proof_of_burn.burn_nonce, 0.into());
+        // deadline is forwarded by the proxy
+        assert_eq!(proof_of_burn.burn_deadline, 60_000);
+
+        // nonce is counted for the package, not for the account behind it
+        let account = deployed.account();
+        assert_eq!(deployed.get_nonce(&proxy_caller), 1.into());
+        assert_eq!(
+            deployed.get_nonce(&pad_with_8_bytes(account.as_bytes().to_vec())),
+            0.into()
+        );
+        assert_eq!(deployed.total_supply(), 600.into());
+    }
+
+    // the next nonce view is what the following burn of that caller commits to
+    #[test]
+    fn next_nonce_per_caller() {
+        let mut deployed = TestFixture::deploy();
+        let account = deployed.account();
+
+        let token = deployed.token_contract_hash();
+        let proxy = deployed.deploy_proxy();
+        let proxy_caller = Bytes::from(pad_with_tag(PACKAGE_TAG, proxy.value().to_vec()));
+        let account_caller = Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec()));
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(evm_address(3)),
+            true,
+        );
+        deployed.set_allowance(
+            Bytes::from(evm_address(2)),
+            Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec())),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+
+        deployed.mint(1_000.into(), account);
+        deployed.mint_to(1_000.into(), Key::from(proxy));
+
+        let next_nonce = |deployed: &mut TestFixture, burn_caller: &Bytes| {
+            deployed
+                .view::<U256>(
+                    "get_next_nonce",
+                    runtime_args! {
+                        "burn_caller" => burn_caller.clone(),
+                    },
+                )
+                .expect("should be viewed")
+        };
+        let last_burn_nonce =
+            |deployed: &TestFixture| match deployed.get_event(deployed.events_length() - 1) {
+                BridgeEvent::ProofOfBurn(proof_of_burn) => proof_of_burn.burn_nonce,
+                event => panic!("unexpected event {:?}", event),
+            };
+
+        assert_eq!(next_nonce(&mut deployed, &account_caller), 0.into());
+        assert_eq!(next_nonce(&mut deployed, &proxy_caller), 0.into());
+
+        for burn in 0..2u64 {
+            deployed.burn_with_session(
+                token,
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(7)),
+                ChainType::Evm as u8,
+                1337,
+                100.into(),
+                None,
+                true,
+            );
+            assert_eq!(last_burn_nonce(&deployed), burn.into());
+
+            assert_eq!(
+                next_nonce(&mut deployed, &account_caller),
+                (burn + 1).into()
+            );
+            assert_eq!(next_nonce(&mut deployed, &proxy_caller), 0.into());
+        }
+
+        // failed burns don't use up a nonce
+        deployed.burn_with_proxy(
+            proxy,
+            token,
+            Bytes::from(evm_address(2)),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            1_001.into(),
+            None,
+            false,
+        );
+        assert_eq!(next_nonce(&mut deployed, &proxy_caller), 0.into());
+
+        deployed.burn_with_proxy(
+            proxy,
+            token,
+            Bytes::from(evm_address(2)),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            100.into(),
+            None,
+            true,
+        );
+        assert_eq!(last_burn_nonce(&deployed), 0.into());
 
-    #[derive(Debug, PartialEq, Eq)]
+        assert_eq!(next_nonce(&mut deployed, &proxy_caller), 1.into());
+        assert_eq!(next_nonce(&mut deployed, &account_caller), 2.into());
 
-    enum States {
-        Burned = 1,
-        Approved = 2,
-        Executed = 3,
+        // unknown callers start at zero
+        assert_eq!(
+            next_nonce(&mut deployed, &Bytes::from(evm_address(7))),
+            0.into()
+        );
     }
 
-    impl From<u8> for States {
-        fn from(val: u8) -> Self {
-            match val {
-                1 => States::Burned,
-                2 => States::Approved,
-                3 => States::Executed,
-                _ => panic!(0),
+    // deadlines from the route ttl, expired mints and the refund grace period
+    #[test]
+    fn burn_deadlines() {
+        let (account, account_2) = TestFixture::account_hashes();
+
+        let remote_bridge = evm_address(3);
+        let kovan = ChainInfo {
+            enabled: true,
+            address_length: 20,
+            name: "kovan".to_string(),
+            remote_bridge: Bytes::from(remote_bridge.clone()),
+        };
+
+        // deployer approves, but has no admin override for refunds
+        let mut deployed = TestFixture::deploy_with_args(runtime_args! {
+            "admin" => account_2,
+            "approvers" => vec![account],
+            "chains" => vec![(ChainType::Evm as u8, 1337u32, kovan)],
+        });
+
+        let token = deployed.token_contract_hash();
+        let bridge = pad_with_tag(
+            PACKAGE_TAG,
+            deployed.contract_package_hash().value().to_vec(),
+        );
+
+        // longer than the default refund timeout, so only the deadline holds refunds back
+        let ttl: u64 = 8 * 24 * 60 * 60 * 1000;
+        let now: u64 = 1_000_000_000;
+
+        deployed.set_block_time(now);
+        deployed.set_allowance_with_ttl(
+            Bytes::from(evm_address(2)),
+            Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec())),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            Some(ttl),
+            true,
+        );
+
+        // mint side, deadline set by the burn chain
+        {
+            let deadline = now + 60_000;
+
+            let burn_proof = BurnProof {
+                proof_version: PROOF_VERSION,
+                mint_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+                burn_caller: Bytes::from(evm_address(1)),
+                mint_token: Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec())),
+                burn_token: Bytes::from(evm_address(2)),
+                burn_amount: 1_000.into(),
+                mint_chain_type: ChainType::Casper as u8,
+                mint_chain_id: 1010,
+                burn_chain_type: ChainType::Evm as u8,
+                burn_chain_id: 1337,
+                burn_nonce: 0.into(),
+                burn_deadline: deadline,
+                mint_bridge: Bytes::from(bridge.clone()),
+                burn_bridge: Bytes::from(remote_bridge.clone()),
+            }
+            .hash(HashAlgorithm::Sha256)
+            .unwrap();
+
+            deployed.approve_burn_proof(U256::from_big_endian(&burn_proof), true);
+
+            let minter_fn = |deployed: &mut TestFixture, is_ok: bool| {
+                deployed.mint_with_burn_proof(
+                    token,
+                    Bytes::from(evm_address(2)),
+                    Bytes::from(evm_address(1)),
+                    ChainType::Evm as u8,
+                    1337,
+                    U256::from_big_endian(&burn_proof),
+                    1_000.into(),
+                    0.into(),
+                    deadline,
+                    is_ok,
+                )
+            };
+
+            deployed.set_block_time(deadline + 1);
+            minter_fn(&mut deployed, false);
+            assert_eq!(deployed.last_error(), Some(Error::ProofExpired));
+
+            // still valid at the deadline itself
+            deployed.set_block_time(deadline);
+            minter_fn(&mut deployed, true);
+        }
+
+        // burn side, no deadline given so the route ttl applies
+        deployed.set_block_time(now);
+        deployed.burn_with_session(
+            token,
+            Bytes::from(evm_address(2)),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            None,
+            true,
+        );
+
+        let proof_of_burn = match deployed.get_event(deployed.events_length() - 1) {
+            BridgeEvent::ProofOfBurn(proof_of_burn) => proof_of_burn,
+            event => panic!("unexpected event {:?}", event),
+        };
+        assert_eq!(proof_of_burn.burn_deadline, now + ttl);
+
+        let proof_hash = deployed.session_burn_proof_hash();
+        assert_eq!(proof_of_burn.burn_proof_hash, proof_hash);
+
+        // expired, but a mint may still land within the grace period
+        deployed.set_block_time(now + ttl + 1);
+        deployed.refund_burn_proof(proof_hash, false);
+        assert_eq!(deployed.last_error(), Some(Error::RefundNotAllowed));
+
+        deployed.set_block_time(now + ttl + EXPIRY_GRACE);
+        deployed.refund_burn_proof(proof_hash, false);
+        assert_eq!(deployed.last_error(), Some(Error::RefundNotAllowed));
+
+        deployed.set_block_time(now + ttl + EXPIRY_GRACE + 1);
+        deployed.refund_burn_proof(proof_hash, true);
+
+        assert_eq!(
+            deployed.get_burn_proof_status(&u256_to_hex(&proof_hash)),
+            Some(States::Refunded as u8)
+        );
+        assert_eq!(deployed.get_balance(&account.to_string()), 1_000.into());
+
+        // explicit deadline through the session, must lie ahead of the block time
+        let now = now + ttl + EXPIRY_GRACE + 1;
+        let burn_with_deadline = |deployed: &mut TestFixture, deadline: u64, is_ok: bool| {
+            deployed.burn_with_session(
+                token,
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(7)),
+                ChainType::Evm as u8,
+                1337,
+                400.into(),
+                Some(deadline),
+                is_ok,
+            )
+        };
+
+        burn_with_deadline(&mut deployed, now, false);
+        assert_eq!(deployed.last_error(), Some(Error::InvalidDeadline));
+
+        burn_with_deadline(&mut deployed, now + 60_000, true);
+
+        match deployed.get_event(deployed.events_length() - 1) {
+            BridgeEvent::ProofOfBurn(proof_of_burn) => {
+                assert_eq!(proof_of_burn.burn_deadline, now + 60_000)
             }
+            event => panic!("unexpected event {:?}", event),
         }
     }
 
-    #[derive(Debug, PartialEq, Eq)]
-    enum ChainType {
-        Undefined = 0,
-        Evm = 1,
-        Casper = 2,
-        Solana = 3,
-        Radix = 4,
+    // destination side attests the cancellation, refund no longer waits for the deadline
+    #[test]
+    fn refund_attested_cancellation() {
+        let (account, account_2) = TestFixture::account_hashes();
+
+        let kovan = ChainInfo {
+            enabled: true,
+            address_length: 20,
+            name: "kovan".to_string(),
+            remote_bridge: Bytes::from(evm_address(3)),
+        };
+
+        // deployer approves, but has no admin override for refunds
+        let mut deployed = TestFixture::deploy_with_args(runtime_args! {
+            "admin" => account_2,
+            "approvers" => vec![account],
+            "chains" => vec![(ChainType::Evm as u8, 1337u32, kovan)],
+        });
+
+        let token = deployed.token_contract_hash();
+        let refund_timeout: u64 = 7 * 24 * 60 * 60 * 1000;
+        let now: u64 = 1_000_000_000;
+        let deadline = now + 4 * refund_timeout;
+
+        deployed.set_block_time(now);
+        deployed.set_allowance(
+            Bytes::from(evm_address(2)),
+            Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec())),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+
+        deployed.mint(1_000.into(), account);
+        deployed.burn_with_session(
+            token,
+            Bytes::from(evm_address(2)),
+            Bytes::from(evm_address(7)),
+            ChainType::Evm as u8,
+            1337,
+            400.into(),
+            Some(deadline),
+            true,
+        );
+        let proof_hash = deployed.session_burn_proof_hash();
+        assert_eq!(deployed.get_balance(&account.to_string()), 600.into());
+
+        // within the refund timeout
+        deployed.set_block_time(now + refund_timeout - 1);
+        deployed.refund_burn_proof(proof_hash, false);
+        assert_eq!(deployed.last_error(), Some(Error::RefundLocked));
+
+        // past the timeout, but neither attested nor expired
+        deployed.set_block_time(now + refund_timeout);
+        deployed.refund_burn_proof(proof_hash, false);
+        assert_eq!(deployed.last_error(), Some(Error::RefundNotAllowed));
+
+        // only burned proofs are attested
+        deployed.attest_burn_cancellation(1.into(), false);
+        assert_eq!(deployed.last_error(), Some(Error::ProofNotBurned));
+
+        deployed.attest_burn_cancellation(proof_hash, true);
+        assert_eq!(
+            deployed.get_event(deployed.events_length() - 1),
+            BridgeEvent::AttestedBurnCancellation(AttestedBurnCancellation {
+                burn_proof_hash: proof_hash,
+            })
+        );
+
+        // still well before the deadline
+        deployed.refund_burn_proof(proof_hash, true);
+
+        assert_eq!(
+            deployed.get_burn_proof_status(&u256_to_hex(&proof_hash)),
+            Some(States::Refunded as u8)
+        );
+        assert_eq!(deployed.get_balance(&account.to_string()), 1_000.into());
+
+        let record = deployed
+            .view::<Option<ProofRecord>>(
+                "get_burn_proof_record",
+                runtime_args! {
+                    "proof_hash" => proof_hash,
+                },
+            )
+            .expect("should be viewed")
+            .expect("should be recorded");
+        assert!(record.cancel_attested);
+        assert_eq!(record.refunded_at, Some(now + refund_timeout));
+    }
+
+    // core golden vectors cover the keccak preimage, this covers the installed bridge
+    #[test]
+    fn keccak_bridge() {
+        let (account, _) = TestFixture::account_hashes();
+
+        let remote_bridge = evm_address(3);
+        let kovan = ChainInfo {
+            enabled: true,
+            address_length: 20,
+            name: "kovan".to_string(),
+            remote_bridge: Bytes::from(remote_bridge.clone()),
+        };
+
+        let mut deployed = TestFixture::deploy_with_args(runtime_args! {
+            "hash_algorithm" => HashAlgorithm::Keccak256 as u8,
+            "chains" => vec![(ChainType::Evm as u8, 1337u32, kovan)],
+        });
+
+        let token = deployed.token_contract_hash();
+        let generic_token = Bytes::from(pad_with_tag(CONTRACT_TAG, token.value().to_vec()));
+        let bridge = pad_with_tag(
+            PACKAGE_TAG,
+            deployed.contract_package_hash().value().to_vec(),
+        );
+
+        deployed.set_allowance(
+            Bytes::from(evm_address(2)),
+            generic_token.clone(),
+            ChainType::Evm as u8,
+            1337,
+            ChainType::Casper as u8,
+            1010,
+            true,
+        );
+
+        // mint side, proof hashed by a keccak bridge on evm
+        {
+            let burn_proof = BurnProof {
+                proof_version: PROOF_VERSION,
+                mint_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+                burn_caller: Bytes::from(evm_address(1)),
+                mint_token: generic_token.clone(),
+                burn_token: Bytes::from(evm_address(2)),
+                burn_amount: 1_000.into(),
+                mint_chain_type: ChainType::Casper as u8,
+                mint_chain_id: 1010,
+                burn_chain_type: ChainType::Evm as u8,
+                burn_chain_id: 1337,
+                burn_nonce: 0.into(),
+                burn_deadline: 0,
+                mint_bridge: Bytes::from(bridge.clone()),
+                burn_bridge: Bytes::from(remote_bridge.clone()),
+            };
+            let keccak_hash =
+                U256::from_big_endian(&burn_proof.hash(HashAlgorithm::Keccak256).unwrap());
+            let sha256_hash =
+                U256::from_big_endian(&burn_proof.hash(HashAlgorithm::Sha256).unwrap());
+
+            // approved under the sha256 hash, which this bridge never computes
+            deployed.approve_burn_proof(sha256_hash, true);
+            deployed.mint_with_burn_proof(
+                token,
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(1)),
+                ChainType::Evm as u8,
+                1337,
+                sha256_hash,
+                1_000.into(),
+                0.into(),
+                0,
+                false,
+            );
+
+            deployed.approve_burn_proof(keccak_hash, true);
+            deployed.mint_with_burn_proof(
+                token,
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(1)),
+                ChainType::Evm as u8,
+                1337,
+                keccak_hash,
+                1_000.into(),
+                0.into(),
+                0,
+                true,
+            );
+
+            assert_eq!(
+                deployed.get_burn_proof_status(&u256_to_hex(&keccak_hash)),
+                Some(States::Executed as u8)
+            );
+            assert_eq!(deployed.get_balance(&account.to_string()), 1_000.into());
+
+            match deployed.get_event(deployed.events_length() - 1) {
+                BridgeEvent::ProofOfMint(proof_of_mint) => {
+                    assert_eq!(proof_of_mint.burn_proof_hash, keccak_hash);
+                    assert_eq!(proof_of_mint.hash_algorithm, HashAlgorithm::Keccak256 as u8);
+                }
+                event => panic!("unexpected event {:?}", event),
+            }
+        }
+
+        // burn side
+        {
+            deployed.burn_with_session(
+                token,
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(7)),
+                ChainType::Evm as u8,
+                1337,
+                400.into(),
+                None,
+                true,
+            );
+
+            let burn_proof = BurnProof {
+                proof_version: PROOF_VERSION,
+                mint_caller: Bytes::from(evm_address(7)),
+                burn_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+                mint_token: Bytes::from(evm_address(2)),
+                burn_token: generic_token.clone(),
+                burn_amount: 400.into(),
+                mint_chain_type: ChainType::Evm as u8,
+                mint_chain_id: 1337,
+                burn_chain_type: ChainType::Casper as u8,
+                burn_chain_id: 1010,
+                burn_nonce: 0.into(),
+                burn_deadline: 0,
+                mint_bridge: Bytes::from(remote_bridge.clone()),
+                burn_bridge: Bytes::from(bridge.clone()),
+            }
+            .hash(HashAlgorithm::Keccak256)
+            .unwrap();
+
+            let proof_hash = deployed.session_burn_proof_hash();
+            assert_eq!(proof_hash, U256::from_big_endian(&burn_proof));
+
+            match deployed.get_event(deployed.events_length() - 1) {
+                BridgeEvent::ProofOfBurn(proof_of_burn) => {
+                    assert_eq!(proof_of_burn.burn_proof_hash, proof_hash);
+                    assert_eq!(proof_of_burn.hash_algorithm, HashAlgorithm::Keccak256 as u8);
+                }
+                event => panic!("unexpected event {:?}", event),
+            }
+
+            assert_eq!(
+                deployed.get_burn_proof_status(&u256_to_hex(&proof_hash)),
+                Some(States::Burned as u8)
+            );
+        }
     }
 
     // TODO: update tests
@@ -64,20 +2143,36 @@ mod tests {
     fn tester() {
         let mut deployed = TestFixture::deploy();
 
-        let burn_proof_storage_uref = deployed.get_burn_proof_storage_uref();
         let token = deployed.token_contract_hash();
 
+        // this bridge and arbitary evm bridge
+        let bridge = pad_with_tag(
+            PACKAGE_TAG,
+            deployed.contract_package_hash().value().to_vec(),
+        );
+        let remote_bridge = evm_address(3);
+
+        deployed.set_chain(
+            ChainType::Evm as u8,
+            1337,
+            20,
+            "kovan",
+            Bytes::from(remote_bridge.clone()),
+            true,
+        );
+
         {
             {
                 let mint_chain_type = ChainType::Casper as u8;
                 let mint_chain_id = 1010 as u32;
-                let mint_token = Bytes::from(pad_with_8_bytes(
+                let mint_token = Bytes::from(pad_with_tag(
+                    CONTRACT_TAG,
                     deployed.token_contract_hash().as_bytes().to_vec(),
                 ));
 
                 let burn_chain_type = ChainType::Evm as u8;
                 let burn_chain_id = 1337 as u32;
-                let burn_token = Bytes::from(vec![2; 40]);
+                let burn_token = Bytes::from(evm_address(2));
 
                 deployed.set_allowance(
                     mint_token.clone(),
@@ -103,11 +2198,8 @@ mod tests {
             // }
         }
 
-        println!("burn_proof_storage_uref {:#?}", burn_proof_storage_uref);
-
         let account = deployed.account();
-        println!("account {:#?}", account.to_formatted_string());
-        println!("account {:#?}", account.to_string());
+        assert_eq!(account, TestFixture::account_hashes().0);
 
         {
             let total_supply = deployed.total_supply();
@@ -116,13 +2208,12 @@ mod tests {
 
         // direct mint for initial supply
         let _ = deployed.mint(U256::from(1_000_000), account);
-        println!("minted!");
+        assert_eq!(deployed.total_supply(), 1_000_000.into());
 
         // owner
         {
             let account_balance = deployed.get_balance(&account.to_string());
             assert!(account_balance == 1_000_000.into());
-            println!("account_balance {:#?}", account_balance);
         }
 
         // account not found
@@ -131,46 +2222,33 @@ mod tests {
 
             let account_balance = deployed.get_balance(&account.to_string());
             assert!(account_balance == 0.into());
-
-            println!("account_balance {:#?}", account_balance);
         }
 
         // let token_contract_hash = deployed.token_contract_hash();
 
         // tx hash with arbitary amount
         // imagine it was sent from validator (from evm network)
-        let burn_proof = {
-            let mint_caller_bytes = pad_with_8_bytes(account.as_bytes().to_vec());
-            let burn_caller_bytes = vec![1; 40]; // arbitary address
-
-            let mint_token_bytes =
-                pad_with_8_bytes(deployed.token_contract_hash().as_bytes().to_vec());
-            let burn_token_bytes = vec![2; 40]; // arbitary token
-
-            let burn_amount_bytes = u256_to_bytes(&50_555.into());
-
-            let mint_chain_type_bytes = (ChainType::Casper as u8).to_be_bytes().to_vec();
-            let mint_chain_id_bytes = (1010 as u32).to_be_bytes().to_vec(); // 1010 as test chain id for casper
-
-            let burn_chain_type_bytes = (ChainType::Evm as u8).to_be_bytes().to_vec();
-            let burn_chain_id_bytes = (1337 as u32).to_be_bytes().to_vec(); // kovan
-
-            let burn_nonce_bytes = u256_to_bytes(&1337.into());
-
-            #[rustfmt::skip]
-            let data = merge_bytes(vec![
-                mint_caller_bytes, burn_caller_bytes,
-                mint_token_bytes, burn_token_bytes,
-                burn_amount_bytes,
-                mint_chain_type_bytes, mint_chain_id_bytes,
-                burn_chain_type_bytes, burn_chain_id_bytes,
-                burn_nonce_bytes
-            ]);
-
-            let burn_proof = sha256(&data);
-
-            burn_proof
-        };
+        let burn_proof = BurnProof {
+            proof_version: PROOF_VERSION,
+            mint_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+            burn_caller: Bytes::from(evm_address(1)), // arbitary address
+            mint_token: Bytes::from(pad_with_tag(
+                CONTRACT_TAG,
+                deployed.token_contract_hash().as_bytes().to_vec(),
+            )),
+            burn_token: Bytes::from(evm_address(2)), // arbitary token
+            burn_amount: 50_555.into(),
+            mint_chain_type: ChainType::Casper as u8,
+            mint_chain_id: 1010, // 1010 as test chain id for casper
+            burn_chain_type: ChainType::Evm as u8,
+            burn_chain_id: 1337, // kovan
+            burn_nonce: 1337.into(),
+            burn_deadline: 0, // never expires
+            mint_bridge: Bytes::from(bridge.clone()),
+            burn_bridge: Bytes::from(remote_bridge.clone()),
+        }
+        .hash(HashAlgorithm::Sha256)
+        .unwrap();
 
         // approve specific hash
         let _ = deployed.approve_burn_proof(U256::from_big_endian(&burn_proof), true);
@@ -179,44 +2257,44 @@ mod tests {
             let str_tx_hash = encode_hex(&burn_proof);
             let some = deployed
                 .get_burn_proof_status(&str_tx_hash)
-                .map(States::from);
+                .map(|v| States::try_from(v).unwrap());
 
             assert!(some == Some(States::Approved));
         }
 
-        println!("transaction from validator approved!");
-
         let mut minter_fn = |is_ok: bool| {
             deployed.mint_with_burn_proof(
                 token,
-                Bytes::from(vec![2; 40]),
-                Bytes::from(vec![1; 40]),
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(1)),
                 ChainType::Evm as u8,
                 1337,
                 U256::from_big_endian(&burn_proof),
                 50_555.into(),
                 1337.into(),
+                0,
                 is_ok,
             )
         };
 
         minter_fn(true); // mint once
         minter_fn(false); // cant mint twice
-
-        println!("transaction from validator minted once!");
+        assert_eq!(
+            deployed.get_burn_proof_status(&encode_hex(&burn_proof)),
+            Some(States::Executed as u8)
+        );
 
         // owner
         {
             let account_balance = deployed.get_balance(&account.to_string());
             assert!(account_balance == 1_050_555.into());
-            println!("account_balance {:#?}", account_balance);
         }
 
         // amount exceeded
         {
             let burn_token = token;
-            let mint_token = Bytes::from(vec![2; 40]);
-            let mint_caller = Bytes::from(vec![7; 40]);
+            let mint_token = Bytes::from(evm_address(2));
+            let mint_caller = Bytes::from(evm_address(7));
             let burn_amount = U256::from(2_000_001);
             let mint_chain_type = ChainType::Evm as u8;
             let mint_chain_id = 1337 as u32;
@@ -235,8 +2313,8 @@ mod tests {
         // ok
         {
             let burn_token = token;
-            let mint_token = Bytes::from(vec![2; 40]);
-            let mint_caller = Bytes::from(vec![7; 40]);
+            let mint_token = Bytes::from(evm_address(2));
+            let mint_caller = Bytes::from(evm_address(7));
             let burn_amount = U256::from(1000);
             let mint_chain_type = ChainType::Evm as u8;
             let mint_chain_id = 1337 as u32;
@@ -251,7 +2329,10 @@ mod tests {
                 true,
             );
         }
-        println!("proof created!");
+        assert_eq!(
+            deployed.get_event(deployed.events_length() - 1).name(),
+            "ProofOfBurn"
+        );
 
         // owner
         {
@@ -261,54 +2342,45 @@ mod tests {
 
         {
             // check nonce and supply again
-            let nonce = deployed.get_nonce_by_token(token);
+            let nonce = deployed.get_nonce(&pad_with_8_bytes(account.as_bytes().to_vec()));
             let total_supply = deployed.total_supply();
 
             assert!(nonce == 1.into());
             assert!(total_supply == (1_000_000 + 50_555 - 1_000).into());
+
+            // nonces are counted per burn caller
+            assert!(deployed.get_nonce(&evm_address(7)) == 0.into());
         }
 
         // calculate hash
-        let burn_proof = {
-            let mint_caller_bytes = vec![7; 40];
-            let burn_caller_bytes = pad_with_8_bytes(account.as_bytes().to_vec());
-
-            let mint_token_bytes = vec![2; 40];
-
-            let burn_token_bytes =
-                pad_with_8_bytes(deployed.token_contract_hash().as_bytes().to_vec());
-
-            let burn_amount_bytes = u256_to_bytes(&1_000.into());
-
-            let mint_chain_type_bytes = (ChainType::Evm as u8).to_be_bytes().to_vec();
-            let mint_chain_id_bytes = (1337 as u32).to_be_bytes().to_vec(); // 1010 as test chain id for casper
-
-            let burn_chain_type_bytes = (ChainType::Casper as u8).to_be_bytes().to_vec();
-            let burn_chain_id_bytes = (1010 as u32).to_be_bytes().to_vec(); // kovan
-
-            let burn_nonce_bytes = u256_to_bytes(&0.into());
-
-            #[rustfmt::skip]
-            let data = merge_bytes(vec![
-                mint_caller_bytes, burn_caller_bytes,
-                mint_token_bytes, burn_token_bytes,
-                burn_amount_bytes,
-                mint_chain_type_bytes, mint_chain_id_bytes,
-                burn_chain_type_bytes, burn_chain_id_bytes,
-                burn_nonce_bytes
-            ]);
-
-            let burn_proof = sha256(&data);
-
-            burn_proof
-        };
+        let burn_proof = BurnProof {
+            proof_version: PROOF_VERSION,
+            mint_caller: Bytes::from(evm_address(7)),
+            burn_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+            mint_token: Bytes::from(evm_address(2)),
+            burn_token: Bytes::from(pad_with_tag(
+                CONTRACT_TAG,
+                deployed.token_contract_hash().as_bytes().to_vec(),
+            )),
+            burn_amount: 1_000.into(),
+            mint_chain_type: ChainType::Evm as u8,
+            mint_chain_id: 1337,
+            burn_chain_type: ChainType::Casper as u8,
+            burn_chain_id: 1010, // 1010 as test chain id for casper
+            burn_nonce: 0.into(),
+            burn_deadline: 0, // no route ttl
+            mint_bridge: Bytes::from(remote_bridge.clone()),
+            burn_bridge: Bytes::from(bridge.clone()),
+        }
+        .hash(HashAlgorithm::Sha256)
+        .unwrap();
 
         // should be burned
         {
             let str_tx_hash = encode_hex(&burn_proof);
             let some: Option<States> = deployed
                 .get_burn_proof_status(&str_tx_hash)
-                .map(States::from);
+                .map(|v| States::try_from(v).unwrap());
 
             assert!(some == Some(States::Burned));
         }
@@ -322,35 +2394,121 @@ mod tests {
             let str_tx_hash = encode_hex(&burn_proof);
             let some: Option<States> = deployed
                 .get_burn_proof_status(&str_tx_hash)
-                .map(States::from);
+                .map(|v| States::try_from(v).unwrap());
             assert!(some == Some(States::Burned));
         }
 
-        println!("burned transaction not approved!");
-
         // should not be minted
         let _ = deployed.mint_with_burn_proof(
             token,
-            Bytes::from(vec![2; 40]),
+            Bytes::from(evm_address(2)),
             Bytes::from(vec![1, 40]),
             1,
             1337,
             U256::from_big_endian(&burn_proof),
             1_000.into(),
             0.into(),
+            0,
             false,
         );
 
-        println!("burned transaction not minted!");
-
         {
             // check nonce and supply again
-            let nonce = deployed.get_nonce_by_token(token);
+            let nonce = deployed.get_nonce(&pad_with_8_bytes(account.as_bytes().to_vec()));
             let total_supply = deployed.total_supply();
 
             assert!(nonce == 1.into());
             assert!(total_supply == (1_000_000 + 50_555 - 1_000).into());
         }
+
+        // refund is locked until timeout passes
+        let _ = deployed.refund_burn_proof(U256::from_big_endian(&burn_proof), false);
+        assert_eq!(deployed.last_error(), Some(Error::RefundLocked));
+
+        deployed.set_refund_timeout(0, true);
+
+        // admin decision, no attestation needed
+        let _ = deployed.refund_burn_proof(U256::from_big_endian(&burn_proof), true);
+
+        {
+            let str_tx_hash = encode_hex(&burn_proof);
+            let some: Option<States> = deployed
+                .get_burn_proof_status(&str_tx_hash)
+                .map(|v| States::try_from(v).unwrap());
+            assert!(some == Some(States::Refunded));
+        }
+
+        // cant refund twice
+        let _ = deployed.refund_burn_proof(U256::from_big_endian(&burn_proof), false);
+        assert_eq!(deployed.last_error(), Some(Error::ProofNotBurned));
+
+        {
+            let account_balance = deployed.get_balance(&account.to_string());
+            let total_supply = deployed.total_supply();
+
+            assert!(account_balance == (1_000_000 + 50_555).into());
+            assert!(total_supply == (1_000_000 + 50_555).into());
+        }
+
+        // destination side cancellation
+        {
+            let proof_hash = U256::from(42);
+
+            deployed.cancel_burn_proof(proof_hash, true);
+
+            // cancelled proof can't be approved anymore
+            deployed.approve_burn_proof(proof_hash, false);
+            deployed.cancel_burn_proof(proof_hash, false);
+
+            let some: Option<States> = deployed
+                .get_burn_proof_status(&u256_to_hex(&proof_hash))
+                .map(|v| States::try_from(v).unwrap());
+            assert!(some == Some(States::Cancelled));
+        }
+
+        // burn from session, account gets the proof hash back
+        {
+            deployed.burn_with_session(
+                token,
+                Bytes::from(evm_address(2)),
+                Bytes::from(evm_address(7)),
+                ChainType::Evm as u8,
+                1337,
+                1_000.into(),
+                None,
+                true,
+            );
+
+            let burn_proof = BurnProof {
+                proof_version: PROOF_VERSION,
+                mint_caller: Bytes::from(evm_address(7)),
+                burn_caller: Bytes::from(pad_with_8_bytes(account.as_bytes().to_vec())),
+                mint_token: Bytes::from(evm_address(2)),
+                burn_token: Bytes::from(pad_with_tag(
+                    CONTRACT_TAG,
+                    deployed.token_contract_hash().as_bytes().to_vec(),
+                )),
+                burn_amount: 1_000.into(),
+                mint_chain_type: ChainType::Evm as u8,
+                mint_chain_id: 1337,
+                burn_chain_type: ChainType::Casper as u8,
+                burn_chain_id: 1010,
+                burn_nonce: 1.into(),
+                burn_deadline: 0,
+                mint_bridge: Bytes::from(remote_bridge.clone()),
+                burn_bridge: Bytes::from(bridge.clone()),
+            }
+            .hash(HashAlgorithm::Sha256)
+            .unwrap();
+
+            let proof_hash = deployed.session_burn_proof_hash();
+            assert_eq!(proof_hash, U256::from_big_endian(&burn_proof));
+
+            let some: Option<States> = deployed
+                .get_burn_proof_status(&u256_to_hex(&proof_hash))
+                .map(|v| States::try_from(v).unwrap());
+            assert!(some == Some(States::Burned));
+        }
     }
 }
 
@@ -1,5 +1,8 @@
-use crate::shared::encode_hex;
-use std::{path::PathBuf, rc::Rc};
+use bridge_core::{
+    get_nonce_key, BridgeEvent, Error, MintRequest, Route, BRIDGE_EVENTS_COUNT, BRIDGE_EVENTS_DICT,
+    EVENTS_DICT, EVENTS_LENGTH,
+};
+use std::{convert::TryFrom, path::PathBuf, rc::Rc};
 
 use casper_engine_test_support::{
     DeployItemBuilder, ExecuteRequestBuilder, InMemoryWasmTestBuilder, WasmTestBuilder, ARG_AMOUNT,
@@ -7,27 +10,67 @@ use casper_engine_test_support::{
     DEFAULT_GENESIS_CONFIG_HASH, DEFAULT_PAYMENT, DEFAULT_RUN_GENESIS_REQUEST,
 };
 use casper_execution_engine::{
-    core::engine_state::{run_genesis_request::RunGenesisRequest, ExecutionResult, GenesisAccount},
+    core::{
+        engine_state::{
+            self, run_genesis_request::RunGenesisRequest, ExecutionResult, GenesisAccount,
+        },
+        execution,
+    },
+    shared::{stored_value::StoredValue, transform::Transform},
     storage::global_state::in_memory::InMemoryGlobalState,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::Bytes, runtime_args, ContractHash, ContractPackage, Key,
-    Motes, PublicKey, RuntimeArgs, SecretKey, URef, U256, U512,
+    account::AccountHash,
+    bytesrepr::{self, Bytes, FromBytes},
+    runtime_args, CLTyped, ContractHash, ContractPackage, ContractPackageHash, Key, Motes,
+    PublicKey, RuntimeArgs, SecretKey, URef, U256, U512,
 };
 
 pub struct TestFixture {
     builder: WasmTestBuilder<InMemoryGlobalState>,
     account: AccountHash,
     account_2: AccountHash,
+    // block time (ms) of the following deploys, 0 by default
+    block_time: u64,
 }
 
 const MY_ACCOUNT: [u8; 32] = [7u8; 32];
 const MY_ACCOUNT_2: [u8; 32] = [6u8; 32];
 const CONTRACT_WASM: &str = "contract.wasm";
 const TOKEN_CONTRACT_WASM: &str = "erc20-contract.wasm";
+const BURN_SESSION_WASM: &str = "burn_session.wasm";
+const PROXY_CONTRACT_WASM: &str = "proxy_contract.wasm";
+const VIEW_SESSION_WASM: &str = "view_session.wasm";
 
 impl TestFixture {
     pub fn deploy() -> Self {
+        Self::deploy_with_args(runtime_args! {})
+    }
+
+    // (account, account_2), known before deploy for install args
+    pub fn account_hashes() -> (AccountHash, AccountHash) {
+        let account_hash = |bytes| {
+            let secret_key = SecretKey::ed25519_from_bytes(bytes).unwrap();
+            AccountHash::from(&PublicKey::from(&secret_key))
+        };
+
+        (account_hash(MY_ACCOUNT), account_hash(MY_ACCOUNT_2))
+    }
+
+    // install args of the bridge contract, e.g. "hash_algorithm"
+    pub fn deploy_with_args(bridge_args: RuntimeArgs) -> Self {
+        Self::deploy_wasm(CONTRACT_WASM, bridge_args)
+    }
+
+    // contract of an older schema version, built by `make build-legacy-contracts`
+    pub fn deploy_legacy(schema_version: u32) -> Self {
+        Self::deploy_wasm(
+            &format!("contract_schema_{}.wasm", schema_version),
+            runtime_args! {},
+        )
+    }
+
+    fn deploy_wasm(contract_wasm: &str, bridge_args: RuntimeArgs) -> Self {
         let secret_key = SecretKey::ed25519_from_bytes(MY_ACCOUNT).unwrap();
         let public_key = PublicKey::from(&secret_key);
         let account_addr = AccountHash::from(&public_key);
@@ -79,7 +122,7 @@ impl TestFixture {
         // let token_contract_hash = wrapped.token_contract_hash();
 
         let execute_request =
-            ExecuteRequestBuilder::standard(account_addr, CONTRACT_WASM, runtime_args! {}).build();
+            ExecuteRequestBuilder::standard(account_addr, contract_wasm, bridge_args).build();
 
         // deploy the contract.
         builder.exec(execute_request).commit().expect_success();
@@ -88,19 +131,30 @@ impl TestFixture {
             account: account_addr,
             account_2: account_addr_2,
             builder: builder,
+            block_time: 0,
         }
     }
 
+    pub fn set_block_time(&mut self, block_time: u64) {
+        self.block_time = block_time;
+    }
+
     pub fn mint(&mut self, amount: U256, to: AccountHash) {
+        self.mint_to(amount, Key::from(to))
+    }
+
+    // any token holder, e.g. a contract package
+    pub fn mint_to(&mut self, amount: U256, to: Key) {
         let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
             self.account,
             self.token_contract_hash().into(),
             "mint",
             runtime_args! {
                 "amount" => amount,
-                "to" => Key::from(to),
+                "to" => to,
             },
         )
+        .with_block_time(self.block_time)
         .build();
 
         let tx = self.builder.exec(execute_request).commit();
@@ -161,6 +215,24 @@ impl TestFixture {
         *uref
     }
 
+    pub fn bridge_allowances_uref(&self) -> URef {
+        let some = self.builder.query(
+            None,
+            Key::Account(self.account),
+            &["bridge_contract_hash".to_string()],
+        );
+
+        let some = some.unwrap();
+
+        let some = some.as_contract().unwrap().named_keys();
+        let uref = match some.get("allowances").unwrap() {
+            Key::URef(uref) => uref,
+            _ => panic!(),
+        };
+
+        *uref
+    }
+
     pub fn token_contract_hash(&self) -> ContractHash {
         let token_contract_hash = self
             .builder
@@ -190,19 +262,92 @@ impl TestFixture {
 
         is_ok: bool,
     ) {
+        self.set_allowance_with_ttl(
+            mint_token,
+            burn_token,
+            mint_chain_type,
+            mint_chain_id,
+            burn_chain_type,
+            burn_chain_id,
+            None,
+            is_ok,
+        )
+    }
+
+    // route ttl (ms) is applied to burns without a deadline
+    pub fn set_allowance_with_ttl(
+        &mut self,
+        mint_token: Bytes,
+        burn_token: Bytes,
+        mint_chain_type: u8,
+        mint_chain_id: u32,
+        burn_chain_type: u8,
+        burn_chain_id: u32,
+        default_ttl: Option<u64>,
+
+        is_ok: bool,
+    ) {
+        let mut args = runtime_args! {
+            "mint_token" =>  mint_token,
+            "burn_token" =>  burn_token,
+            "mint_chain_type" => mint_chain_type,
+            "mint_chain_id" => mint_chain_id,
+            "burn_chain_type" => burn_chain_type,
+            "burn_chain_id" => burn_chain_id,
+        };
+
+        if let Some(default_ttl) = default_ttl {
+            args.insert("default_ttl", default_ttl).unwrap();
+        }
+
         let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
             self.account,
             self.contract_hash().into(),
             "set_allowance",
+            args,
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn mint_with_burn_proof(
+        &mut self,
+        mint_token: ContractHash,
+        burn_token: Bytes,
+        burn_caller: Bytes,
+        burn_chain_type: u8,
+        burn_chain_id: u32,
+        burn_proof_hash: U256,
+        burn_amount: U256,
+        burn_nonce: U256,
+        burn_deadline: u64,
+        is_ok: bool,
+    ) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "mint_with_burn_proof",
             runtime_args! {
-                "mint_token" =>  mint_token,
-                "burn_token" =>  burn_token,
-                "mint_chain_type" => mint_chain_type,
-                "mint_chain_id" => mint_chain_id,
+                "mint_token" => mint_token,
+                "burn_token" => burn_token,
+                "burn_caller" => burn_caller,
                 "burn_chain_type" => burn_chain_type,
                 "burn_chain_id" => burn_chain_id,
+                "burn_amount" => burn_amount,
+                "burn_proof_hash" => burn_proof_hash,
+                "burn_nonce" => burn_nonce,
+                "burn_deadline" => burn_deadline,
             },
         )
+        .with_block_time(self.block_time)
         .build();
 
         let tx = self.builder.exec(execute_request).commit();
@@ -214,7 +359,28 @@ impl TestFixture {
         }
     }
 
-    pub fn mint_with_burn_proof(
+    pub fn mint_with_burn_proofs(&mut self, requests: Vec<MintRequest>, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "mint_with_burn_proofs",
+            runtime_args! {
+                "requests" => requests,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn mint_with_merkle_proof(
         &mut self,
         mint_token: ContractHash,
         burn_token: Bytes,
@@ -224,12 +390,15 @@ impl TestFixture {
         burn_proof_hash: U256,
         burn_amount: U256,
         burn_nonce: U256,
+        burn_deadline: u64,
+        epoch: u64,
+        merkle_path: Vec<Bytes>,
         is_ok: bool,
     ) {
         let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
             self.account,
             self.contract_hash().into(),
-            "mint_with_burn_proof",
+            "mint_with_merkle_proof",
             runtime_args! {
                 "mint_token" => mint_token,
                 "burn_token" => burn_token,
@@ -239,8 +408,12 @@ impl TestFixture {
                 "burn_amount" => burn_amount,
                 "burn_proof_hash" => burn_proof_hash,
                 "burn_nonce" => burn_nonce,
+                "burn_deadline" => burn_deadline,
+                "epoch" => epoch,
+                "merkle_path" => merkle_path,
             },
         )
+        .with_block_time(self.block_time)
         .build();
 
         let tx = self.builder.exec(execute_request).commit();
@@ -276,6 +449,7 @@ impl TestFixture {
                 "burn_amount" => burn_amount,
             },
         )
+        .with_block_time(self.block_time)
         .build();
 
         let tx = self.builder.exec(execute_request).commit();
@@ -287,16 +461,38 @@ impl TestFixture {
         }
     }
 
-    pub fn approve_burn_proof(&mut self, proof_hash: U256, is_ok: bool) {
-        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
-            self.account,
-            self.contract_hash().into(),
-            "approve_burn_proof",
-            runtime_args! {
-              "proof_hash" => proof_hash,
-            },
-        )
-        .build();
+    // same as `burn_and_create_proof`, but proof hash is kept under account named key
+    pub fn burn_with_session(
+        &mut self,
+        burn_token: ContractHash,
+        mint_token: Bytes,
+        mint_caller: Bytes,
+        mint_chain_type: u8,
+        mint_chain_id: u32,
+        burn_amount: U256,
+        deadline: Option<u64>,
+
+        is_ok: bool,
+    ) {
+        let mut args = runtime_args! {
+            "bridge_package_hash" => self.contract_package_hash(),
+            "burn_token" => burn_token,
+            "mint_token" => mint_token,
+            "mint_caller" => mint_caller,
+            "mint_chain_type" => mint_chain_type,
+            "mint_chain_id" => mint_chain_id,
+            "burn_amount" => burn_amount,
+        };
+
+        // left out, not passed as none
+        if let Some(deadline) = deadline {
+            args.insert("deadline", deadline).unwrap();
+        }
+
+        let execute_request =
+            ExecuteRequestBuilder::standard(self.account, BURN_SESSION_WASM, args)
+                .with_block_time(self.block_time)
+                .build();
 
         let tx = self.builder.exec(execute_request).commit();
 
@@ -307,95 +503,764 @@ impl TestFixture {
         }
     }
 
-    pub fn account(&self) -> AccountHash {
-        self.account
+    // stored by the burn session
+    pub fn session_burn_proof_hash(&self) -> U256 {
+        self.builder
+            .query(
+                None,
+                Key::Account(self.account),
+                &["burn_proof_hash".to_string()],
+            )
+            .expect("should be stored value")
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t::<U256>()
+            .expect("should be U256")
     }
 
-    pub fn get_balance(&self, account: &str) -> U256 {
-        let balances_uref = self.token_balances_uref();
+    // stored contract that forwards burns to the bridge, see session/src/proxy.rs
+    pub fn deploy_proxy(&mut self) -> ContractPackageHash {
+        let execute_request =
+            ExecuteRequestBuilder::standard(self.account, PROXY_CONTRACT_WASM, runtime_args! {})
+                .with_block_time(self.block_time)
+                .build();
 
-        let balance = self
-            .builder
-            .query_dictionary_item(None, balances_uref, account);
+        self.builder.exec(execute_request).commit().expect_success();
 
-        // account not found
-        if balance.is_err() {
-            return 0.into();
+        let key = *self
+            .builder
+            .get_account(self.account)
+            .expect("should be account")
+            .named_keys()
+            .get("proxy_package_hash")
+            .expect("should be package key");
+
+        match key {
+            Key::Hash(addr) => ContractPackageHash::new(addr),
+            _ => panic!(),
         }
-
-        let balance = balance
-            .expect("should be stored value.")
-            .as_cl_value()
-            .expect("should be cl value.")
-            .clone()
-            .into_t::<Option<U256>>()
-            .expect("should be");
-
-        balance.unwrap()
     }
 
-    pub fn get_nonce_by_token(&self, token_hash: ContractHash) -> U256 {
-        let nonces_uref = self.bridge_nonces_uref();
-        let token_hex = encode_hex(&token_hash.as_bytes().to_vec());
+    // burns the tokens of the proxy package, the bridge is called by a contract
+    pub fn burn_with_proxy(
+        &mut self,
+        proxy_package_hash: ContractPackageHash,
+        burn_token: ContractHash,
+        mint_token: Bytes,
+        mint_caller: Bytes,
+        mint_chain_type: u8,
+        mint_chain_id: u32,
+        burn_amount: U256,
+        deadline: Option<u64>,
 
-        let nonce = self
-            .builder
-            .query_dictionary_item(None, nonces_uref, &token_hex);
+        is_ok: bool,
+    ) {
+        let mut args = runtime_args! {
+            "bridge_package_hash" => self.contract_package_hash(),
+            "burn_token" => burn_token,
+            "mint_token" => mint_token,
+            "mint_caller" => mint_caller,
+            "mint_chain_type" => mint_chain_type,
+            "mint_chain_id" => mint_chain_id,
+            "burn_amount" => burn_amount,
+        };
 
-        // token not found
-        if nonce.is_err() {
-            return 0.into();
+        if let Some(deadline) = deadline {
+            args.insert("deadline", deadline).unwrap();
         }
 
-        let nonce = nonce
-            .expect("should be stored value.")
-            .as_cl_value()
-            .expect("should be cl value.")
-            .clone()
-            .into_t::<Option<U256>>()
-            .expect("should be");
+        let execute_request = ExecuteRequestBuilder::versioned_contract_call_by_hash(
+            self.account,
+            proxy_package_hash,
+            None,
+            "burn",
+            args,
+        )
+        .with_block_time(self.block_time)
+        .build();
 
-        nonce.unwrap()
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
     }
 
-    pub fn get_burn_proof_status(&self, tx_hash: &str) -> Option<u8> {
-        let burn_proof_storage_uref = self.get_burn_proof_storage_uref();
+    pub fn approve_burn_proof(&mut self, proof_hash: U256, is_ok: bool) {
+        self.approve_burn_proof_as(self.account, proof_hash, is_ok)
+    }
 
-        let tx_hash = self
-            .builder
-            .query_dictionary_item(None, burn_proof_storage_uref, tx_hash);
+    pub fn approve_burn_proof_as(&mut self, caller: AccountHash, proof_hash: U256, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            caller,
+            self.contract_hash().into(),
+            "approve_burn_proof",
+            runtime_args! {
+              "proof_hash" => proof_hash,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
 
-        // tx hash not found
-        if tx_hash.is_err() {
-            return None;
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
         }
+    }
 
-        let tx_hash = tx_hash
-            .expect("should be stored value.")
-            .as_cl_value()
-            .expect("should be cl value.")
-            .clone()
-            .into_t::<Option<u8>>()
-            .expect("should be");
+    pub fn approve_burn_proofs(
+        &mut self,
+        proof_hashes: Vec<U256>,
+        skip_approved: bool,
+        is_ok: bool,
+    ) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "approve_burn_proofs",
+            runtime_args! {
+                "proof_hashes" => proof_hashes,
+                "skip_approved" => skip_approved,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
 
-        tx_hash
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
     }
 
-    pub fn total_supply(&self) -> U256 {
-        self.builder
-            .query(
-                None,
-                self.token_contract_hash().into(),
-                &["total_supply".to_string()],
-            )
-            .expect("should be stored value")
-            .as_cl_value()
+    pub fn approve_merkle_root(&mut self, root: Bytes, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "approve_merkle_root",
+            runtime_args! {
+                "root" => root,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn cancel_burn_proof(&mut self, proof_hash: U256, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "cancel_burn_proof",
+            runtime_args! {
+              "proof_hash" => proof_hash,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn attest_burn_cancellation(&mut self, proof_hash: U256, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "attest_burn_cancellation",
+            runtime_args! {
+              "proof_hash" => proof_hash,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn refund_burn_proof(&mut self, proof_hash: U256, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "refund_burn_proof",
+            runtime_args! {
+              "proof_hash" => proof_hash,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn set_chain(
+        &mut self,
+        chain_type: u8,
+        chain_id: u32,
+        address_length: u8,
+        name: &str,
+        remote_bridge: Bytes,
+        is_ok: bool,
+    ) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "set_chain",
+            runtime_args! {
+                "chain_type" => chain_type,
+                "chain_id" => chain_id,
+                "address_length" => address_length,
+                "name" => name.to_string(),
+                "remote_bridge" => remote_bridge,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn set_chain_enabled(&mut self, chain_type: u8, chain_id: u32, enabled: bool, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "set_chain_enabled",
+            runtime_args! {
+                "chain_type" => chain_type,
+                "chain_id" => chain_id,
+                "enabled" => enabled,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn set_refund_timeout(&mut self, refund_timeout: u64, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "set_refund_timeout",
+            runtime_args! {
+              "refund_timeout" => refund_timeout,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn grant_role(&mut self, role: &str, account: AccountHash, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "grant_role",
+            runtime_args! {
+                "role" => role.to_string(),
+                "account" => account,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn revoke_role(&mut self, role: &str, account: AccountHash, is_ok: bool) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            self.contract_hash().into(),
+            "revoke_role",
+            runtime_args! {
+                "role" => role.to_string(),
+                "account" => account,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    // bridge view called by the view session, none if it reverted
+    pub fn view<T: CLTyped + FromBytes>(
+        &mut self,
+        entry_point: &str,
+        view_args: RuntimeArgs,
+    ) -> Option<T> {
+        let mut args = view_args;
+        args.insert("bridge_package_hash", self.contract_package_hash())
+            .unwrap();
+        args.insert("entry_point", entry_point.to_string()).unwrap();
+
+        let execute_request =
+            ExecuteRequestBuilder::standard(self.account, VIEW_SESSION_WASM, args)
+                .with_block_time(self.block_time)
+                .build();
+
+        self.builder.exec(execute_request).commit();
+
+        if self.builder.get_error().is_some() {
+            return None;
+        }
+
+        let result = self
+            .builder
+            .query(
+                None,
+                Key::Account(self.account),
+                &["view_result".to_string()],
+            )
+            .expect("should be stored value")
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t::<T>()
+            .expect("should be view result");
+
+        Some(result)
+    }
+
+    // reruns the install wasm, which adds a new version to the existing package
+    pub fn upgrade(&mut self, disable_previous: bool, is_ok: bool) {
+        self.upgrade_with_args(
+            runtime_args! {
+                "disable_previous" => disable_previous,
+            },
+            is_ok,
+        )
+    }
+
+    // routes of an unversioned install to carry over
+    pub fn upgrade_with_legacy_routes(&mut self, legacy_routes: Vec<Route>, is_ok: bool) {
+        self.upgrade_with_args(
+            runtime_args! {
+                "legacy_routes" => legacy_routes,
+            },
+            is_ok,
+        )
+    }
+
+    fn upgrade_with_args(&mut self, upgrade_args: RuntimeArgs, is_ok: bool) {
+        let execute_request =
+            ExecuteRequestBuilder::standard(self.account, CONTRACT_WASM, upgrade_args)
+                .with_block_time(self.block_time)
+                .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn set_refund_timeout_at(
+        &mut self,
+        contract_hash: ContractHash,
+        refund_timeout: u64,
+        is_ok: bool,
+    ) {
+        let execute_request = ExecuteRequestBuilder::contract_call_by_hash(
+            self.account,
+            contract_hash,
+            "set_refund_timeout",
+            runtime_args! {
+              "refund_timeout" => refund_timeout,
+            },
+        )
+        .with_block_time(self.block_time)
+        .build();
+
+        let tx = self.builder.exec(execute_request).commit();
+
+        if is_ok {
+            tx.expect_success();
+        } else {
+            tx.expect_failure();
+        }
+    }
+
+    pub fn account(&self) -> AccountHash {
+        self.account
+    }
+
+    pub fn account_2(&self) -> AccountHash {
+        self.account_2
+    }
+
+    pub fn get_balance(&self, account: &str) -> U256 {
+        let balances_uref = self.token_balances_uref();
+
+        let balance = self
+            .builder
+            .query_dictionary_item(None, balances_uref, account);
+
+        // account not found
+        if balance.is_err() {
+            return 0.into();
+        }
+
+        let balance = balance
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<Option<U256>>()
+            .expect("should be");
+
+        balance.unwrap()
+    }
+
+    pub fn get_nonce(&self, burn_caller: &[u8]) -> U256 {
+        let nonces_uref = self.bridge_nonces_uref();
+
+        let nonce =
+            self.builder
+                .query_dictionary_item(None, nonces_uref, &get_nonce_key(burn_caller));
+
+        // caller not found
+        if nonce.is_err() {
+            return 0.into();
+        }
+
+        let nonce = nonce
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<Option<U256>>()
+            .expect("should be");
+
+        nonce.unwrap()
+    }
+
+    pub fn get_burn_proof_status(&self, tx_hash: &str) -> Option<u8> {
+        let burn_proof_storage_uref = self.get_burn_proof_storage_uref();
+
+        let tx_hash = self
+            .builder
+            .query_dictionary_item(None, burn_proof_storage_uref, tx_hash);
+
+        // tx hash not found
+        if tx_hash.is_err() {
+            return None;
+        }
+
+        let tx_hash = tx_hash
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<Option<u8>>()
+            .expect("should be");
+
+        tx_hash
+    }
+
+    pub fn get_allowance(&self, allowance_hash: &str) -> Option<u8> {
+        let allowances_uref = self.bridge_allowances_uref();
+
+        let allowance = self
+            .builder
+            .query_dictionary_item(None, allowances_uref, allowance_hash);
+
+        // allowance not found
+        if allowance.is_err() {
+            return None;
+        }
+
+        allowance
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<Option<u8>>()
+            .expect("should be")
+    }
+
+    // raw dictionary item under a bridge named key, for keys the current version no longer derives
+    pub fn get_dict_item<T: CLTyped + FromBytes>(&self, dict: &str, key: &str) -> Option<T> {
+        let some = self.builder.query(
+            None,
+            Key::Account(self.account),
+            &["bridge_contract_hash".to_string()],
+        );
+
+        let some = some.unwrap();
+
+        let some = some.as_contract().unwrap().named_keys();
+        let dict_uref = match some.get(dict).unwrap() {
+            Key::URef(uref) => *uref,
+            _ => panic!(),
+        };
+
+        let item = self.builder.query_dictionary_item(None, dict_uref, key);
+
+        // key not found
+        if item.is_err() {
+            return None;
+        }
+
+        item.expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<Option<T>>()
+            .expect("should be")
+    }
+
+    pub fn get_role_holders(&self, role: &str) -> Vec<AccountHash> {
+        self.builder
+            .query(None, self.contract_hash().into(), &[role.to_string()])
+            .expect("should be stored value")
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t::<Vec<AccountHash>>()
+            .expect("should be account list")
+    }
+
+    pub fn events_length(&self) -> u32 {
+        self.builder
+            .query(
+                None,
+                self.contract_hash().into(),
+                &[EVENTS_LENGTH.to_string()],
+            )
+            .expect("should be stored value")
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32")
+    }
+
+    pub fn get_event(&self, index: u32) -> BridgeEvent {
+        let some = self.builder.query(
+            None,
+            Key::Account(self.account),
+            &["bridge_contract_hash".to_string()],
+        );
+
+        let some = some.unwrap();
+
+        let some = some.as_contract().unwrap().named_keys();
+        let events_uref = match some.get(EVENTS_DICT).unwrap() {
+            Key::URef(uref) => *uref,
+            _ => panic!(),
+        };
+
+        let bytes = self
+            .builder
+            .query_dictionary_item(None, events_uref, &index.to_string())
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<Bytes>()
+            .expect("should be bytes");
+
+        bytesrepr::deserialize(bytes.into()).expect("should be bridge event")
+    }
+
+    pub fn bridge_events_count(&self) -> u64 {
+        self.builder
+            .query(
+                None,
+                self.contract_hash().into(),
+                &[BRIDGE_EVENTS_COUNT.to_string()],
+            )
+            .expect("should be stored value")
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t::<u64>()
+            .expect("should be u64")
+    }
+
+    pub fn get_bridge_event(&self, index: u64) -> Option<Bytes> {
+        let some = self.builder.query(
+            None,
+            Key::Account(self.account),
+            &["bridge_contract_hash".to_string()],
+        );
+
+        let some = some.unwrap();
+
+        let some = some.as_contract().unwrap().named_keys();
+        let events_uref = match some.get(BRIDGE_EVENTS_DICT).unwrap() {
+            Key::URef(uref) => *uref,
+            _ => panic!(),
+        };
+
+        let event = self
+            .builder
+            .query_dictionary_item(None, events_uref, &index.to_string());
+
+        // index not reached yet
+        if event.is_err() {
+            return None;
+        }
+
+        let event = event
+            .expect("should be stored value.")
+            .as_cl_value()
+            .expect("should be cl value.")
+            .clone()
+            .into_t::<Bytes>()
+            .expect("should be bytes");
+
+        Some(event)
+    }
+
+    // event bytes the last deploy wrote to new urefs, where the indexer reads them
+    pub fn last_event_urefs(&self) -> Vec<Bytes> {
+        let exec_results: &Vec<Rc<ExecutionResult>> = self
+            .builder
+            .get_exec_results()
+            .last()
+            .expect("should be executed");
+
+        exec_results
+            .iter()
+            .flat_map(|result| {
+                result
+                    .effect()
+                    .transforms
+                    .iter()
+                    .filter_map(|(key, transform)| match (key, transform) {
+                        (Key::URef(_), Transform::Write(StoredValue::CLValue(value)))
+                            if *value.cl_type() == Bytes::cl_type() =>
+                        {
+                            value.clone().into_t::<Bytes>().ok()
+                        }
+                        _ => None,
+                    })
+            })
+            .collect()
+    }
+
+    // bridge error the last deploy reverted with
+    pub fn last_error(&self) -> Option<Error> {
+        match self.builder.get_error()? {
+            engine_state::Error::Exec(execution::Error::Revert(error)) => {
+                Error::try_from(error).ok()
+            }
+            _ => None,
+        }
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        self.builder
+            .query(
+                None,
+                self.contract_hash().into(),
+                &["schema_version".to_string()],
+            )
+            .expect("should be stored value")
+            .as_cl_value()
+            .expect("should be cl value")
+            .clone()
+            .into_t::<u32>()
+            .expect("should be u32")
+    }
+
+    pub fn total_supply(&self) -> U256 {
+        self.builder
+            .query(
+                None,
+                self.token_contract_hash().into(),
+                &["total_supply".to_string()],
+            )
+            .expect("should be stored value")
+            .as_cl_value()
             .expect("should be cl value")
             .clone()
             .into_t::<U256>()
             .expect("should be U256")
     }
 
+    pub fn contract_package_hash(&self) -> ContractPackageHash {
+        let key = *self
+            .builder
+            .get_account(self.account)
+            .expect("should be account")
+            .named_keys()
+            .get("bridge_package_hash")
+            .expect("should be package key");
+
+        match key {
+            Key::Hash(addr) => ContractPackageHash::new(addr),
+            _ => panic!(),
+        }
+    }
+
     pub fn contract_hash(&self) -> ContractHash {
         let some = self
             .builder
@@ -0,0 +1,458 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.13;
+
+import "@openzeppelin/contracts/access/AccessControlEnumerable.sol";
+
+interface IMintBurnBridgeToken {
+    function balanceOf(address account) external returns (uint256);
+
+    function mintByBridge(address to, uint256 amount) external;
+
+    function burnByBridge(address from, uint256 amount) external;
+}
+
+error InvalidCallerLength();
+error InvalidTokenLength();
+error AllowanceNotFound();
+error ProofIsNotApprovedOrAlreadyExecuted();
+error PackageIsInvalid();
+error ProvidedHashIsInvalid();
+error ReduceAmount();
+error InvalidDeadline();
+error ProofExpired();
+error RemoteBridgeNotFound();
+error InvalidBridgeLength();
+
+
+
+contract CrossChainBridge is AccessControlEnumerable {
+    event ProofOfBurn(
+        bytes mintToken,
+        bytes burnToken,
+        bytes mintCaller,
+        bytes burnCaller,
+        uint256 burnAmount,
+        uint256 burnNonce,
+        uint64 burnDeadline,
+        ChainType mintChainType,
+        uint32 mintChainId,
+        ChainType burnChainType,
+        uint32 burnChainId,
+        bytes32 burnProofHash
+    );
+
+    event ProofOfMint(
+        bytes mintToken,
+        bytes burnToken,
+        bytes mintCaller,
+        bytes burnCaller,
+        uint256 burnAmount,
+        ChainType mintChainType,
+        uint32 mintChainId,
+        ChainType burnChainType,
+        uint32 burnChainId,
+        bytes32 burnProofHash
+    );
+
+    event ApprovedBurnProof(bytes32 burnProofHash);
+
+    enum States {
+        DefaultValue,
+        Burned,
+        Approved,
+        Executed
+    }
+
+    enum ChainType {
+        Undefined,
+        Evm,
+        Casper,
+        Solana,
+        Radix
+    }
+
+    enum Allowance {
+        Undefined,
+        Allowed,
+        Blocked
+    }
+
+    // same as HashAlgorithm of the casper bridge, both sides of a route must agree
+    enum HashAlgorithm {
+        Sha256,
+        Keccak256
+    }
+
+    // everything a burn proof hash commits to, same as BurnProof of the casper bridge
+    struct BurnProof {
+        uint8 proofVersion;
+        bytes mintCaller;
+        bytes burnCaller;
+        bytes mintToken;
+        bytes burnToken;
+        uint256 burnAmount;
+        uint8 mintChainType;
+        uint32 mintChainId;
+        uint8 burnChainType;
+        uint32 burnChainId;
+        uint256 burnNonce;
+        // ms, 0 = never expires
+        uint64 burnDeadline;
+        bytes mintBridge;
+        bytes burnBridge;
+    }
+
+    bytes32 public constant ROLE_APPROVER = keccak256("ROLE_APPROVER");
+
+    // bump on any change of the preimage layout
+    uint8 public constant PROOF_VERSION = 1;
+    // 1 + 40 + 40 + 40 + 40 + 32 + 1 + 4 + 1 + 4 + 32 + 8 + 40 + 40
+    uint256 public constant PROOF_PREIMAGE_LENGTH = 323;
+
+    ChainType thisChainType;
+    HashAlgorithm public immutable hashAlgorithm;
+
+    // it's common storage for all chains (evm, casper, solana etc)
+    mapping(bytes32 => States) public burnProofStorage;
+    // per burn caller, uint256 as committed in the proof hash on every chain
+    mapping(address => uint256) public nonceByCaller;
+    mapping(bytes32 => Allowance) public allowances;
+    // generic address of the bridge deployed on a remote chain, by chain type and id
+    mapping(uint8 => mapping(uint32 => bytes)) public remoteBridges;
+
+    constructor(HashAlgorithm _hashAlgorithm) {
+        _setupRole(DEFAULT_ADMIN_ROLE, msg.sender);
+        _setupRole(ROLE_APPROVER, msg.sender);
+
+        thisChainType = ChainType.Evm;
+        hashAlgorithm = _hashAlgorithm;
+    }
+
+    function setRemoteBridge(
+        ChainType chainType,
+        uint32 chainId,
+        bytes memory remoteBridge
+    ) external onlyRole(DEFAULT_ADMIN_ROLE) {
+        if (remoteBridge.length != 40) {
+            revert InvalidBridgeLength();
+        }
+
+        remoteBridges[uint8(chainType)][chainId] = remoteBridge;
+    }
+
+    function setAllowance(
+        bytes memory mintGenericToken,
+        bytes memory burnGenericToken,
+        ChainType mintChainType,
+        uint32 mintChainId,
+        ChainType burnChainType,
+        uint32 burnChainId
+    ) external onlyRole(ROLE_APPROVER) {
+        bytes32 allowanceHash = getAllowanceHash(
+            mintGenericToken,
+            burnGenericToken,
+            mintChainType,
+            mintChainId,
+            burnChainType,
+            burnChainId
+        );
+
+        allowances[allowanceHash] = Allowance.Allowed;
+    }
+
+    function digest(HashAlgorithm algorithm, bytes memory data)
+        private
+        pure
+        returns (bytes32)
+    {
+        if (algorithm == HashAlgorithm.Keccak256) {
+            return keccak256(data);
+        }
+
+        return sha256(data);
+    }
+
+    function getAllowanceHash(
+        bytes memory mintGenericToken,
+        bytes memory burnGenericToken,
+        ChainType mintChainType,
+        uint32 mintChainId,
+        ChainType burnChainType,
+        uint32 burnChainId
+    ) private view returns (bytes32) {
+        // chainType || chainId || token, same as casper bridge
+        // see packages/test-vectors/allowance_hash.json
+        bytes memory mintBytes = abi.encodePacked(
+            mintChainType,
+            mintChainId,
+            mintGenericToken
+        );
+        bytes memory burnBytes = abi.encodePacked(
+            burnChainType,
+            burnChainId,
+            burnGenericToken
+        );
+
+        if (digest(hashAlgorithm, mintBytes) > digest(hashAlgorithm, burnBytes)) {
+            return digest(hashAlgorithm, abi.encodePacked(mintBytes, burnBytes));
+        }
+
+        return digest(hashAlgorithm, abi.encodePacked(burnBytes, mintBytes));
+    }
+
+    // packed, big endian, see packages/test-vectors/burn_proof_hash.json
+    function getBurnProofPreimage(BurnProof memory proof)
+        public
+        pure
+        returns (bytes memory)
+    {
+        // two halves, stack too deep otherwise
+        // prettier-ignore
+        bytes memory callersAndTokens = abi.encodePacked(
+            proof.proofVersion,
+            proof.mintCaller, proof.burnCaller,
+            proof.mintToken, proof.burnToken,
+            proof.burnAmount
+        );
+
+        // prettier-ignore
+        bytes memory chainsAndBridges = abi.encodePacked(
+            proof.mintChainType, proof.mintChainId,
+            proof.burnChainType, proof.burnChainId,
+            proof.burnNonce,
+            proof.burnDeadline,
+            proof.mintBridge, proof.burnBridge
+        );
+
+        bytes memory packed = abi.encodePacked(callersAndTokens, chainsAndBridges);
+
+        if (packed.length != PROOF_PREIMAGE_LENGTH) {
+            revert PackageIsInvalid();
+        }
+
+        return packed;
+    }
+
+    function getBurnProofHash(BurnProof memory proof, HashAlgorithm algorithm)
+        public
+        pure
+        returns (bytes32)
+    {
+        return digest(algorithm, getBurnProofPreimage(proof));
+    }
+
+    // 40 bytes
+    function genericAddress(address some) private pure returns (bytes memory) {
+        bytes memory result = new bytes(40);
+        assembly {
+            mstore(add(result, 40), some)
+        }
+        return result;
+    }
+
+    function getRemoteBridge(uint8 chainType, uint32 chainId)
+        private
+        view
+        returns (bytes memory)
+    {
+        bytes memory remoteBridge = remoteBridges[chainType][chainId];
+
+        if (remoteBridge.length != 40) {
+            revert RemoteBridgeNotFound();
+        }
+
+        return remoteBridge;
+    }
+
+    // deadlines are in ms, same as casper block time
+    function blockTime() private view returns (uint64) {
+        return uint64(block.timestamp) * 1000;
+    }
+
+    function approveBurnProof(bytes32 proofHash) external onlyRole(ROLE_APPROVER) {
+        require(
+            burnProofStorage[proofHash] == States.DefaultValue,
+            "CCB: Already approved"
+        );
+        burnProofStorage[proofHash] = States.Approved;
+        emit ApprovedBurnProof(proofHash);
+    }
+
+    function mintWithBurnProof(
+        address mintToken,
+        bytes memory burnGenericToken,
+        bytes memory burnGenericCaller,
+        uint8 burnChainType,
+        uint32 burnChainId,
+        uint256 burnAmount,
+        uint256 burnNonce,
+        uint64 burnDeadline,
+        bytes32 burnProofHash
+    ) external {
+        if (burnGenericToken.length != 40) {
+            revert InvalidTokenLength();
+        }
+        if (burnGenericCaller.length != 40) {
+            revert InvalidCallerLength();
+        }
+
+        if (burnDeadline != 0 && blockTime() > burnDeadline) {
+            revert ProofExpired();
+        }
+
+        // filled field by field, stack too deep otherwise
+        BurnProof memory proof;
+        proof.proofVersion = PROOF_VERSION;
+        proof.mintCaller = genericAddress(msg.sender);
+        proof.burnCaller = burnGenericCaller;
+        proof.mintToken = genericAddress(mintToken);
+        proof.burnToken = burnGenericToken;
+        proof.burnAmount = burnAmount;
+        proof.mintChainType = uint8(thisChainType);
+        proof.mintChainId = uint32(block.chainid);
+        proof.burnChainType = burnChainType;
+        proof.burnChainId = burnChainId;
+        proof.burnNonce = burnNonce;
+        proof.burnDeadline = burnDeadline;
+        proof.mintBridge = genericAddress(address(this));
+        proof.burnBridge = getRemoteBridge(burnChainType, burnChainId);
+
+        {
+            bytes32 allowanceHash = getAllowanceHash(
+                proof.mintToken,
+                proof.burnToken,
+                thisChainType,
+                proof.mintChainId,
+                ChainType(burnChainType),
+                burnChainId
+            );
+
+            if (allowances[allowanceHash] != Allowance.Allowed) {
+                revert AllowanceNotFound();
+            }
+        }
+
+        if (burnProofStorage[burnProofHash] != States.Approved) {
+            revert ProofIsNotApprovedOrAlreadyExecuted();
+        }
+
+        bytes32 computedBurnProofHash = getBurnProofHash(proof, hashAlgorithm);
+
+        if (computedBurnProofHash != burnProofHash) {
+            revert ProvidedHashIsInvalid();
+        }
+
+        burnProofStorage[burnProofHash] = States.Executed;
+
+        IMintBurnBridgeToken(mintToken).mintByBridge(msg.sender, burnAmount);
+
+        emitProofOfMint(proof, computedBurnProofHash);
+    }
+
+    function burnAndCreateProof(
+        address burnToken,
+        bytes memory mintGenericToken,
+        bytes memory mintGenericCaller,
+        uint256 burnAmount,
+        uint8 mintChainType,
+        uint32 mintChainId,
+        uint64 burnDeadline
+    ) external {
+        if (mintGenericCaller.length != 40) {
+            revert InvalidCallerLength();
+        }
+        if (mintGenericToken.length != 40) {
+            revert InvalidTokenLength();
+        }
+
+        // optional, absolute time in ms
+        if (burnDeadline != 0 && burnDeadline <= blockTime()) {
+            revert InvalidDeadline();
+        }
+
+        BurnProof memory proof;
+        proof.proofVersion = PROOF_VERSION;
+        proof.mintCaller = mintGenericCaller;
+        proof.burnCaller = genericAddress(msg.sender);
+        proof.mintToken = mintGenericToken;
+        proof.burnToken = genericAddress(burnToken);
+        proof.burnAmount = burnAmount;
+        proof.mintChainType = mintChainType;
+        proof.mintChainId = mintChainId;
+        proof.burnChainType = uint8(thisChainType);
+        proof.burnChainId = uint32(block.chainid);
+        proof.burnNonce = nonceByCaller[msg.sender];
+        proof.burnDeadline = burnDeadline;
+        proof.mintBridge = getRemoteBridge(mintChainType, mintChainId);
+        proof.burnBridge = genericAddress(address(this));
+
+        // stack to deep fix
+        {
+            bytes32 allowanceHash = getAllowanceHash(
+                mintGenericToken,
+                proof.burnToken,
+                ChainType(mintChainType),
+                mintChainId,
+                thisChainType,
+                proof.burnChainId
+            );
+
+            if (allowances[allowanceHash] != Allowance.Allowed) {
+                revert AllowanceNotFound();
+            }
+        }
+
+        if (burnAmount > IMintBurnBridgeToken(burnToken).balanceOf(msg.sender)) {
+            revert ReduceAmount();
+        }
+
+        bytes32 computedBurnProofHash = getBurnProofHash(proof, hashAlgorithm);
+
+        burnProofStorage[computedBurnProofHash] = States.Burned;
+
+        IMintBurnBridgeToken(burnToken).burnByBridge(msg.sender, burnAmount);
+
+        emitProofOfBurn(proof, computedBurnProofHash);
+
+        nonceByCaller[msg.sender]++;
+
+        // return computedBurnProofHash;
+    }
+
+    function emitProofOfBurn(BurnProof memory proof, bytes32 burnProofHash)
+        private
+    {
+        emit ProofOfBurn(
+            proof.mintToken,
+            proof.burnToken,
+            proof.mintCaller,
+            proof.burnCaller,
+            proof.burnAmount,
+            proof.burnNonce,
+            proof.burnDeadline,
+            ChainType(proof.mintChainType),
+            proof.mintChainId,
+            ChainType(proof.burnChainType),
+            proof.burnChainId,
+            burnProofHash
+        );
+    }
+
+    function emitProofOfMint(BurnProof memory proof, bytes32 burnProofHash)
+        private
+    {
+        emit ProofOfMint(
+            proof.mintToken,
+            proof.burnToken,
+            proof.mintCaller,
+            proof.burnCaller,
+            proof.burnAmount,
+            ChainType(proof.mintChainType),
+            proof.mintChainId,
+            ChainType(proof.burnChainType),
+            proof.burnChainId,
+            burnProofHash
+        );
+    }
+}
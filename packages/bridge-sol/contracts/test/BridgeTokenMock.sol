@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+pragma solidity ^0.8.13;
+
+// bare balances, anyone may mint and burn, tests only
+contract BridgeTokenMock {
+    mapping(address => uint256) public balanceOf;
+
+    function mintByBridge(address to, uint256 amount) external {
+        balanceOf[to] += amount;
+    }
+
+    function burnByBridge(address from, uint256 amount) external {
+        balanceOf[from] -= amount;
+    }
+}
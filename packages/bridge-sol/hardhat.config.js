@@ -0,0 +1,11 @@
+require("@nomiclabs/hardhat-waffle");
+
+/** @type import('hardhat/config').HardhatUserConfig */
+module.exports = {
+  solidity: {
+    version: "0.8.13",
+    settings: {
+      optimizer: { enabled: true, runs: 200 },
+    },
+  },
+};
@@ -0,0 +1,107 @@
+const { expect } = require("chai");
+const { ethers } = require("hardhat");
+const vectors = require("../../test-vectors/burn_proof_hash.json").vectors;
+
+const Keccak256 = 1;
+const Evm = 1;
+const Casper = 2;
+
+// generic address of an evm address, left padded to 40 bytes
+const generic = (address) => "0x" + address.slice(2).toLowerCase().padStart(80, "0");
+
+const burnProof = (vector) => ({
+  proofVersion: vector.proof_version,
+  mintCaller: "0x" + vector.mint_caller,
+  burnCaller: "0x" + vector.burn_caller,
+  mintToken: "0x" + vector.mint_token,
+  burnToken: "0x" + vector.burn_token,
+  burnAmount: vector.burn_amount,
+  mintChainType: vector.mint_chain_type,
+  mintChainId: vector.mint_chain_id,
+  burnChainType: vector.burn_chain_type,
+  burnChainId: vector.burn_chain_id,
+  burnNonce: vector.burn_nonce,
+  burnDeadline: vector.burn_deadline,
+  mintBridge: "0x" + vector.mint_bridge,
+  burnBridge: "0x" + vector.burn_bridge,
+});
+
+describe("burn proof hash", function () {
+  let bridge;
+
+  before(async function () {
+    const Bridge = await ethers.getContractFactory("CrossChainBridge");
+    bridge = await Bridge.deploy(Keccak256);
+    await bridge.deployed();
+  });
+
+  for (const vector of vectors) {
+    it(vector.name, async function () {
+      const proof = burnProof(vector);
+
+      expect(await bridge.getBurnProofPreimage(proof)).to.equal("0x" + vector.preimage);
+      expect(await bridge.getBurnProofHash(proof, vector.hash_algorithm)).to.equal(
+        "0x" + vector.burn_proof_hash
+      );
+    });
+  }
+
+  it("keccak bridge burns with the expected hash", async function () {
+    const [account] = await ethers.getSigners();
+    const { chainId } = await ethers.provider.getNetwork();
+
+    const Token = await ethers.getContractFactory("BridgeTokenMock");
+    const token = await Token.deploy();
+    await token.deployed();
+    await token.mintByBridge(account.address, 1000);
+
+    const mintToken = "0x" + "44".repeat(40);
+    const mintCaller = "0x" + "22".repeat(40);
+    const mintBridge = "0x" + "66".repeat(40);
+
+    await bridge.setRemoteBridge(Casper, 1010, mintBridge);
+    await bridge.setAllowance(mintToken, generic(token.address), Casper, 1010, Evm, chainId);
+
+    const proof = {
+      proofVersion: 1,
+      mintCaller,
+      burnCaller: generic(account.address),
+      mintToken,
+      burnToken: generic(token.address),
+      burnAmount: 400,
+      mintChainType: Casper,
+      mintChainId: 1010,
+      burnChainType: Evm,
+      burnChainId: chainId,
+      burnNonce: 0,
+      burnDeadline: 0,
+      mintBridge,
+      burnBridge: generic(bridge.address),
+    };
+    const expected = await bridge.getBurnProofHash(proof, Keccak256);
+    expect(expected).to.equal(ethers.utils.keccak256(await bridge.getBurnProofPreimage(proof)));
+
+    await expect(
+      bridge.burnAndCreateProof(token.address, mintToken, mintCaller, 400, Casper, 1010, 0)
+    )
+      .to.emit(bridge, "ProofOfBurn")
+      .withArgs(
+        mintToken,
+        generic(token.address),
+        mintCaller,
+        generic(account.address),
+        400,
+        0,
+        0,
+        Casper,
+        1010,
+        Evm,
+        chainId,
+        expected
+      );
+
+    expect(await bridge.burnProofStorage(expected)).to.equal(1);
+    expect(await bridge.nonceByCaller(account.address)).to.equal(1);
+    expect(await token.balanceOf(account.address)).to.equal(600);
+  });
+});